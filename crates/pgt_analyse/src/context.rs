@@ -1,11 +1,19 @@
+use pgt_schema_cache::{SchemaCache, Version};
+
 use crate::{
+    NodeRanges,
     categories::RuleCategory,
+    file_context::FileStatement,
     rule::{GroupCategory, Rule, RuleGroup, RuleMetadata},
 };
 
 pub struct RuleContext<'a, R: Rule> {
     stmt: &'a pgt_query_ext::NodeEnum,
+    text: &'a str,
+    node_ranges: &'a NodeRanges,
     options: &'a R::Options,
+    schema_cache: Option<&'a SchemaCache>,
+    version: Option<&'a Version>,
 }
 
 impl<'a, R> RuleContext<'a, R>
@@ -13,8 +21,22 @@ where
     R: Rule + Sized + 'static,
 {
     #[allow(clippy::too_many_arguments)]
-    pub fn new(stmt: &'a pgt_query_ext::NodeEnum, options: &'a R::Options) -> Self {
-        Self { stmt, options }
+    pub fn new(
+        stmt: &'a pgt_query_ext::NodeEnum,
+        text: &'a str,
+        node_ranges: &'a NodeRanges,
+        options: &'a R::Options,
+        schema_cache: Option<&'a SchemaCache>,
+        version: Option<&'a Version>,
+    ) -> Self {
+        Self {
+            stmt,
+            text,
+            node_ranges,
+            options,
+            schema_cache,
+            version,
+        }
     }
 
     /// Returns the group that belongs to the current rule
@@ -32,6 +54,37 @@ where
         self.stmt
     }
 
+    /// Returns the text of the statement, e.g. to build the fixed statement for
+    /// [`RuleDiagnostic::fix`](crate::RuleDiagnostic::fix)
+    pub fn text(&self) -> &str {
+        self.text
+    }
+
+    /// Returns the ranges of the nodes of the statement, to point diagnostics at the node that
+    /// causes them instead of the whole statement
+    pub fn node_ranges(&self) -> &NodeRanges {
+        self.node_ranges
+    }
+
+    /// Returns the schema of the database, if it is available.
+    ///
+    /// Rules must still work without it, e.g. when no database is configured, and only use it to
+    /// refine their diagnostics.
+    pub fn schema_cache(&self) -> Option<&SchemaCache> {
+        self.schema_cache
+    }
+
+    /// Returns the version of the database, if it is available.
+    pub fn version(&self) -> Option<&Version> {
+        self.version
+    }
+
+    /// Returns the version of the database as a number, e.g. `150007` for 15.7, if it is
+    /// available.
+    pub fn server_version_num(&self) -> Option<i64> {
+        self.version.and_then(|version| version.version_num)
+    }
+
     /// Returns the metadata of the rule
     ///
     /// The metadata contains information about the rule, such as the name, version, language, and whether it is recommended.
@@ -71,3 +124,80 @@ where
         self.options
     }
 }
+
+/// The context of a rule that runs once per file, see [Rule::run_file]
+pub struct FileRuleContext<'a, R: Rule> {
+    statements: &'a [FileStatement<'a>],
+    text: &'a str,
+    options: &'a R::Options,
+    schema_cache: Option<&'a SchemaCache>,
+    version: Option<&'a Version>,
+}
+
+impl<'a, R> FileRuleContext<'a, R>
+where
+    R: Rule + Sized + 'static,
+{
+    pub fn new(
+        statements: &'a [FileStatement<'a>],
+        text: &'a str,
+        options: &'a R::Options,
+        schema_cache: Option<&'a SchemaCache>,
+        version: Option<&'a Version>,
+    ) -> Self {
+        Self {
+            statements,
+            text,
+            options,
+            schema_cache,
+            version,
+        }
+    }
+
+    /// Returns the group that belongs to the current rule
+    pub fn group(&self) -> &'static str {
+        <R::Group as RuleGroup>::NAME
+    }
+
+    /// Returns the category that belongs to the current rule
+    pub fn category(&self) -> RuleCategory {
+        <<R::Group as RuleGroup>::Category as GroupCategory>::CATEGORY
+    }
+
+    /// Returns the statements of the file in order, together with the transaction block and
+    /// settings they run with. Statements that fail to parse are left out.
+    pub fn statements(&self) -> &[FileStatement<'a>] {
+        self.statements
+    }
+
+    /// Returns the text of the file
+    pub fn text(&self) -> &str {
+        self.text
+    }
+
+    /// Returns the schema of the database, if it is available.
+    pub fn schema_cache(&self) -> Option<&SchemaCache> {
+        self.schema_cache
+    }
+
+    /// Returns the version of the database, if it is available.
+    pub fn version(&self) -> Option<&Version> {
+        self.version
+    }
+
+    /// Returns the version of the database as a number, e.g. `150007` for 15.7, if it is
+    /// available.
+    pub fn server_version_num(&self) -> Option<i64> {
+        self.version.and_then(|version| version.version_num)
+    }
+
+    /// Returns the metadata of the rule
+    pub fn metadata(&self) -> &RuleMetadata {
+        &R::METADATA
+    }
+
+    /// Returns the options of the rule
+    pub fn options(&self) -> &R::Options {
+        self.options
+    }
+}
@@ -0,0 +1,287 @@
+use pgt_query_ext::{
+    NodeEnum,
+    protobuf::{TransactionStmt, TransactionStmtKind, VariableSetKind, VariableSetStmt, a_const},
+};
+use pgt_text_size::TextRange;
+use rustc_hash::FxHashMap;
+
+/// A statement of a file, together with the state of the session at the time it runs.
+///
+/// The state is derived from the statements that precede it in the file, so it does not know
+/// about transactions that a migration tool opens around the whole file, or settings that are
+/// configured on the role or database.
+#[derive(Debug)]
+pub struct FileStatement<'a> {
+    pub root: &'a NodeEnum,
+    /// The range of the statement in the file
+    pub range: TextRange,
+    /// The transaction block the statement runs in, if any
+    pub transaction: Option<TransactionBlock>,
+    /// The settings changed via `SET` before the statement
+    pub settings: SessionSettings,
+}
+
+impl<'a> FileStatement<'a> {
+    /// Walks the statements of a file in order and derives the transaction block and settings
+    /// each of them runs with.
+    pub fn from_statements(
+        statements: impl IntoIterator<Item = (&'a NodeEnum, TextRange)>,
+    ) -> Vec<Self> {
+        let mut session = Session::default();
+
+        statements
+            .into_iter()
+            .map(|(root, range)| {
+                let statement = FileStatement {
+                    root,
+                    range,
+                    transaction: session.transaction.as_ref().map(|open| open.block.clone()),
+                    settings: session.settings.clone(),
+                };
+
+                match root {
+                    NodeEnum::TransactionStmt(stmt) => session.transaction(stmt, range),
+                    NodeEnum::VariableSetStmt(stmt) => session.set(stmt, range),
+                    _ => {}
+                }
+
+                statement
+            })
+            .collect()
+    }
+
+    /// Whether the statement runs inside a transaction block
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+}
+
+/// A transaction block opened with `BEGIN` or `START TRANSACTION`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionBlock {
+    /// The range of the statement that opened the transaction block
+    pub start: TextRange,
+}
+
+/// A setting changed via `SET` or `SET LOCAL`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    /// The value as written in the statement, e.g. `5s` for `set lock_timeout = '5s'`. Lists
+    /// like `search_path` are joined with `, `.
+    pub value: String,
+    /// The range of the statement that changed the setting
+    pub range: TextRange,
+    /// Whether the setting only lasts until the end of the transaction block
+    pub is_local: bool,
+}
+
+/// The settings changed via `SET` at some point of the file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionSettings {
+    session: FxHashMap<String, Setting>,
+    local: FxHashMap<String, Setting>,
+}
+
+impl SessionSettings {
+    /// Returns the setting with the given name, e.g. `lock_timeout`, if it was changed.
+    pub fn get(&self, name: &str) -> Option<&Setting> {
+        let name = name.to_lowercase();
+        self.local.get(&name).or_else(|| self.session.get(&name))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Session {
+    settings: SessionSettings,
+    transaction: Option<OpenTransaction>,
+}
+
+#[derive(Debug)]
+struct OpenTransaction {
+    block: TransactionBlock,
+    /// The session settings before the transaction block, restored on rollback
+    settings_before: FxHashMap<String, Setting>,
+}
+
+impl Session {
+    fn transaction(&mut self, stmt: &TransactionStmt, range: TextRange) {
+        match stmt.kind() {
+            // a nested `BEGIN` only raises a warning
+            TransactionStmtKind::TransStmtBegin | TransactionStmtKind::TransStmtStart
+                if self.transaction.is_none() =>
+            {
+                self.begin(range);
+            }
+            TransactionStmtKind::TransStmtCommit | TransactionStmtKind::TransStmtPrepare => {
+                self.transaction = None;
+                self.settings.local.clear();
+                if stmt.chain {
+                    self.begin(range);
+                }
+            }
+            TransactionStmtKind::TransStmtRollback => {
+                if let Some(open) = self.transaction.take() {
+                    self.settings.session = open.settings_before;
+                }
+                self.settings.local.clear();
+                if stmt.chain {
+                    self.begin(range);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn begin(&mut self, range: TextRange) {
+        self.transaction = Some(OpenTransaction {
+            block: TransactionBlock { start: range },
+            settings_before: self.settings.session.clone(),
+        });
+    }
+
+    fn set(&mut self, stmt: &VariableSetStmt, range: TextRange) {
+        match stmt.kind() {
+            VariableSetKind::VarSetValue => {
+                let Some(value) = setting_value(stmt) else {
+                    return;
+                };
+                let setting = Setting {
+                    value,
+                    range,
+                    is_local: stmt.is_local,
+                };
+
+                if !stmt.is_local {
+                    self.settings.local.remove(&stmt.name);
+                    self.settings.session.insert(stmt.name.clone(), setting);
+                } else if self.transaction.is_some() {
+                    // `SET LOCAL` outside of a transaction block has no effect
+                    self.settings.local.insert(stmt.name.clone(), setting);
+                }
+            }
+            VariableSetKind::VarSetDefault | VariableSetKind::VarReset => {
+                self.settings.local.remove(&stmt.name);
+                if !stmt.is_local {
+                    self.settings.session.remove(&stmt.name);
+                }
+            }
+            VariableSetKind::VarResetAll => {
+                self.settings.local.clear();
+                self.settings.session.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the value of a `SET` statement as written, or `None` if it is not a list of constants
+fn setting_value(stmt: &VariableSetStmt) -> Option<String> {
+    let values = stmt
+        .args
+        .iter()
+        .map(|arg| match arg.node.as_ref()? {
+            NodeEnum::AConst(constant) => match constant.val.as_ref()? {
+                a_const::Val::Ival(ival) => Some(ival.ival.to_string()),
+                a_const::Val::Fval(fval) => Some(fval.fval.clone()),
+                a_const::Val::Boolval(boolval) => Some(boolval.boolval.to_string()),
+                a_const::Val::Sval(sval) => Some(sval.sval.clone()),
+                a_const::Val::Bsval(bsval) => Some(bsval.bsval.clone()),
+            },
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(values.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_text_size::{TextRange, TextSize};
+
+    use super::FileStatement;
+
+    fn parse(statements: &[&str]) -> Vec<pgt_query_ext::NodeEnum> {
+        statements
+            .iter()
+            .map(|sql| pgt_query_ext::parse(sql).unwrap())
+            .collect()
+    }
+
+    fn range(idx: usize) -> TextRange {
+        TextRange::empty(TextSize::from(u32::try_from(idx).unwrap()))
+    }
+
+    #[test]
+    fn tracks_transaction_blocks() {
+        let roots = parse(&[
+            "create index concurrently users_email_idx on users (email);",
+            "begin;",
+            "create index concurrently users_name_idx on users (name);",
+            "commit;",
+            "start transaction;",
+            "rollback and chain;",
+            "rollback;",
+            "select 1;",
+        ]);
+        let statements =
+            FileStatement::from_statements(roots.iter().enumerate().map(|(i, r)| (r, range(i))));
+
+        let transactions: Vec<_> = statements
+            .iter()
+            .map(|stmt| stmt.transaction.as_ref().map(|block| block.start))
+            .collect();
+
+        assert_eq!(
+            transactions,
+            vec![
+                None,
+                None,
+                Some(range(1)),
+                Some(range(1)),
+                None,
+                Some(range(4)),
+                Some(range(5)),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn tracks_settings() {
+        let roots = parse(&[
+            "set lock_timeout = '5s';",
+            "begin;",
+            "set local lock_timeout to 1000;",
+            "set statement_timeout = '1min';",
+            "select 1;",
+            "rollback;",
+            "select 1;",
+            "set local lock_timeout = '1s';",
+            "reset lock_timeout;",
+            "select 1;",
+        ]);
+        let statements =
+            FileStatement::from_statements(roots.iter().enumerate().map(|(i, r)| (r, range(i))));
+
+        let value = |idx: usize, name: &str| {
+            statements[idx]
+                .settings
+                .get(name)
+                .map(|setting| setting.value.as_str())
+        };
+
+        assert_eq!(value(0, "lock_timeout"), None);
+        assert_eq!(value(1, "lock_timeout"), Some("5s"));
+        assert_eq!(value(4, "LOCK_TIMEOUT"), Some("1000"));
+        assert!(statements[4].settings.get("lock_timeout").unwrap().is_local);
+        assert_eq!(value(4, "statement_timeout"), Some("1min"));
+
+        // the rollback reverts all changes made in the transaction block
+        assert_eq!(value(6, "lock_timeout"), Some("5s"));
+        assert_eq!(value(6, "statement_timeout"), None);
+
+        // `set local` has no effect outside of a transaction block
+        assert_eq!(value(8, "lock_timeout"), Some("5s"));
+        assert_eq!(value(9, "lock_timeout"), None);
+    }
+}
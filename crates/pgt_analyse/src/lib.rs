@@ -1,7 +1,9 @@
 mod categories;
 pub mod context;
+mod file_context;
 mod filter;
 pub mod macros;
+mod node_ranges;
 pub mod options;
 mod registry;
 mod rule;
@@ -13,10 +15,13 @@ pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder, RuleCategory,
     SUPPRESSION_ACTION_CATEGORY, SourceActionKind,
 };
+pub use crate::file_context::{FileStatement, SessionSettings, Setting, TransactionBlock};
 pub use crate::filter::{AnalysisFilter, GroupKey, RuleFilter, RuleKey};
+pub use crate::node_ranges::NodeRanges;
 pub use crate::options::{AnalyserOptions, AnalyserRules};
 pub use crate::registry::{
-    MetadataRegistry, RegistryRuleParams, RegistryVisitor, RuleRegistry, RuleRegistryBuilder,
+    MetadataRegistry, RegistryFileRuleParams, RegistryRuleParams, RegistryVisitor, RuleRegistry,
+    RuleRegistryBuilder,
 };
 pub use crate::rule::{
     GroupCategory, Rule, RuleDiagnostic, RuleGroup, RuleMeta, RuleMetadata, RuleSource,
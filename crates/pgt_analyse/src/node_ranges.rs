@@ -0,0 +1,209 @@
+use pgt_lexer::{SyntaxKind, WHITESPACE_TOKENS};
+use pgt_query_ext::{NodeEnum, protobuf::AlterTableStmt};
+use pgt_text_size::TextRange;
+
+/// Maps the nodes of a statement to their range in the statement text.
+///
+/// The AST only stores the start of some nodes, so the ranges are recovered from the tokens of
+/// the statement. All ranges are relative to the start of the statement.
+#[derive(Debug, Default)]
+pub struct NodeRanges {
+    /// The tokens of the statement, without whitespace and comments
+    tokens: Vec<(SyntaxKind, TextRange)>,
+}
+
+impl NodeRanges {
+    pub fn new(text: &str) -> Self {
+        let tokens = pgt_lexer::lex(text)
+            .map(|tokens| {
+                tokens
+                    .into_iter()
+                    .filter(|token| !WHITESPACE_TOKENS.contains(&token.kind))
+                    .map(|token| (token.kind, token.span))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { tokens }
+    }
+
+    /// Returns the range of a node that has a location, e.g. a column definition or a
+    /// constraint. The range ends with the list item the node starts, so it spans e.g. a
+    /// column definition up to the next comma.
+    pub fn node(&self, node: &NodeEnum) -> Option<TextRange> {
+        let location = pgt_query_ext::get_location(node)?;
+        let start = self.position(location)?;
+
+        self.list_items(start).into_iter().next()
+    }
+
+    /// Returns the range of the statement without leading comments.
+    pub fn statement(&self) -> Option<TextRange> {
+        let (_, first) = self.tokens.first()?;
+        let (_, last) = self.tokens.last()?;
+
+        Some(first.cover(*last))
+    }
+
+    /// Returns the range of the token at `location`, e.g. the name of a column definition.
+    pub fn token(&self, location: usize) -> Option<TextRange> {
+        self.position(location).map(|idx| self.tokens[idx].1)
+    }
+
+    /// Returns the range from the token at `location` up to and including the next token of
+    /// `kind`, e.g. `not null` for a not null constraint.
+    pub fn until(&self, location: usize, kind: SyntaxKind) -> Option<TextRange> {
+        let start = self.position(location)?;
+        let (_, end) = self.tokens[start..].iter().find(|(k, _)| *k == kind)?;
+
+        Some(self.tokens[start].1.cover(*end))
+    }
+
+    /// Returns the range of the command at `idx` of an `ALTER TABLE` statement, e.g.
+    /// `drop column email` in `alter table users drop column email, drop column name`.
+    pub fn alter_table_cmd(&self, stmt: &AlterTableStmt, idx: usize) -> Option<TextRange> {
+        let location = usize::try_from(stmt.relation.as_ref()?.location).ok()?;
+        let mut start = self.position(location)?;
+
+        // skip the qualified name of the relation and the `*` that includes its descendants
+        start += 1;
+        while self.kind(start) == Some(SyntaxKind::Ascii46) {
+            start += 2;
+        }
+        if self.kind(start) == Some(SyntaxKind::Ascii42) {
+            start += 1;
+        }
+
+        let cmds = self.list_items(start);
+        if cmds.len() != stmt.cmds.len() {
+            return None;
+        }
+
+        cmds.get(idx).copied()
+    }
+
+    /// Returns the index of the token that starts at `location`
+    fn position(&self, location: usize) -> Option<usize> {
+        self.tokens
+            .iter()
+            .position(|(_, range)| usize::from(range.start()) == location)
+    }
+
+    fn kind(&self, idx: usize) -> Option<SyntaxKind> {
+        self.tokens.get(idx).map(|(kind, _)| *kind)
+    }
+
+    /// Returns the ranges of the comma separated items of the list that starts at the token at
+    /// `start`. The list ends with the statement or with the parenthesis that encloses it.
+    fn list_items(&self, start: usize) -> Vec<TextRange> {
+        let mut items = vec![];
+        let mut item: Option<TextRange> = None;
+        let mut depth = 0;
+
+        for (kind, range) in self.tokens.iter().skip(start) {
+            match kind {
+                SyntaxKind::Ascii40 => depth += 1,
+                SyntaxKind::Ascii41 if depth == 0 => break,
+                SyntaxKind::Ascii41 => depth -= 1,
+                SyntaxKind::Ascii59 if depth == 0 => break,
+                SyntaxKind::Ascii44 if depth == 0 => {
+                    items.extend(item.take());
+                    continue;
+                }
+                _ => {}
+            }
+
+            item = Some(item.map_or(*range, |item| item.cover(*range)));
+        }
+
+        items.extend(item);
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_lexer::SyntaxKind;
+    use pgt_query_ext::NodeEnum;
+    use pgt_text_size::TextRange;
+
+    use super::NodeRanges;
+
+    fn text_of(sql: &str, range: Option<TextRange>) -> Option<&str> {
+        range.map(|range| &sql[range])
+    }
+
+    #[test]
+    fn finds_alter_table_commands() {
+        let sql = "alter table only public.users drop column email, alter column \"name\" set default lower('A, B');";
+        let NodeEnum::AlterTableStmt(stmt) = pgt_query_ext::parse(sql).unwrap() else {
+            panic!("expected an alter table statement");
+        };
+        let ranges = NodeRanges::new(sql);
+
+        assert_eq!(
+            text_of(sql, ranges.alter_table_cmd(&stmt, 0)),
+            Some("drop column email")
+        );
+        assert_eq!(
+            text_of(sql, ranges.alter_table_cmd(&stmt, 1)),
+            Some("alter column \"name\" set default lower('A, B')")
+        );
+        assert_eq!(ranges.alter_table_cmd(&stmt, 2), None);
+    }
+
+    #[test]
+    fn finds_nodes_with_a_location() {
+        let sql = "create table users (id int primary key, name text not null)";
+        let NodeEnum::CreateStmt(stmt) = pgt_query_ext::parse(sql).unwrap() else {
+            panic!("expected a create table statement");
+        };
+        let ranges = NodeRanges::new(sql);
+
+        let columns: Vec<_> = stmt
+            .table_elts
+            .iter()
+            .map(|elt| text_of(sql, ranges.node(elt.node.as_ref().unwrap())))
+            .collect();
+
+        assert_eq!(
+            columns,
+            vec![Some("id int primary key"), Some("name text not null")]
+        );
+    }
+
+    #[test]
+    fn finds_tokens() {
+        let sql = "-- add a column\nalter table users add column \"Score\" int constraint required not null;";
+        let NodeEnum::AlterTableStmt(stmt) = pgt_query_ext::parse(sql).unwrap() else {
+            panic!("expected an alter table statement");
+        };
+        let ranges = NodeRanges::new(sql);
+
+        let Some(NodeEnum::AlterTableCmd(cmd)) = stmt.cmds[0].node.as_ref() else {
+            panic!("expected an alter table command");
+        };
+        let Some(NodeEnum::ColumnDef(column)) = cmd.def.as_ref().unwrap().node.as_ref() else {
+            panic!("expected a column definition");
+        };
+        let Some(NodeEnum::Constraint(constraint)) = column.constraints[0].node.as_ref() else {
+            panic!("expected a constraint");
+        };
+
+        assert_eq!(
+            text_of(sql, ranges.statement()),
+            Some("alter table users add column \"Score\" int constraint required not null;")
+        );
+        assert_eq!(
+            text_of(sql, ranges.token(column.location as usize)),
+            Some("\"Score\"")
+        );
+        assert_eq!(
+            text_of(
+                sql,
+                ranges.until(constraint.location as usize, SyntaxKind::NullP)
+            ),
+            Some("constraint required not null")
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use std::{borrow, collections::BTreeSet};
 
+use pgt_schema_cache::{SchemaCache, Version};
+
 use crate::{
-    AnalyserOptions,
-    context::RuleContext,
+    AnalyserOptions, NodeRanges,
+    context::{FileRuleContext, RuleContext},
+    file_context::FileStatement,
     filter::{AnalysisFilter, GroupKey, RuleKey},
     rule::{GroupCategory, Rule, RuleDiagnostic, RuleGroup},
 };
@@ -142,6 +145,7 @@ impl IntoIterator for RuleRegistry {
 #[derive(Copy, Clone)]
 pub struct RegistryRule {
     pub run: RuleExecutor,
+    pub run_file: FileRuleExecutor,
 }
 
 impl RuleRegistry {
@@ -157,12 +161,27 @@ impl RuleRegistry {
 
 pub struct RegistryRuleParams<'a> {
     pub root: &'a pgt_query_ext::NodeEnum,
+    pub text: &'a str,
+    pub node_ranges: &'a NodeRanges,
+    pub options: &'a AnalyserOptions,
+    pub schema_cache: Option<&'a SchemaCache>,
+    pub version: Option<&'a Version>,
+}
+
+pub struct RegistryFileRuleParams<'a> {
+    pub statements: &'a [FileStatement<'a>],
+    pub text: &'a str,
     pub options: &'a AnalyserOptions,
+    pub schema_cache: Option<&'a SchemaCache>,
+    pub version: Option<&'a Version>,
 }
 
 /// Executor for rule as a generic function pointer
 type RuleExecutor = fn(&RegistryRuleParams) -> Vec<RuleDiagnostic>;
 
+/// Executor for the file-level part of a rule as a generic function pointer
+type FileRuleExecutor = fn(&RegistryFileRuleParams) -> Vec<RuleDiagnostic>;
+
 impl RegistryRule {
     fn new<R>() -> Self
     where
@@ -174,11 +193,37 @@ impl RegistryRule {
             R: Rule<Options: Default> + 'static,
         {
             let options = params.options.rule_options::<R>().unwrap_or_default();
-            let ctx = RuleContext::new(params.root, &options);
+            let ctx = RuleContext::new(
+                params.root,
+                params.text,
+                params.node_ranges,
+                &options,
+                params.schema_cache,
+                params.version,
+            );
             R::run(&ctx)
         }
 
-        Self { run: run::<R> }
+        /// Generic implementation of FileRuleExecutor for any rule type R
+        fn run_file<R>(params: &RegistryFileRuleParams) -> Vec<RuleDiagnostic>
+        where
+            R: Rule<Options: Default> + 'static,
+        {
+            let options = params.options.rule_options::<R>().unwrap_or_default();
+            let ctx = FileRuleContext::new(
+                params.statements,
+                params.text,
+                &options,
+                params.schema_cache,
+                params.version,
+            );
+            R::run_file(&ctx)
+        }
+
+        Self {
+            run: run::<R>,
+            run_file: run_file::<R>,
+        }
     }
 }
 
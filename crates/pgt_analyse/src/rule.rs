@@ -2,14 +2,19 @@ use pgt_console::fmt::Display;
 use pgt_console::{MarkupBuf, markup};
 use pgt_diagnostics::advice::CodeSuggestionAdvice;
 use pgt_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory, MessageAndDescription,
-    Visit,
+    Advices, Applicability, Category, Diagnostic, DiagnosticTags, Location, LogCategory,
+    MessageAndDescription, Visit,
 };
+use pgt_text_edit::TextEdit;
 use pgt_text_size::TextRange;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use crate::{categories::RuleCategory, context::RuleContext, registry::RegistryVisitor};
+use crate::{
+    categories::RuleCategory,
+    context::{FileRuleContext, RuleContext},
+    registry::RegistryVisitor,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(
@@ -94,7 +99,20 @@ pub trait GroupCategory {
 pub trait Rule: RuleMeta + Sized {
     type Options: Default + Clone + Debug;
 
-    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic>;
+    /// Runs the rule on a single statement. The spans of the diagnostics are relative to the
+    /// start of the statement.
+    fn run(_ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        Vec::new()
+    }
+
+    /// Runs the rule once per file, for rules that need to know about the other statements of the
+    /// file, e.g. whether a statement runs inside a transaction block. The spans of the
+    /// diagnostics are relative to the start of the file and must be set, so that the diagnostics
+    /// can be suppressed for the statement they point at. Like those of [Rule::run], their fixes
+    /// replace the text of that statement.
+    fn run_file(_ctx: &FileRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        Vec::new()
+    }
 }
 
 /// Diagnostic object returned by a single analysis rule
@@ -260,6 +278,35 @@ impl RuleDiagnostic {
         self.footer(LogCategory::Warn, msg)
     }
 
+    /// Offers a fix for this [`RuleDiagnostic`] that changes the statement `text` into
+    /// `new_text`.
+    ///
+    /// Fixes that can change the semantics of the statement, or that need to be completed by the
+    /// user, must use [`Applicability::MaybeIncorrect`]. They are only applied with
+    /// `check --write --unsafe`.
+    pub fn fix(
+        mut self,
+        applicability: Applicability,
+        msg: impl Display,
+        text: &str,
+        new_text: &str,
+    ) -> Self {
+        self.rule_advice
+            .code_suggestion_list
+            .push(CodeSuggestionAdvice {
+                applicability,
+                msg: markup!({ msg }).to_owned(),
+                suggestion: TextEdit::from_unicode_words(text, new_text),
+            });
+        self
+    }
+
+    /// Returns the fixes offered for this [`RuleDiagnostic`]. The edits of a fix span the whole
+    /// statement.
+    pub fn fixes(&self) -> &[CodeSuggestionAdvice<MarkupBuf>] {
+        &self.rule_advice.code_suggestion_list
+    }
+
     pub fn advices(&self) -> &RuleAdvice {
         &self.rule_advice
     }
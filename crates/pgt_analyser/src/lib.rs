@@ -1,14 +1,19 @@
 use std::{ops::Deref, sync::LazyLock};
 
 use pgt_analyse::{
-    AnalyserOptions, AnalysisFilter, MetadataRegistry, RegistryRuleParams, RuleDiagnostic,
-    RuleRegistry,
+    AnalyserOptions, AnalysisFilter, FileStatement, MetadataRegistry, NodeRanges,
+    RegistryFileRuleParams, RegistryRuleParams, RuleDiagnostic, RuleRegistry,
 };
+use pgt_schema_cache::{SchemaCache, Version};
+use pgt_text_size::TextRange;
 pub use registry::visit_registry;
+pub use suppressions::{SuppressionDiagnostic, Suppressions};
 
 mod lint;
 pub mod options;
 mod registry;
+mod suppressions;
+mod utils;
 
 pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     let mut metadata = MetadataRegistry::default();
@@ -19,8 +24,6 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Main entry point to the analyser.
 pub struct Analyser<'a> {
     /// Holds the metadata for all the rules statically known to the analyser
-    /// we need this later when we add suppression support
-    #[allow(dead_code)]
     metadata: &'a MetadataRegistry,
 
     /// Holds all rule options
@@ -32,6 +35,23 @@ pub struct Analyser<'a> {
 
 pub struct AnalyserContext<'a> {
     pub root: &'a pgt_query_ext::NodeEnum,
+    /// The text of the statement
+    pub text: &'a str,
+    /// The schema of the database, if one is available
+    pub schema_cache: Option<&'a SchemaCache>,
+    /// The version of the database, if one is available
+    pub version: Option<&'a Version>,
+}
+
+pub struct FileAnalyserContext<'a> {
+    /// The statements of the file in order, with their range in the file
+    pub statements: Vec<(&'a pgt_query_ext::NodeEnum, TextRange)>,
+    /// The text of the file
+    pub text: &'a str,
+    /// The schema of the database, if one is available
+    pub schema_cache: Option<&'a SchemaCache>,
+    /// The version of the database, if one is available
+    pub version: Option<&'a Version>,
 }
 
 pub struct AnalyserConfig<'a> {
@@ -53,9 +73,14 @@ impl<'a> Analyser<'a> {
     }
 
     pub fn run(&self, ctx: AnalyserContext) -> Vec<RuleDiagnostic> {
+        let node_ranges = NodeRanges::new(ctx.text);
         let params = RegistryRuleParams {
             root: ctx.root,
+            text: ctx.text,
+            node_ranges: &node_ranges,
             options: self.options,
+            schema_cache: ctx.schema_cache,
+            version: ctx.version,
         };
 
         self.registry
@@ -64,6 +89,31 @@ impl<'a> Analyser<'a> {
             .flat_map(|rule| (rule.run)(&params))
             .collect::<Vec<_>>()
     }
+
+    /// Runs the rules that look at a whole file at once. The spans of the diagnostics are relative
+    /// to the start of the file.
+    pub fn run_file(&self, ctx: FileAnalyserContext) -> Vec<RuleDiagnostic> {
+        let statements = FileStatement::from_statements(ctx.statements);
+        let params = RegistryFileRuleParams {
+            statements: &statements,
+            text: ctx.text,
+            options: self.options,
+            schema_cache: ctx.schema_cache,
+            version: ctx.version,
+        };
+
+        self.registry
+            .rules
+            .iter()
+            .flat_map(|rule| (rule.run_file)(&params))
+            .collect::<Vec<_>>()
+    }
+
+    /// Collects the suppression comments of a file, given its text and the ranges of its
+    /// statements.
+    pub fn suppressions(&self, text: &str, statement_ranges: &[TextRange]) -> Suppressions {
+        Suppressions::new(text, statement_ranges, self.metadata)
+    }
 }
 
 #[cfg(test)]
@@ -72,11 +122,15 @@ mod tests {
 
     use pgt_analyse::{AnalyserOptions, AnalysisFilter, RuleFilter};
     use pgt_console::{
-        Markup,
+        Markup, StdDisplay,
         fmt::{Formatter, Termcolor},
         markup,
     };
     use pgt_diagnostics::PrintDiagnostic;
+    use pgt_schema_cache::{
+        Column, ColumnClassKind, ColumnGeneration, Constraint, ConstraintKind, ForeignKeyAction,
+        SchemaCache, Table, View, ViewColumnDependencies, ViewColumnDependency,
+    };
     use termcolor::NoColor;
 
     use crate::Analyser;
@@ -110,7 +164,12 @@ mod tests {
             filter,
         });
 
-        let results = analyser.run(crate::AnalyserContext { root: &ast });
+        let results = analyser.run(crate::AnalyserContext {
+            root: &ast,
+            text: SQL,
+            schema_cache: None,
+            version: None,
+        });
 
         println!("*******************");
         for result in &results {
@@ -123,4 +182,201 @@ mod tests {
 
         // assert_eq!(results, vec![]);
     }
+
+    #[test]
+    fn lists_views_referencing_a_dropped_column() {
+        let mut schema_cache = SchemaCache::default();
+        *schema_cache.views_mut() = vec![View {
+            id: 1,
+            schema: "public".into(),
+            name: "user_emails".into(),
+            is_materialized: false,
+            is_populated: true,
+            definition: "SELECT users.email FROM users;".into(),
+            column_dependencies: ViewColumnDependencies {
+                columns: vec![ViewColumnDependency {
+                    table_id: 2,
+                    schema: "public".into(),
+                    table_name: "users".into(),
+                    column_name: "email".into(),
+                }],
+            },
+            comment: None,
+        }];
+
+        let rule_filter = RuleFilter::Rule("safety", "banDropColumn");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..Default::default()
+        };
+        let options = AnalyserOptions::default();
+        let analyser = Analyser::new(crate::AnalyserConfig {
+            options: &options,
+            filter,
+        });
+
+        let run = |sql: &str| {
+            let ast = pgt_query_ext::parse(sql).expect("failed to parse SQL");
+            analyser
+                .run(crate::AnalyserContext {
+                    root: &ast,
+                    text: sql,
+                    schema_cache: Some(&schema_cache),
+                    version: None,
+                })
+                .iter()
+                .map(|d| StdDisplay(PrintDiagnostic::simple(d)).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let referenced = run("alter table public.users drop column email;");
+        assert_eq!(referenced.len(), 1);
+        assert!(referenced[0].contains("public.user_emails"));
+
+        let unreferenced = run("alter table public.users drop column name;");
+        assert_eq!(unreferenced.len(), 1);
+        assert!(!unreferenced[0].contains("public.user_emails"));
+    }
+
+    #[test]
+    fn skips_columns_with_a_validated_not_null_check() {
+        let check = |name: &str, definition: &str, is_validated: bool| Constraint {
+            id: 0,
+            schema: "public".into(),
+            name: name.into(),
+            table_id: 1,
+            table_name: "users".into(),
+            kind: ConstraintKind::Check,
+            columns: vec![],
+            referenced_table_id: None,
+            referenced_schema: None,
+            referenced_table: None,
+            referenced_columns: vec![],
+            on_update: ForeignKeyAction::NoAction,
+            on_delete: ForeignKeyAction::NoAction,
+            is_deferrable: false,
+            is_initially_deferred: false,
+            is_validated,
+            definition: definition.into(),
+            comment: None,
+        };
+
+        let mut schema_cache = SchemaCache::default();
+        *schema_cache.tables_mut() = vec![Table {
+            id: 1,
+            schema: "public".into(),
+            name: "users".into(),
+            ..Default::default()
+        }];
+        *schema_cache.constraints_mut() = vec![
+            check(
+                "users_email_not_null",
+                r#"CHECK (("Email" IS NOT NULL))"#,
+                true,
+            ),
+            check(
+                "users_order_not_null",
+                r#"CHECK (("order" IS NOT NULL))"#,
+                true,
+            ),
+            check(
+                "users_name_not_null",
+                "CHECK ((name IS NOT NULL)) NOT VALID",
+                false,
+            ),
+            check(
+                "users_age_check",
+                "CHECK (((age IS NOT NULL) AND (age > 0)))",
+                true,
+            ),
+        ];
+
+        let rule_filter = RuleFilter::Rule("safety", "addingNotNullField");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..Default::default()
+        };
+        let options = AnalyserOptions::default();
+        let analyser = Analyser::new(crate::AnalyserConfig {
+            options: &options,
+            filter,
+        });
+
+        let is_reported = |column: &str| {
+            let sql = format!("alter table users alter column {column} set not null;");
+            let ast = pgt_query_ext::parse(&sql).expect("failed to parse SQL");
+            !analyser
+                .run(crate::AnalyserContext {
+                    root: &ast,
+                    text: &sql,
+                    schema_cache: Some(&schema_cache),
+                    version: None,
+                })
+                .is_empty()
+        };
+
+        assert!(!is_reported(r#""Email""#));
+        assert!(!is_reported(r#""order""#));
+        assert!(is_reported("email"));
+        assert!(is_reported("name"));
+        assert!(is_reported("age"));
+    }
+
+    #[test]
+    fn skips_widened_varchar_columns_without_a_using_expression() {
+        let mut schema_cache = SchemaCache::default();
+        *schema_cache.tables_mut() = vec![Table {
+            id: 1,
+            schema: "public".into(),
+            name: "users".into(),
+            ..Default::default()
+        }];
+        *schema_cache.columns_mut() = vec![Column {
+            name: "email".into(),
+            table_name: "users".into(),
+            table_oid: 1,
+            class_kind: ColumnClassKind::OrdinaryTable,
+            schema_name: "public".into(),
+            type_id: 1043,
+            is_nullable: true,
+            is_primary_key: false,
+            is_unique: false,
+            generation: ColumnGeneration::None,
+            default_expr: None,
+            varchar_length: Some(255),
+            comment: None,
+        }];
+
+        let rule_filter = RuleFilter::Rule("safety", "changingColumnType");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..Default::default()
+        };
+        let options = AnalyserOptions::default();
+        let analyser = Analyser::new(crate::AnalyserConfig {
+            options: &options,
+            filter,
+        });
+
+        let is_reported = |change: &str| {
+            let sql = format!("alter table users alter column email {change};");
+            let ast = pgt_query_ext::parse(&sql).expect("failed to parse SQL");
+            !analyser
+                .run(crate::AnalyserContext {
+                    root: &ast,
+                    text: &sql,
+                    schema_cache: Some(&schema_cache),
+                    version: None,
+                })
+                .is_empty()
+        };
+
+        assert!(!is_reported("type text"));
+        assert!(!is_reported("type varchar(300)"));
+        assert!(!is_reported("type text using email"));
+        assert!(!is_reported("type text using email::text"));
+        assert!(is_reported("type varchar(100)"));
+        assert!(is_reported("type text using lower(email)"));
+        assert!(is_reported("type text using email::varchar(10)"));
+    }
 }
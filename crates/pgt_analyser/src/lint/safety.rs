@@ -1,8 +1,17 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use pgt_analyse::declare_lint_group;
+pub mod adding_field_with_default;
+pub mod adding_not_null_field;
 pub mod adding_required_field;
+pub mod ban_concurrent_index_creation_in_transaction;
 pub mod ban_drop_column;
 pub mod ban_drop_not_null;
 pub mod ban_drop_table;
-declare_lint_group! { pub Safety { name : "safety" , rules : [self :: adding_required_field :: AddingRequiredField , self :: ban_drop_column :: BanDropColumn , self :: ban_drop_not_null :: BanDropNotNull , self :: ban_drop_table :: BanDropTable ,] } }
+pub mod ban_vacuum_full_and_cluster;
+pub mod changing_column_type;
+pub mod constraint_missing_not_valid;
+pub mod require_concurrent_index_creation;
+pub mod require_concurrent_index_deletion;
+pub mod use_if_exists;
+declare_lint_group! { pub Safety { name : "safety" , rules : [self :: adding_field_with_default :: AddingFieldWithDefault , self :: adding_not_null_field :: AddingNotNullField , self :: adding_required_field :: AddingRequiredField , self :: ban_concurrent_index_creation_in_transaction :: BanConcurrentIndexCreationInTransaction , self :: ban_drop_column :: BanDropColumn , self :: ban_drop_not_null :: BanDropNotNull , self :: ban_drop_table :: BanDropTable , self :: ban_vacuum_full_and_cluster :: BanVacuumFullAndCluster , self :: changing_column_type :: ChangingColumnType , self :: constraint_missing_not_valid :: ConstraintMissingNotValid , self :: require_concurrent_index_creation :: RequireConcurrentIndexCreation , self :: require_concurrent_index_deletion :: RequireConcurrentIndexDeletion , self :: use_if_exists :: UseIfExists ,] } }
@@ -0,0 +1,178 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::NodeEnum;
+use pgt_query_ext::protobuf::{AlterTableType, ColumnDef, ConstrType, FuncCall};
+use pgt_schema_cache::Behavior;
+
+/// Functions that return a different value for every row, used if no database is available
+const VOLATILE_FUNCTIONS: &[&str] = &[
+    "clock_timestamp",
+    "gen_random_uuid",
+    "nextval",
+    "random",
+    "timeofday",
+    "uuid_generate_v1",
+    "uuid_generate_v1mc",
+    "uuid_generate_v4",
+];
+
+/// Types that add a default from a sequence
+const SERIAL_TYPES: &[&str] = &[
+    "smallserial",
+    "serial",
+    "bigserial",
+    "serial2",
+    "serial4",
+    "serial8",
+];
+
+declare_lint_rule! {
+    /// Adding a column with a volatile default value rewrites the whole table while blocking reads and writes.
+    ///
+    /// Since Postgres 11, adding a column with a constant default, or a default that is not volatile like `now()`, only changes the catalog. A volatile default like `gen_random_uuid()` or the sequence of a `serial` column has to be computed for every existing row, so the table is rewritten while holding an ACCESS EXCLUSIVE lock.
+    ///
+    /// Add the column without a default instead, set the default in a separate statement so that it applies to new rows only, and backfill the existing rows in batches.
+    ///
+    /// If a database is available, the volatility of the functions is read from it. Otherwise, only well-known volatile functions are reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column token uuid default gen_random_uuid();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add column created_at timestamptz default now();
+    /// ```
+    ///
+    pub AddingFieldWithDefault {
+        version: "next",
+        name: "addingFieldWithDefault",
+        recommended: false,
+        sources: &[RuleSource::Squawk("adding-field-with-default")],
+    }
+}
+
+impl Rule for AddingFieldWithDefault {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let NodeEnum::AlterTableStmt(stmt) = ctx.stmt() {
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                let Some(NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                    continue;
+                };
+                if cmd.subtype() != AlterTableType::AtAddColumn {
+                    continue;
+                }
+                let Some(NodeEnum::ColumnDef(column)) =
+                    cmd.def.as_ref().and_then(|def| def.node.as_ref())
+                else {
+                    continue;
+                };
+
+                if is_serial(column) || has_volatile_default(ctx, column) {
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            ctx.node_ranges().alter_table_cmd(stmt, idx),
+                            markup! {
+                                "Adding a column with a volatile default value rewrites the whole table while blocking reads and writes."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "Add the column without a default, set the default in a separate statement, and backfill the existing rows in batches.",
+                        ),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+fn is_serial(column: &ColumnDef) -> bool {
+    column
+        .type_name
+        .as_ref()
+        .and_then(|type_name| type_name.names.last())
+        .and_then(|name| match name.node.as_ref()? {
+            NodeEnum::String(name) => Some(name.sval.as_str()),
+            _ => None,
+        })
+        .is_some_and(|name| SERIAL_TYPES.contains(&name))
+}
+
+fn has_volatile_default(ctx: &RuleContext<AddingFieldWithDefault>, column: &ColumnDef) -> bool {
+    column.constraints.iter().any(|constraint| {
+        let Some(NodeEnum::Constraint(constraint)) = &constraint.node else {
+            return false;
+        };
+
+        constraint.contype() == ConstrType::ConstrDefault
+            && constraint
+                .raw_expr
+                .as_ref()
+                .and_then(|expr| expr.node.as_ref())
+                .is_some_and(|expr| is_volatile(ctx, expr))
+    })
+}
+
+/// Whether the expression calls a volatile function or contains a subquery
+fn is_volatile(ctx: &RuleContext<AddingFieldWithDefault>, expr: &NodeEnum) -> bool {
+    let any_volatile = |nodes: &[pgt_query_ext::protobuf::Node]| {
+        nodes
+            .iter()
+            .filter_map(|node| node.node.as_ref())
+            .any(|node| is_volatile(ctx, node))
+    };
+    let is_volatile_box = |node: &Option<Box<pgt_query_ext::protobuf::Node>>| {
+        node.as_ref()
+            .and_then(|node| node.node.as_ref())
+            .is_some_and(|node| is_volatile(ctx, node))
+    };
+
+    match expr {
+        NodeEnum::FuncCall(func) => is_volatile_function(ctx, func) || any_volatile(&func.args),
+        NodeEnum::TypeCast(cast) => is_volatile_box(&cast.arg),
+        NodeEnum::AExpr(expr) => is_volatile_box(&expr.lexpr) || is_volatile_box(&expr.rexpr),
+        NodeEnum::BoolExpr(expr) => any_volatile(&expr.args),
+        NodeEnum::CoalesceExpr(expr) => any_volatile(&expr.args),
+        NodeEnum::AArrayExpr(expr) => any_volatile(&expr.elements),
+        NodeEnum::SubLink(_) => true,
+        _ => false,
+    }
+}
+
+fn is_volatile_function(ctx: &RuleContext<AddingFieldWithDefault>, func: &FuncCall) -> bool {
+    let names: Vec<&str> = func
+        .funcname
+        .iter()
+        .filter_map(|name| match name.node.as_ref()? {
+            NodeEnum::String(name) => Some(name.sval.as_str()),
+            _ => None,
+        })
+        .collect();
+    let Some((name, schema)) = names.split_last() else {
+        return false;
+    };
+
+    if let Some(schema_cache) = ctx.schema_cache() {
+        let functions = schema_cache.find_functions(name, schema.last().copied());
+        if !functions.is_empty() {
+            return functions
+                .iter()
+                .any(|function| function.behavior == Behavior::Volatile);
+        }
+    }
+
+    VOLATILE_FUNCTIONS.contains(name)
+}
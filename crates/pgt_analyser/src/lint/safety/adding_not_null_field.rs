@@ -0,0 +1,135 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::NodeEnum;
+use pgt_query_ext::protobuf::{AlterTableCmd, AlterTableStmt, AlterTableType, NullTestType};
+use pgt_schema_cache::ConstraintKind;
+
+/// Since Postgres 12, `SET NOT NULL` skips the full table scan if a validated check constraint
+/// already proves that the column is not null.
+const CHECK_CONSTRAINT_SKIPS_SCAN_VERSION: i64 = 120000;
+
+declare_lint_rule! {
+    /// Setting a column to NOT NULL blocks reads and writes while the whole table is scanned.
+    ///
+    /// `ALTER COLUMN ... SET NOT NULL` takes an ACCESS EXCLUSIVE lock and checks every row of the table before it returns.
+    ///
+    /// Since Postgres 12, the scan is skipped if a validated `CHECK (column IS NOT NULL)` constraint exists. Add the constraint with `NOT VALID`, validate it in a separate statement, which does not block reads and writes, and then set the column to NOT NULL. The check constraint can be dropped afterwards.
+    ///
+    /// If a database is available, columns that already have such a constraint are not reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users alter column email set not null;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add constraint users_email_not_null check (email is not null) not valid;
+    /// ```
+    ///
+    pub AddingNotNullField {
+        version: "next",
+        name: "addingNotNullField",
+        recommended: false,
+        sources: &[RuleSource::Squawk("adding-not-nullable-field")],
+    }
+}
+
+impl Rule for AddingNotNullField {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let NodeEnum::AlterTableStmt(stmt) = ctx.stmt() {
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                let Some(NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                    continue;
+                };
+                if cmd.subtype() != AlterTableType::AtSetNotNull
+                    || has_not_null_check(ctx, stmt, cmd)
+                {
+                    continue;
+                }
+
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.node_ranges().alter_table_cmd(stmt, idx),
+                        markup! {
+                            "Setting a column to NOT NULL blocks reads and writes while the whole table is scanned."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a CHECK (column IS NOT NULL) constraint with NOT VALID, validate it in a separate statement, and then set the column to NOT NULL.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Whether the table has a validated check constraint that proves that the column is not null
+fn has_not_null_check(
+    ctx: &RuleContext<AddingNotNullField>,
+    stmt: &AlterTableStmt,
+    cmd: &AlterTableCmd,
+) -> bool {
+    if ctx
+        .server_version_num()
+        .is_some_and(|version| version < CHECK_CONSTRAINT_SKIPS_SCAN_VERSION)
+    {
+        return false;
+    }
+
+    let (Some(schema_cache), Some(relation)) = (ctx.schema_cache(), stmt.relation.as_ref()) else {
+        return false;
+    };
+    let schema = (!relation.schemaname.is_empty()).then_some(relation.schemaname.as_str());
+    let Some(table) = schema_cache.find_table(&relation.relname, schema) else {
+        return false;
+    };
+
+    schema_cache.constraints().iter().any(|constraint| {
+        constraint.table_id == table.id
+            && constraint.kind == ConstraintKind::Check
+            && constraint.is_validated
+            && is_not_null_check(&constraint.definition, &cmd.name)
+    })
+}
+
+/// Whether the definition of a check constraint, e.g. `CHECK ((email IS NOT NULL))`, only tests
+/// that the column is not null
+fn is_not_null_check(definition: &str, column: &str) -> bool {
+    let Ok(NodeEnum::AlterTableStmt(stmt)) =
+        pgt_query_ext::parse(&format!("alter table t add {definition}"))
+    else {
+        return false;
+    };
+
+    if let Some(NodeEnum::AlterTableCmd(cmd)) = stmt.cmds.first().and_then(|cmd| cmd.node.as_ref())
+        && let Some(NodeEnum::Constraint(constraint)) =
+            cmd.def.as_ref().and_then(|def| def.node.as_ref())
+        && let Some(NodeEnum::NullTest(test)) = constraint
+            .raw_expr
+            .as_ref()
+            .and_then(|expr| expr.node.as_ref())
+        && test.nulltesttype() == NullTestType::IsNotNull
+        && let Some(NodeEnum::ColumnRef(column_ref)) =
+            test.arg.as_ref().and_then(|arg| arg.node.as_ref())
+    {
+        matches!(
+            column_ref.fields.as_slice(),
+            [field] if matches!(&field.node, Some(NodeEnum::String(name)) if name.sval == column)
+        )
+    } else {
+        false
+    }
+}
@@ -1,5 +1,9 @@
 use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
 use pgt_console::markup;
+use pgt_diagnostics::Applicability;
+use pgt_lexer::SyntaxKind;
+use pgt_query_ext::protobuf::{AlterTableCmd, AlterTableStmt, ConstrType};
+use pgt_text_size::TextRange;
 
 declare_lint_rule! {
     /// Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required.
@@ -14,6 +18,9 @@ declare_lint_rule! {
     ///
     /// ## Valid in Postgres >= 11
     /// alter table test add column count int not null default 0;
+    ///
+    /// Before Postgres 11, adding a column with a default rewrites the whole table while holding an exclusive lock.
+    /// This is reported if the version of the database is known.
     pub AddingRequiredField {
         version: "next",
         name: "addingRequiredField",
@@ -34,30 +41,68 @@ impl Rule for AddingRequiredField {
             let plain_sql = ctx.stmt().to_ref().deparse().unwrap().to_ascii_lowercase();
             let is_nullable = !plain_sql.contains("not null");
             let has_set_default = plain_sql.contains("default");
-            if is_nullable || has_set_default {
+            if is_nullable {
                 return diagnostics;
             }
 
-            for cmd in &stmt.cmds {
-                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(alter_table_cmd)) = &cmd.node {
-                    if alter_table_cmd.subtype()
+            if has_set_default {
+                // without a connection, assume a version that adds the default without a rewrite
+                if ctx
+                    .server_version_num()
+                    .is_some_and(|version| version < 110000)
+                {
+                    let add_column = stmt.cmds.iter().position(|cmd| {
+                        matches!(
+                            &cmd.node,
+                            Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd))
+                                if cmd.subtype() == pgt_query_ext::protobuf::AlterTableType::AtAddColumn
+                        )
+                    });
+
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            add_column.and_then(|idx| ctx.node_ranges().alter_table_cmd(stmt, idx)),
+                            markup! {
+                                "Adding a new column with a default value rewrites the whole table before Postgres 11."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "The table is locked exclusively while it is rewritten. Add the column without a default, backfill the existing rows in batches, and then set the default and the NOT NULL constraint.",
+                        ),
+                    );
+                }
+                return diagnostics;
+            }
+
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(alter_table_cmd)) = &cmd.node
+                    && alter_table_cmd.subtype()
                         == pgt_query_ext::protobuf::AlterTableType::AtAddColumn
-                    {
-                        diagnostics.push(
-                            RuleDiagnostic::new(
-                                rule_category!(),
-                                None,
-                                markup! {
-                                    "Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required."
-                                },
-                            )
-                            .detail(
-                                None,
-                                "Make new columns optional initially by omitting the NOT NULL constraint until all existing data and application code has been updated. Once no NULL values are written to or persisted in the database, set it to NOT NULL. Alternatively, if using Postgres version 11 or later, add a DEFAULT value that is not volatile. This allows the column to keep its NOT NULL constraint.
+                {
+                    let diagnostic = RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.node_ranges().alter_table_cmd(stmt, idx),
+                        markup! {
+                            "Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Make new columns optional initially by omitting the NOT NULL constraint until all existing data and application code has been updated. Once no NULL values are written to or persisted in the database, set it to NOT NULL. Alternatively, if using Postgres version 11 or later, add a DEFAULT value that is not volatile. This allows the column to keep its NOT NULL constraint.
                                 ",
-                        ),
                     );
-                    }
+
+                    diagnostics.push(match split_add_column(ctx, stmt, alter_table_cmd) {
+                        Some(fixed) => diagnostic.fix(
+                            Applicability::MaybeIncorrect,
+                            markup! { "Add the column as nullable, backfill it, and then set it to NOT NULL." },
+                            ctx.text(),
+                            &fixed,
+                        ),
+                        None => diagnostic,
+                    });
                 }
             }
         }
@@ -65,3 +110,57 @@ impl Rule for AddingRequiredField {
         diagnostics
     }
 }
+
+/// Splits adding a required column into adding it as nullable, a placeholder for the backfill and
+/// setting it to NOT NULL, e.g. `alter table test add column count int not null` into
+/// `alter table test add column count int;` and `alter table test alter column count set not null`.
+fn split_add_column(
+    ctx: &RuleContext<AddingRequiredField>,
+    stmt: &AlterTableStmt,
+    cmd: &AlterTableCmd,
+) -> Option<String> {
+    let Some(pgt_query_ext::NodeEnum::ColumnDef(column)) =
+        cmd.def.as_ref().and_then(|def| def.node.as_ref())
+    else {
+        return None;
+    };
+
+    let not_null = column
+        .constraints
+        .iter()
+        .find_map(|constraint| match &constraint.node {
+            Some(pgt_query_ext::NodeEnum::Constraint(constraint))
+                if constraint.contype() == ConstrType::ConstrNotnull =>
+            {
+                ctx.node_ranges().until(
+                    usize::try_from(constraint.location).ok()?,
+                    SyntaxKind::NullP,
+                )
+            }
+            _ => None,
+        })?;
+    let name = ctx
+        .node_ranges()
+        .token(usize::try_from(column.location).ok()?)?;
+    // the statement up to its first command, e.g. `alter table test `
+    let first_cmd = ctx.node_ranges().alter_table_cmd(stmt, 0)?;
+    let stmt_range = ctx.node_ranges().statement()?;
+
+    let text = ctx.text();
+    let before = text[..usize::from(not_null.start())].trim_end();
+    let after = text[usize::from(not_null.end())..]
+        .trim_end()
+        .trim_end_matches(';')
+        .trim_end();
+    let terminator = if text.trim_end().ends_with(';') {
+        ";"
+    } else {
+        ""
+    };
+
+    Some(format!(
+        "{before}{after};\n-- backfill the existing rows of {name} here\n{prefix}alter column {name} set not null{terminator}",
+        name = &text[name],
+        prefix = &text[TextRange::new(stmt_range.start(), first_cmd.start())],
+    ))
+}
@@ -0,0 +1,60 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::FileRuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::NodeEnum;
+
+declare_lint_rule! {
+    /// `CREATE INDEX CONCURRENTLY` fails inside a transaction block.
+    ///
+    /// Building an index concurrently waits for all transactions that may use the table to finish, so Postgres does not allow it inside a transaction block. The statement errors, and the migration has to be rolled back.
+    ///
+    /// Commit the transaction before creating the index, or run the statement in a migration of its own. Note that some migration tools wrap every migration in a transaction, which this rule does not know about.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// begin;
+    /// create index concurrently users_email_idx on users (email);
+    /// commit;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create index concurrently users_email_idx on users (email);
+    /// ```
+    ///
+    pub BanConcurrentIndexCreationInTransaction {
+        version: "next",
+        name: "banConcurrentIndexCreationInTransaction",
+        recommended: true,
+        sources: &[RuleSource::Squawk("ban-concurrent-index-creation-in-transaction")],
+    }
+}
+
+impl Rule for BanConcurrentIndexCreationInTransaction {
+    type Options = ();
+
+    fn run_file(ctx: &FileRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        ctx.statements()
+            .iter()
+            .filter(|stmt| matches!(stmt.root, NodeEnum::IndexStmt(index) if index.concurrent))
+            .filter_map(|stmt| {
+                let transaction = stmt.transaction.as_ref()?;
+
+                Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        Some(stmt.range),
+                        markup! {
+                            "CREATE INDEX CONCURRENTLY cannot run inside a transaction block."
+                        },
+                    )
+                    .detail(Some(transaction.start), "The transaction block starts here.")
+                    .note("Commit the transaction before creating the index, or create it in a separate migration."),
+                )
+            })
+            .collect()
+    }
+}
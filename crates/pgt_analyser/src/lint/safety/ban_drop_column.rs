@@ -8,6 +8,8 @@ declare_lint_rule! {
     ///
     /// You can leave the column as nullable or delete the column once queries no longer select or modify the column.
     ///
+    /// If the schema of the database is available, the views that reference the column are listed as well.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -31,17 +33,37 @@ impl Rule for BanDropColumn {
         let mut diagnostics = Vec::new();
 
         if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
-            for cmd in &stmt.cmds {
-                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
-                    if cmd.subtype() == pgt_query_ext::protobuf::AlterTableType::AtDropColumn {
-                        diagnostics.push(RuleDiagnostic::new(
-                            rule_category!(),
-                            None,
-                            markup! {
-                                "Dropping a column may break existing clients."
-                            },
-                        ).detail(None, "You can leave the column as nullable or delete the column once queries no longer select or modify the column."));
-                    }
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node
+                    && cmd.subtype() == pgt_query_ext::protobuf::AlterTableType::AtDropColumn
+                {
+                    let diagnostic = RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.node_ranges().alter_table_cmd(stmt, idx),
+                        markup! {
+                            "Dropping a column may break existing clients."
+                        },
+                    ).detail(None, "You can leave the column as nullable or delete the column once queries no longer select or modify the column.");
+
+                    let views = stmt
+                        .relation
+                        .as_ref()
+                        .map(|relation| dependent_views(ctx, relation, &cmd.name))
+                        .unwrap_or_default();
+
+                    diagnostics.push(if views.is_empty() {
+                        diagnostic
+                    } else if cmd.behavior() == pgt_query_ext::protobuf::DropBehavior::DropCascade {
+                        diagnostic.footer_list(
+                            "The column is referenced by these views, which are dropped as well:",
+                            &views,
+                        )
+                    } else {
+                        diagnostic.footer_list(
+                            "The column is referenced by these views, so dropping it fails unless they are dropped first:",
+                            &views,
+                        )
+                    });
                 }
             }
         }
@@ -49,3 +71,30 @@ impl Rule for BanDropColumn {
         diagnostics
     }
 }
+
+/// Returns the names of the views that reference the column, if the schema is available.
+fn dependent_views(
+    ctx: &RuleContext<BanDropColumn>,
+    relation: &pgt_query_ext::protobuf::RangeVar,
+    column: &str,
+) -> Vec<String> {
+    let Some(schema_cache) = ctx.schema_cache() else {
+        return vec![];
+    };
+
+    let schema = if relation.schemaname.is_empty() {
+        match schema_cache.find_table(&relation.relname, None) {
+            Some(table) => table.schema.as_str(),
+            None => return vec![],
+        }
+    } else {
+        relation.schemaname.as_str()
+    };
+
+    schema_cache
+        .views()
+        .iter()
+        .filter(|view| view.depends_on_column(schema, &relation.relname, column))
+        .map(|view| format!("{}.{}", view.schema, view.name))
+        .collect()
+}
@@ -31,17 +31,17 @@ impl Rule for BanDropNotNull {
         let mut diagnostics = Vec::new();
 
         if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
-            for cmd in &stmt.cmds {
-                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
-                    if cmd.subtype() == pgt_query_ext::protobuf::AlterTableType::AtDropNotNull {
-                        diagnostics.push(RuleDiagnostic::new(
-                            rule_category!(),
-                            None,
-                            markup! {
-                                "Dropping a NOT NULL constraint may break existing clients."
-                            },
-                        ).detail(None, "Consider using a marker value that represents NULL. Alternatively, create a new table allowing NULL values, copy the data from the old table, and create a view that filters NULL values."));
-                    }
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node
+                    && cmd.subtype() == pgt_query_ext::protobuf::AlterTableType::AtDropNotNull
+                {
+                    diagnostics.push(RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.node_ranges().alter_table_cmd(stmt, idx),
+                        markup! {
+                            "Dropping a NOT NULL constraint may break existing clients."
+                        },
+                    ).detail(None, "Consider using a marker value that represents NULL. Alternatively, create a new table allowing NULL values, copy the data from the old table, and create a view that filters NULL values."));
                 }
             }
         }
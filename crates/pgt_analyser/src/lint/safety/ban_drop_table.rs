@@ -29,22 +29,22 @@ impl Rule for BanDropTable {
     fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
         let mut diagnostics = vec![];
 
-        if let pgt_query_ext::NodeEnum::DropStmt(stmt) = &ctx.stmt() {
-            if stmt.remove_type() == pgt_query_ext::protobuf::ObjectType::ObjectTable {
-                diagnostics.push(
-                    RuleDiagnostic::new(
-                        rule_category!(),
-                        None,
-                        markup! {
-                            "Dropping a table may break existing clients."
-                        },
-                    )
-                    .detail(
-                        None,
-                        "Update your application code to no longer read or write the table, and only then delete the table. Be sure to create a backup.",
-                    ),
-                );
-            }
+        if let pgt_query_ext::NodeEnum::DropStmt(stmt) = &ctx.stmt()
+            && stmt.remove_type() == pgt_query_ext::protobuf::ObjectType::ObjectTable
+        {
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "Dropping a table may break existing clients."
+                    },
+                )
+                .detail(
+                    None,
+                    "Update your application code to no longer read or write the table, and only then delete the table. Be sure to create a backup.",
+                ),
+            );
         }
 
         diagnostics
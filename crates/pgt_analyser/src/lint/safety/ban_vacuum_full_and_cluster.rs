@@ -0,0 +1,84 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::NodeEnum;
+use pgt_query_ext::protobuf::VacuumStmt;
+
+declare_lint_rule! {
+    /// `VACUUM FULL` and `CLUSTER` rewrite the whole table while blocking reads and writes.
+    ///
+    /// Both commands take an ACCESS EXCLUSIVE lock on the table for as long as it takes to write a new copy of it and rebuild all of its indexes, which can be hours for large tables.
+    ///
+    /// Use a plain `VACUUM` to make dead rows reusable, or an extension like `pg_repack` or `pg_squeeze` to reclaim disk space or reorder a table without blocking.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// vacuum full users;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// vacuum (analyze) users;
+    /// ```
+    ///
+    pub BanVacuumFullAndCluster {
+        version: "next",
+        name: "banVacuumFullAndCluster",
+        recommended: false,
+    }
+}
+
+impl Rule for BanVacuumFullAndCluster {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let message = match ctx.stmt() {
+            NodeEnum::VacuumStmt(stmt) if is_vacuum_full(stmt) => Some(markup! {
+                "VACUUM FULL rewrites the whole table while blocking reads and writes."
+            }),
+            NodeEnum::ClusterStmt(_) => Some(markup! {
+                "CLUSTER rewrites the whole table while blocking reads and writes."
+            }),
+            _ => None,
+        };
+
+        if let Some(message) = message {
+            diagnostics.push(
+                RuleDiagnostic::new(rule_category!(), None, message).detail(
+                    None,
+                    "Use a plain VACUUM, or an extension like pg_repack or pg_squeeze to rewrite the table without blocking.",
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+/// Whether the statement is a `VACUUM` with the `FULL` option, as opposed to `ANALYZE` or `VACUUM (FULL false)`
+fn is_vacuum_full(stmt: &VacuumStmt) -> bool {
+    stmt.is_vacuumcmd
+        && stmt.options.iter().any(|option| {
+            let Some(NodeEnum::DefElem(option)) = &option.node else {
+                return false;
+            };
+            if option.defname != "full" {
+                return false;
+            }
+
+            match option.arg.as_ref().and_then(|arg| arg.node.as_ref()) {
+                None => true,
+                Some(NodeEnum::Boolean(value)) => value.boolval,
+                Some(NodeEnum::String(value)) => {
+                    matches!(value.sval.as_str(), "true" | "on" | "1")
+                }
+                Some(NodeEnum::Integer(value)) => value.ival != 0,
+                Some(_) => true,
+            }
+        })
+}
@@ -0,0 +1,158 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableCmd, AlterTableStmt, AlterTableType, Node, TypeName};
+
+/// The `oid` of `character varying`
+const VARCHAR_OID: i64 = 1043;
+
+declare_lint_rule! {
+    /// Changing the type of a column may rewrite the whole table while blocking reads and writes.
+    ///
+    /// Unless the old type can be converted to the new one without a rewrite, Postgres rewrites the table and rebuilds its indexes while holding an ACCESS EXCLUSIVE lock. Changing the type may also break clients that expect the old type.
+    ///
+    /// Add a new column with the new type instead, backfill it in batches, switch the application to the new column and then drop the old one.
+    ///
+    /// If a database is available, changes that do not rewrite the table are not reported, e.g. changing a `varchar(n)` column to `text` or to a longer `varchar`, unless a `USING` clause computes the new values.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users alter column id type bigint;
+    /// ```
+    ///
+    pub ChangingColumnType {
+        version: "next",
+        name: "changingColumnType",
+        recommended: false,
+        sources: &[RuleSource::Squawk("changing-column-type")],
+    }
+}
+
+impl Rule for ChangingColumnType {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = ctx.stmt() {
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                    continue;
+                };
+                if cmd.subtype() != AlterTableType::AtAlterColumnType
+                    || widens_varchar(ctx, stmt, cmd)
+                {
+                    continue;
+                }
+
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.node_ranges().alter_table_cmd(stmt, idx),
+                        markup! {
+                            "Changing the type of a column may rewrite the whole table while blocking reads and writes."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a new column with the new type, backfill it, switch the application to the new column and then drop the old one.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Whether the command changes a `varchar` column to `text` or to a `varchar` that is at least as
+/// long, which does not rewrite the table
+fn widens_varchar(
+    ctx: &RuleContext<ChangingColumnType>,
+    stmt: &AlterTableStmt,
+    cmd: &AlterTableCmd,
+) -> bool {
+    let (Some(schema_cache), Some(relation)) = (ctx.schema_cache(), stmt.relation.as_ref()) else {
+        return false;
+    };
+    let schema = (!relation.schemaname.is_empty()).then_some(relation.schemaname.as_str());
+    let Some(column) = schema_cache.find_col(&cmd.name, &relation.relname, schema) else {
+        return false;
+    };
+    if column.type_id != VARCHAR_OID {
+        return false;
+    }
+
+    let Some(pgt_query_ext::NodeEnum::ColumnDef(def)) =
+        cmd.def.as_ref().and_then(|def| def.node.as_ref())
+    else {
+        return false;
+    };
+    let Some(type_name) = def.type_name.as_ref() else {
+        return false;
+    };
+
+    // the `USING` expression is stored as the default, and any other expression than the column
+    // itself may change every value
+    if let Some(using) = def.raw_default.as_ref()
+        && !is_column_or_cast(using, &cmd.name, type_name)
+    {
+        return false;
+    }
+
+    match type_name_of(type_name).as_deref() {
+        Some("text") => true,
+        Some("varchar") => match (type_length(type_name), column.varchar_length) {
+            (None, _) => true,
+            (Some(new), Some(old)) => new >= old,
+            (Some(_), None) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a reference to the column, or a cast of it to the new type
+fn is_column_or_cast(expr: &Node, column: &str, new_type: &TypeName) -> bool {
+    match expr.node.as_ref() {
+        Some(pgt_query_ext::NodeEnum::ColumnRef(column_ref)) => {
+            matches!(
+                column_ref.fields.as_slice(),
+                [field] if matches!(
+                    field.node.as_ref(),
+                    Some(pgt_query_ext::NodeEnum::String(name)) if name.sval == column
+                )
+            )
+        }
+        Some(pgt_query_ext::NodeEnum::TypeCast(cast)) => {
+            cast.type_name.as_ref().is_some_and(|type_name| {
+                type_name_of(type_name) == type_name_of(new_type)
+                    && type_length(type_name) == type_length(new_type)
+            }) && cast
+                .arg
+                .as_ref()
+                .is_some_and(|arg| is_column_or_cast(arg, column, new_type))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the unqualified name of a type, e.g. `varchar` for `pg_catalog.varchar`
+fn type_name_of(type_name: &TypeName) -> Option<String> {
+    match type_name.names.last()?.node.as_ref()? {
+        pgt_query_ext::NodeEnum::String(name) => Some(name.sval.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the length of a type like `varchar(255)`
+fn type_length(type_name: &TypeName) -> Option<i32> {
+    match type_name.typmods.first()?.node.as_ref()? {
+        pgt_query_ext::NodeEnum::AConst(constant) => match constant.val.as_ref()? {
+            pgt_query_ext::protobuf::a_const::Val::Ival(ival) => Some(ival.ival),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,132 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_diagnostics::Applicability;
+use pgt_query_ext::protobuf::{AlterTableStmt, AlterTableType, ConstrType, Constraint};
+use pgt_text_size::TextRange;
+
+use crate::utils::quote_ident;
+
+declare_lint_rule! {
+    /// Adding a foreign key or check constraint without `NOT VALID` blocks writes while all existing rows are checked.
+    ///
+    /// Adding a check constraint holds an ACCESS EXCLUSIVE lock on the table until every row is checked. Adding a foreign key holds a SHARE ROW EXCLUSIVE lock on both the table and the referenced table, which blocks writes to both of them.
+    ///
+    /// Add the constraint with `NOT VALID` instead, so that only new and updated rows are checked, and validate the existing rows with `VALIDATE CONSTRAINT` in a separate transaction. Validating only takes a SHARE UPDATE EXCLUSIVE lock, which does not block reads and writes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table orders add constraint orders_user_fk foreign key (user_id) references users (id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table orders add constraint orders_user_fk foreign key (user_id) references users (id) not valid;
+    /// ```
+    ///
+    pub ConstraintMissingNotValid {
+        version: "next",
+        name: "constraintMissingNotValid",
+        recommended: false,
+        sources: &[
+            RuleSource::Squawk("constraint-missing-not-valid"),
+            RuleSource::Squawk("adding-foreign-key-constraint"),
+        ],
+    }
+}
+
+impl Rule for ConstraintMissingNotValid {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = ctx.stmt() {
+            for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                    continue;
+                };
+                if cmd.subtype() != AlterTableType::AtAddConstraint {
+                    continue;
+                }
+                let Some(pgt_query_ext::NodeEnum::Constraint(constraint)) =
+                    cmd.def.as_ref().and_then(|def| def.node.as_ref())
+                else {
+                    continue;
+                };
+                if constraint.skip_validation {
+                    continue;
+                }
+
+                let message = match constraint.contype() {
+                    ConstrType::ConstrForeign => markup! {
+                        "Adding a foreign key without NOT VALID blocks writes to both tables while all rows are checked."
+                    },
+                    ConstrType::ConstrCheck => markup! {
+                        "Adding a check constraint without NOT VALID blocks reads and writes while all rows are checked."
+                    },
+                    _ => continue,
+                };
+
+                let cmd_range = ctx.node_ranges().alter_table_cmd(stmt, idx);
+                let diagnostic = RuleDiagnostic::new(rule_category!(), cmd_range, message).detail(
+                    None,
+                    "Add the constraint with NOT VALID, and validate the existing rows with VALIDATE CONSTRAINT in a separate transaction.",
+                );
+                let diagnostic = match validate_constraint(ctx, stmt, constraint) {
+                    Some(validate) => diagnostic.note(markup! {
+                        "Once the constraint is added, run "<Emphasis>{validate}</Emphasis>" in a separate transaction."
+                    }),
+                    None => diagnostic,
+                };
+
+                diagnostics.push(match cmd_range.map(|range| add_not_valid(ctx, range)) {
+                    Some(fixed) => diagnostic.fix(
+                        Applicability::MaybeIncorrect,
+                        markup! { "Add the constraint with NOT VALID." },
+                        ctx.text(),
+                        &fixed,
+                    ),
+                    None => diagnostic,
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Appends `not valid` to the command at `cmd_range`. The constraint is validated by a separate
+/// statement, which must not run in the same transaction.
+fn add_not_valid(ctx: &RuleContext<ConstraintMissingNotValid>, cmd_range: TextRange) -> String {
+    let text = ctx.text();
+    let (before, after) = text.split_at(usize::from(cmd_range.end()));
+
+    format!("{before} not valid{after}")
+}
+
+/// Returns the statement that validates the constraint, e.g.
+/// `alter table orders validate constraint orders_user_fk;`. Only named constraints can be
+/// validated.
+fn validate_constraint(
+    ctx: &RuleContext<ConstraintMissingNotValid>,
+    stmt: &AlterTableStmt,
+    constraint: &Constraint,
+) -> Option<String> {
+    if constraint.conname.is_empty() {
+        return None;
+    }
+
+    // the statement up to its first command, e.g. `alter table orders `
+    let first_cmd = ctx.node_ranges().alter_table_cmd(stmt, 0)?;
+    let stmt_range = ctx.node_ranges().statement()?;
+
+    Some(format!(
+        "{prefix}validate constraint {name};",
+        prefix = &ctx.text()[TextRange::new(stmt_range.start(), first_cmd.start())],
+        name = quote_ident(&constraint.conname),
+    ))
+}
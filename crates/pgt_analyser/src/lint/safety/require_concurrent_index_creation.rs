@@ -0,0 +1,81 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_diagnostics::Applicability;
+use pgt_lexer::SyntaxKind;
+
+declare_lint_rule! {
+    /// Creating an index non-concurrently blocks writes to the table while the index is built.
+    ///
+    /// `CREATE INDEX` holds a SHARE lock on the table until the index is built, which can take a long time for large tables. Inserts, updates and deletes wait for it, and so does every query that waits for them.
+    ///
+    /// Use `CREATE INDEX CONCURRENTLY` to build the index without blocking writes. Note that it cannot run inside a transaction block, and that a failed build leaves an invalid index behind that has to be dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create index users_email_idx on users (email);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create index concurrently users_email_idx on users (email);
+    /// ```
+    ///
+    pub RequireConcurrentIndexCreation {
+        version: "next",
+        name: "requireConcurrentIndexCreation",
+        recommended: false,
+        sources: &[RuleSource::Squawk("require-concurrent-index-creation")],
+    }
+}
+
+impl Rule for RequireConcurrentIndexCreation {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::IndexStmt(stmt) = ctx.stmt()
+            && !stmt.concurrent
+        {
+            let diagnostic = RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Creating an index non-concurrently blocks writes to the table."
+                },
+            )
+            .detail(
+                None,
+                "Use CREATE INDEX CONCURRENTLY to build the index without blocking writes. It cannot run inside a transaction block.",
+            );
+
+            diagnostics.push(match add_concurrently(ctx) {
+                Some(fixed) => diagnostic.fix(
+                    Applicability::MaybeIncorrect,
+                    markup! { "Create the index concurrently." },
+                    ctx.text(),
+                    &fixed,
+                ),
+                None => diagnostic,
+            });
+        }
+
+        diagnostics
+    }
+}
+
+/// Inserts `concurrently` after the `index` keyword of the statement
+fn add_concurrently(ctx: &RuleContext<RequireConcurrentIndexCreation>) -> Option<String> {
+    let start = ctx.node_ranges().statement()?.start();
+    let index = ctx
+        .node_ranges()
+        .until(usize::from(start), SyntaxKind::Index)?;
+
+    let text = ctx.text();
+    let (before, after) = text.split_at(usize::from(index.end()));
+    Some(format!("{before} concurrently{after}"))
+}
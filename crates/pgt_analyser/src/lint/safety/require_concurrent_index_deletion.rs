@@ -0,0 +1,91 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_diagnostics::Applicability;
+use pgt_lexer::SyntaxKind;
+use pgt_query_ext::protobuf::{DropBehavior, DropStmt, ObjectType};
+
+declare_lint_rule! {
+    /// Dropping an index non-concurrently blocks reads and writes to the table.
+    ///
+    /// `DROP INDEX` holds an ACCESS EXCLUSIVE lock on the table of the index. While it waits for running queries to finish, every new query on the table waits as well.
+    ///
+    /// Use `DROP INDEX CONCURRENTLY` to wait for conflicting transactions without blocking other queries. Note that it cannot run inside a transaction block, drop more than one index at once, or use `CASCADE`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// drop index users_email_idx;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// drop index concurrently users_email_idx;
+    /// ```
+    ///
+    pub RequireConcurrentIndexDeletion {
+        version: "next",
+        name: "requireConcurrentIndexDeletion",
+        recommended: false,
+        sources: &[RuleSource::Squawk("require-concurrent-index-deletion")],
+    }
+}
+
+impl Rule for RequireConcurrentIndexDeletion {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::DropStmt(stmt) = ctx.stmt()
+            && stmt.remove_type() == ObjectType::ObjectIndex
+            && !stmt.concurrent
+        {
+            let diagnostic = RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Dropping an index non-concurrently blocks reads and writes to the table."
+                },
+            )
+            .detail(
+                None,
+                "Use DROP INDEX CONCURRENTLY to drop the index without blocking queries. It cannot run inside a transaction block.",
+            );
+
+            diagnostics.push(match add_concurrently(ctx, stmt) {
+                Some(fixed) => diagnostic.fix(
+                    Applicability::MaybeIncorrect,
+                    markup! { "Drop the index concurrently." },
+                    ctx.text(),
+                    &fixed,
+                ),
+                None => diagnostic,
+            });
+        }
+
+        diagnostics
+    }
+}
+
+/// Inserts `concurrently` after the `index` keyword of the statement, if the statement can be run
+/// concurrently
+fn add_concurrently(
+    ctx: &RuleContext<RequireConcurrentIndexDeletion>,
+    stmt: &DropStmt,
+) -> Option<String> {
+    if stmt.objects.len() != 1 || stmt.behavior() == DropBehavior::DropCascade {
+        return None;
+    }
+
+    let start = ctx.node_ranges().statement()?.start();
+    let index = ctx
+        .node_ranges()
+        .until(usize::from(start), SyntaxKind::Index)?;
+
+    let text = ctx.text();
+    let (before, after) = text.split_at(usize::from(index.end()));
+    Some(format!("{before} concurrently{after}"))
+}
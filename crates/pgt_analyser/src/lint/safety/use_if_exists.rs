@@ -0,0 +1,150 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_diagnostics::Applicability;
+use pgt_lexer::SyntaxKind;
+use pgt_query_ext::{
+    NodeEnum,
+    protobuf::{AlterTableType, DropStmt, ObjectType},
+};
+use pgt_text_size::TextRange;
+
+declare_lint_rule! {
+    /// Dropping a table, index, column or constraint without `IF EXISTS` fails if the migration is run again.
+    ///
+    /// A migration that fails halfway, e.g. because of a lock timeout, has to be run again. If it is not wrapped in a transaction, the objects it already dropped are gone, so the statement fails the second time.
+    ///
+    /// Add `IF EXISTS` so that the statement only raises a notice if the object was already dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// drop index users_email_idx;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// drop index if exists users_email_idx;
+    /// alter table users drop column if exists email;
+    /// ```
+    ///
+    pub UseIfExists {
+        version: "next",
+        name: "useIfExists",
+        recommended: false,
+        sources: &[RuleSource::Squawk("prefer-robust-stmts")],
+    }
+}
+
+impl Rule for UseIfExists {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        match ctx.stmt() {
+            NodeEnum::DropStmt(stmt) if !stmt.missing_ok => {
+                let object = match stmt.remove_type() {
+                    ObjectType::ObjectTable => "a table",
+                    ObjectType::ObjectIndex => "an index",
+                    _ => return diagnostics,
+                };
+
+                diagnostics.push(with_fix(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! { "Dropping "{object}" without IF EXISTS fails if the migration is run again." },
+                    ),
+                    ctx,
+                    drop_keywords(ctx, stmt),
+                ));
+            }
+            NodeEnum::AlterTableStmt(stmt) => {
+                for (idx, cmd) in stmt.cmds.iter().enumerate() {
+                    let Some(NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                        continue;
+                    };
+                    if cmd.missing_ok {
+                        continue;
+                    }
+
+                    let (object, keyword) = match cmd.subtype() {
+                        AlterTableType::AtDropColumn => ("a column", SyntaxKind::Column),
+                        AlterTableType::AtDropConstraint => {
+                            ("a constraint", SyntaxKind::Constraint)
+                        }
+                        _ => continue,
+                    };
+
+                    let cmd_range = ctx.node_ranges().alter_table_cmd(stmt, idx);
+                    let keywords =
+                        cmd_range.and_then(|range| drop_cmd_keywords(ctx, range, keyword));
+
+                    diagnostics.push(with_fix(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            cmd_range,
+                            markup! { "Dropping "{object}" without IF EXISTS fails if the migration is run again." },
+                        ),
+                        ctx,
+                        keywords,
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+}
+
+/// Offers to insert `if exists` after the keywords that end at `keywords`
+fn with_fix(
+    diagnostic: RuleDiagnostic,
+    ctx: &RuleContext<UseIfExists>,
+    keywords: Option<TextRange>,
+) -> RuleDiagnostic {
+    let Some(keywords) = keywords else {
+        return diagnostic;
+    };
+
+    let text = ctx.text();
+    let (before, after) = text.split_at(usize::from(keywords.end()));
+
+    diagnostic.fix(
+        Applicability::Always,
+        markup! { "Add IF EXISTS." },
+        text,
+        &format!("{before} if exists{after}"),
+    )
+}
+
+/// Returns the range of e.g. `drop index concurrently`, after which `if exists` goes
+fn drop_keywords(ctx: &RuleContext<UseIfExists>, stmt: &DropStmt) -> Option<TextRange> {
+    let start = usize::from(ctx.node_ranges().statement()?.start());
+    let keyword = match stmt.remove_type() {
+        ObjectType::ObjectIndex if stmt.concurrent => SyntaxKind::Concurrently,
+        ObjectType::ObjectIndex => SyntaxKind::Index,
+        _ => SyntaxKind::Table,
+    };
+
+    ctx.node_ranges().until(start, keyword)
+}
+
+/// Returns the range of e.g. `drop column` in the command at `cmd_range`. The `column` keyword is
+/// optional, so it falls back to `drop`.
+fn drop_cmd_keywords(
+    ctx: &RuleContext<UseIfExists>,
+    cmd_range: TextRange,
+    keyword: SyntaxKind,
+) -> Option<TextRange> {
+    let start = usize::from(cmd_range.start());
+
+    ctx.node_ranges()
+        .until(start, keyword)
+        .filter(|range| range.end() <= cmd_range.end())
+        .or_else(|| ctx.node_ranges().token(start))
+}
@@ -1,10 +1,22 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use crate::lint;
+pub type AddingFieldWithDefault =
+    <lint::safety::adding_field_with_default::AddingFieldWithDefault as pgt_analyse::Rule>::Options;
+pub type AddingNotNullField =
+    <lint::safety::adding_not_null_field::AddingNotNullField as pgt_analyse::Rule>::Options;
 pub type AddingRequiredField =
     <lint::safety::adding_required_field::AddingRequiredField as pgt_analyse::Rule>::Options;
+pub type BanConcurrentIndexCreationInTransaction = < lint :: safety :: ban_concurrent_index_creation_in_transaction :: BanConcurrentIndexCreationInTransaction as pgt_analyse :: Rule > :: Options ;
 pub type BanDropColumn =
     <lint::safety::ban_drop_column::BanDropColumn as pgt_analyse::Rule>::Options;
 pub type BanDropNotNull =
     <lint::safety::ban_drop_not_null::BanDropNotNull as pgt_analyse::Rule>::Options;
 pub type BanDropTable = <lint::safety::ban_drop_table::BanDropTable as pgt_analyse::Rule>::Options;
+pub type BanVacuumFullAndCluster = < lint :: safety :: ban_vacuum_full_and_cluster :: BanVacuumFullAndCluster as pgt_analyse :: Rule > :: Options ;
+pub type ChangingColumnType =
+    <lint::safety::changing_column_type::ChangingColumnType as pgt_analyse::Rule>::Options;
+pub type ConstraintMissingNotValid = < lint :: safety :: constraint_missing_not_valid :: ConstraintMissingNotValid as pgt_analyse :: Rule > :: Options ;
+pub type RequireConcurrentIndexCreation = < lint :: safety :: require_concurrent_index_creation :: RequireConcurrentIndexCreation as pgt_analyse :: Rule > :: Options ;
+pub type RequireConcurrentIndexDeletion = < lint :: safety :: require_concurrent_index_deletion :: RequireConcurrentIndexDeletion as pgt_analyse :: Rule > :: Options ;
+pub type UseIfExists = <lint::safety::use_if_exists::UseIfExists as pgt_analyse::Rule>::Options;
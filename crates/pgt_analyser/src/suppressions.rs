@@ -0,0 +1,366 @@
+use pgt_analyse::MetadataRegistry;
+use pgt_diagnostics::{Category, Diagnostic, DiagnosticTags, MessageAndDescription, category};
+use pgt_lexer::SyntaxKind;
+use pgt_text_size::{TextRange, TextSize};
+
+/// The marker that starts every suppression comment
+const SUPPRESSION_PREFIX: &str = "pgt-ignore";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuppressionKind {
+    /// `-- pgt-ignore`, suppresses the statement following the comment
+    Statement,
+    /// `-- pgt-ignore-all`, suppresses the whole file
+    File,
+    /// `-- pgt-ignore-start`, suppresses everything up to the matching `-- pgt-ignore-end`
+    RangeStart,
+    /// `-- pgt-ignore-end`
+    RangeEnd,
+}
+
+#[derive(Debug)]
+struct Suppression {
+    /// The rule or group that is suppressed, e.g. `lint/safety/banDropColumn`
+    category: String,
+    /// The range of the suppression comment
+    comment_range: TextRange,
+    /// The range of the source the suppression applies to
+    target: Option<TextRange>,
+    /// Whether the suppression silenced at least one diagnostic
+    used: bool,
+}
+
+impl Suppression {
+    fn matches(&self, category: &Category) -> bool {
+        category
+            .name()
+            .strip_prefix(self.category.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+}
+
+/// The suppression comments of a file.
+///
+/// A suppression comment names the rule or group it silences, and optionally the reason why:
+///
+/// ```sql
+/// -- pgt-ignore lint/safety/banDropColumn: the column was unused for months
+/// alter table test drop column id;
+/// ```
+///
+/// `-- pgt-ignore-all <category>` silences the whole file, and the statements between
+/// `-- pgt-ignore-start <category>` and `-- pgt-ignore-end <category>` are silenced as well.
+#[derive(Debug)]
+pub struct Suppressions {
+    suppressions: Vec<Suppression>,
+    diagnostics: Vec<SuppressionDiagnostic>,
+}
+
+impl Suppressions {
+    /// Collects the suppression comments from the text of a file. The ranges of the statements
+    /// within the file are used to find the statement a `-- pgt-ignore` comment applies to.
+    pub(crate) fn new(
+        text: &str,
+        statement_ranges: &[TextRange],
+        metadata: &MetadataRegistry,
+    ) -> Self {
+        let mut suppressions = Self {
+            suppressions: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        // the comments cannot be told apart from strings without tokens
+        let Ok(tokens) = pgt_lexer::lex(text) else {
+            return suppressions;
+        };
+
+        let text_range = TextRange::new(TextSize::from(0), TextSize::of(text));
+        let mut open_ranges: Vec<Suppression> = Vec::new();
+
+        for token in tokens
+            .iter()
+            .filter(|token| token.kind == SyntaxKind::SqlComment)
+        {
+            let Some((kind, category)) = suppressions.parse_comment(&token.text, token.span) else {
+                continue;
+            };
+
+            if !is_known_category(&category, metadata) {
+                suppressions.diagnostics.push(SuppressionDiagnostic::new(
+                    category!("suppressions/unknownRule"),
+                    token.span,
+                    format!("Unknown lint rule or group \"{}\".", category),
+                ));
+                continue;
+            }
+
+            let suppression = Suppression {
+                category,
+                comment_range: token.span,
+                target: None,
+                used: false,
+            };
+
+            match kind {
+                SuppressionKind::Statement => {
+                    // the first statement that ends after the comment is either the one
+                    // containing it or the one following it
+                    let target = statement_ranges
+                        .iter()
+                        .find(|range| range.end() > token.span.end())
+                        .copied();
+                    suppressions.suppressions.push(Suppression {
+                        target,
+                        ..suppression
+                    });
+                }
+                SuppressionKind::File => suppressions.suppressions.push(Suppression {
+                    target: Some(text_range),
+                    ..suppression
+                }),
+                SuppressionKind::RangeStart => open_ranges.push(suppression),
+                SuppressionKind::RangeEnd => {
+                    match open_ranges
+                        .iter()
+                        .rposition(|start| start.category == suppression.category)
+                    {
+                        Some(idx) => {
+                            let start = open_ranges.remove(idx);
+                            suppressions.suppressions.push(Suppression {
+                                target: Some(TextRange::new(
+                                    start.comment_range.end(),
+                                    token.span.start(),
+                                )),
+                                ..start
+                            });
+                        }
+                        None => suppressions.diagnostics.push(SuppressionDiagnostic::new(
+                            category!("suppressions/parse"),
+                            token.span,
+                            format!(
+                                "There is no matching \"-- pgt-ignore-start {}\" for this comment.",
+                                suppression.category
+                            ),
+                        )),
+                    }
+                }
+            }
+        }
+
+        // a range without an end suppresses the rest of the file
+        suppressions
+            .suppressions
+            .extend(open_ranges.into_iter().map(|start| Suppression {
+                target: Some(TextRange::new(start.comment_range.end(), text_range.end())),
+                ..start
+            }));
+
+        suppressions
+    }
+
+    /// Parses a single comment. Returns `None` if it is not a suppression comment or if it is
+    /// malformed, in which case a diagnostic is recorded.
+    fn parse_comment(&mut self, text: &str, range: TextRange) -> Option<(SuppressionKind, String)> {
+        let content = text.strip_prefix("--")?.trim_start();
+        let rest = content.strip_prefix(SUPPRESSION_PREFIX)?;
+
+        let (kind, rest) = if let Some(rest) = rest.strip_prefix("-all") {
+            (SuppressionKind::File, rest)
+        } else if let Some(rest) = rest.strip_prefix("-start") {
+            (SuppressionKind::RangeStart, rest)
+        } else if let Some(rest) = rest.strip_prefix("-end") {
+            (SuppressionKind::RangeEnd, rest)
+        } else {
+            (SuppressionKind::Statement, rest)
+        };
+
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) && !rest.starts_with(':') {
+            self.diagnostics.push(SuppressionDiagnostic::new(
+                category!("suppressions/parse"),
+                range,
+                "Unknown suppression comment. Use \"pgt-ignore\", \"pgt-ignore-all\", \"pgt-ignore-start\" or \"pgt-ignore-end\".",
+            ));
+            return None;
+        }
+
+        // the reason is optional
+        let category = rest
+            .split_once(':')
+            .map_or(rest, |(category, _)| category)
+            .trim();
+
+        if category.is_empty() {
+            self.diagnostics.push(SuppressionDiagnostic::new(
+                category!("suppressions/parse"),
+                range,
+                "A suppression comment must name the rule or group it suppresses, e.g. \"-- pgt-ignore lint/safety/banDropColumn\".",
+            ));
+            return None;
+        }
+
+        Some((kind, category.to_string()))
+    }
+
+    /// Returns whether a diagnostic of the given category at the given range is suppressed,
+    /// and marks the matching suppressions as used.
+    pub fn is_suppressed(&mut self, category: &Category, range: TextRange) -> bool {
+        let mut suppressed = false;
+
+        for suppression in self.suppressions.iter_mut().filter(|suppression| {
+            suppression
+                .target
+                .is_some_and(|target| target.contains(range.start()))
+                && suppression.matches(category)
+        }) {
+            suppression.used = true;
+            suppressed = true;
+        }
+
+        suppressed
+    }
+
+    /// Returns the diagnostics for malformed and unknown suppressions, and, if
+    /// `report_unused` is set, for suppressions that did not suppress anything.
+    pub fn into_diagnostics(self, report_unused: bool) -> Vec<SuppressionDiagnostic> {
+        let mut diagnostics = self.diagnostics;
+
+        if report_unused {
+            diagnostics.extend(
+                self.suppressions
+                    .into_iter()
+                    .filter(|suppression| !suppression.used)
+                    .map(|suppression| {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/unused"),
+                            suppression.comment_range,
+                            format!(
+                                "Suppression comment has no effect, there is no \"{}\" diagnostic to suppress.",
+                                suppression.category
+                            ),
+                        )
+                        .with_tags(DiagnosticTags::UNNECESSARY_CODE)
+                    }),
+            );
+        }
+
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start());
+        diagnostics
+    }
+}
+
+/// Whether the category of a suppression is `lint`, a known group, or a known rule
+fn is_known_category(category: &str, metadata: &MetadataRegistry) -> bool {
+    let mut parts = category.split('/');
+
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some("lint"), None, None, None) => true,
+        (Some("lint"), Some(group), None, None) => metadata.find_group(group).is_some(),
+        (Some("lint"), Some(group), Some(rule), None) => metadata.find_rule(group, rule).is_some(),
+        _ => false,
+    }
+}
+
+/// A diagnostic for a malformed, unknown or unused suppression comment
+#[derive(Debug, Diagnostic)]
+#[diagnostic(severity = Warning)]
+pub struct SuppressionDiagnostic {
+    #[category]
+    category: &'static Category,
+    #[location(span)]
+    span: TextRange,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+    #[tags]
+    tags: DiagnosticTags,
+}
+
+impl SuppressionDiagnostic {
+    fn new(category: &'static Category, span: TextRange, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            span,
+            message: MessageAndDescription::from(message.into()),
+            tags: DiagnosticTags::empty(),
+        }
+    }
+
+    fn with_tags(mut self, tags: DiagnosticTags) -> Self {
+        self.tags |= tags;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_diagnostics::{Diagnostic, category};
+    use pgt_text_size::TextRange;
+
+    use crate::METADATA;
+
+    use super::Suppressions;
+
+    fn statement_ranges(sql: &str) -> Vec<TextRange> {
+        pgt_statement_splitter::split(sql)
+            .expect("failed to split SQL")
+            .ranges
+    }
+
+    fn diagnostic_categories(suppressions: Suppressions) -> Vec<&'static str> {
+        suppressions
+            .into_diagnostics(true)
+            .iter()
+            .map(|d| d.category().unwrap().name())
+            .collect()
+    }
+
+    #[test]
+    fn suppresses_the_following_statement() {
+        let sql = "-- pgt-ignore lint/safety/banDropColumn: no longer read\nalter table test drop column id;\nalter table test drop column name;";
+        let ranges = statement_ranges(sql);
+        let mut suppressions = Suppressions::new(sql, &ranges, &METADATA);
+
+        assert!(suppressions.is_suppressed(category!("lint/safety/banDropColumn"), ranges[0]));
+        assert!(!suppressions.is_suppressed(category!("lint/safety/banDropColumn"), ranges[1]));
+        assert!(!suppressions.is_suppressed(category!("lint/safety/banDropTable"), ranges[0]));
+        assert!(diagnostic_categories(suppressions).is_empty());
+    }
+
+    #[test]
+    fn suppresses_files_and_ranges() {
+        let sql = "-- pgt-ignore-all lint/safety/banDropTable\ndrop table a;\n-- pgt-ignore-start lint/safety\nalter table test drop column id;\n-- pgt-ignore-end lint/safety\nalter table test drop column name;";
+        let ranges = statement_ranges(sql);
+        let mut suppressions = Suppressions::new(sql, &ranges, &METADATA);
+
+        assert!(suppressions.is_suppressed(category!("lint/safety/banDropTable"), ranges[0]));
+        assert!(suppressions.is_suppressed(category!("lint/safety/banDropColumn"), ranges[1]));
+        assert!(!suppressions.is_suppressed(category!("lint/safety/banDropColumn"), ranges[2]));
+        assert!(diagnostic_categories(suppressions).is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_and_unused_suppressions() {
+        let sql = "-- pgt-ignore lint/safety/banDropColumn\nselect 1;\n-- pgt-ignore lint/safety/banEverything\nselect 2;\n-- pgt-ignore\nselect 3;\n-- pgt-ignore-end lint\n-- pgt-ignored, not a suppression\nselect '-- pgt-ignore';";
+        let ranges = statement_ranges(sql);
+        let suppressions = Suppressions::new(sql, &ranges, &METADATA);
+
+        assert_eq!(
+            diagnostic_categories(suppressions),
+            vec![
+                "suppressions/unused",
+                "suppressions/unknownRule",
+                "suppressions/parse",
+                "suppressions/parse",
+                "suppressions/parse",
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_report_unused_suppressions_on_request() {
+        let sql = "-- pgt-ignore lint\nselect 1;";
+        let suppressions = Suppressions::new(sql, &statement_ranges(sql), &METADATA);
+
+        assert!(suppressions.into_diagnostics(false).is_empty());
+    }
+}
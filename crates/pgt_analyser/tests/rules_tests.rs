@@ -2,9 +2,10 @@ use core::slice;
 use std::{fmt::Write, fs::read_to_string, path::Path};
 
 use pgt_analyse::{AnalyserOptions, AnalysisFilter, RuleDiagnostic, RuleFilter};
-use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext};
+use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext, FileAnalyserContext};
 use pgt_console::StdDisplay;
-use pgt_diagnostics::PrintDiagnostic;
+use pgt_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use pgt_schema_cache::Version;
 
 pgt_test_macros::gen_tests! {
   "tests/specs/**/*.sql",
@@ -32,10 +33,43 @@ fn rule_test(full_path: &'static str, _: &str, _: &str) {
         filter,
     });
 
-    let results = analyser.run(AnalyserContext { root: &ast });
+    let version = parse_version(&query);
+
+    let mut results = analyser.run(AnalyserContext {
+        root: &ast,
+        text: &query,
+        schema_cache: None,
+        version: version.as_ref(),
+    });
+
+    // rules that look at the whole file see every statement, e.g. to know whether it runs
+    // inside a transaction block
+    let statements: Vec<_> = pgt_statement_splitter::split(&query)
+        .expect("failed to split SQL")
+        .ranges
+        .into_iter()
+        .map(|range| {
+            let ast = pgt_query_ext::parse(&query[range]).expect("failed to parse SQL");
+            (ast, range)
+        })
+        .collect();
+    results.extend(
+        analyser.run_file(FileAnalyserContext {
+            statements: statements
+                .iter()
+                .map(|(ast, range)| (ast, *range))
+                .collect(),
+            text: &query,
+            schema_cache: None,
+            version: version.as_ref(),
+        }),
+    );
+
+    let expectation = Expectation::from_file(&query);
+    expectation.assert(results.as_slice());
 
     let mut snapshot = String::new();
-    write_snapshot(&mut snapshot, query.as_str(), results.as_slice());
+    write_snapshot(&mut snapshot, query.as_str(), results);
 
     insta::with_settings!({
         prepend_module_to_snapshot => false,
@@ -43,9 +77,23 @@ fn rule_test(full_path: &'static str, _: &str, _: &str) {
     }, {
         insta::assert_snapshot!(fname, snapshot);
     });
+}
 
-    let expectation = Expectation::from_file(&query);
-    expectation.assert(results.as_slice());
+/// Reads the version of the database the test runs against from a `-- postgres_version: <num>`
+/// comment, e.g. `-- postgres_version: 100000` for Postgres 10.
+fn parse_version(content: &str) -> Option<Version> {
+    content.lines().find_map(|line| {
+        let version_num = line.trim().strip_prefix("-- postgres_version:")?;
+        Some(Version {
+            version_num: Some(
+                version_num
+                    .trim()
+                    .parse()
+                    .expect("Invalid postgres_version"),
+            ),
+            ..Default::default()
+        })
+    })
 }
 
 fn parse_test_path(path: &Path) -> (String, String, String) {
@@ -61,7 +109,7 @@ fn parse_test_path(path: &Path) -> (String, String, String) {
     (group.into(), rule.into(), fname.into())
 }
 
-fn write_snapshot(snapshot: &mut String, query: &str, diagnostics: &[RuleDiagnostic]) {
+fn write_snapshot(snapshot: &mut String, query: &str, diagnostics: Vec<RuleDiagnostic>) {
     writeln!(snapshot, "# Input").unwrap();
     writeln!(snapshot, "```").unwrap();
     writeln!(snapshot, "{query}").unwrap();
@@ -71,7 +119,8 @@ fn write_snapshot(snapshot: &mut String, query: &str, diagnostics: &[RuleDiagnos
     if !diagnostics.is_empty() {
         writeln!(snapshot, "# Diagnostics").unwrap();
         for diagnostic in diagnostics {
-            let printer = PrintDiagnostic::simple(diagnostic);
+            let diagnostic = diagnostic.with_file_source_code(query.to_string());
+            let printer = PrintDiagnostic::simple(&diagnostic);
 
             writeln!(snapshot, "{}", StdDisplay(printer)).unwrap();
             writeln!(snapshot).unwrap();
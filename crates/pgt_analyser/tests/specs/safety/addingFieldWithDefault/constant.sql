@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users add column status text default 'active', add column score int default 0;
\ No newline at end of file
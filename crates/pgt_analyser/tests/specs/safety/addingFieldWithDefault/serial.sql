@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingFieldWithDefault
+alter table users add column seq bigserial;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users add column created_at timestamptz default now();
\ No newline at end of file
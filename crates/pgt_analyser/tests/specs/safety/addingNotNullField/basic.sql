@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingNotNullField
+alter table users alter column email set not null;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users alter column email drop not null;
\ No newline at end of file
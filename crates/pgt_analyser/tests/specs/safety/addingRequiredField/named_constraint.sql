@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingRequiredField
+alter table public.users add column "Score" int constraint score_required not null check ("Score" > 0);
\ No newline at end of file
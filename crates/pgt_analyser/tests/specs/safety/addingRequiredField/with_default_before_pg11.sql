@@ -0,0 +1,4 @@
+-- expect_only_lint/safety/addingRequiredField
+-- postgres_version: 100000
+alter table test
+add column c int not null default 0;
\ No newline at end of file
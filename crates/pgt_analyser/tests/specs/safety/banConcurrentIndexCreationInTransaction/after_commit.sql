@@ -0,0 +1,5 @@
+-- expect_no_diagnostics
+begin;
+alter table users add column email text;
+commit;
+create index concurrently users_email_idx on users (email);
\ No newline at end of file
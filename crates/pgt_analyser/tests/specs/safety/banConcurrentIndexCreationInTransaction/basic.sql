@@ -0,0 +1,4 @@
+-- expect_only_lint/safety/banConcurrentIndexCreationInTransaction
+begin;
+create index concurrently users_email_idx on users (email);
+commit;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- expect_only_lint/safety/banConcurrentIndexCreationInTransaction
+start transaction isolation level read committed;
+set local lock_timeout = '5s';
+create unique index concurrently if not exists users_email_idx on users (email);
\ No newline at end of file
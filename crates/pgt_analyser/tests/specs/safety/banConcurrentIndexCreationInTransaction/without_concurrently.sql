@@ -0,0 +1,4 @@
+-- expect_no_diagnostics
+begin;
+create index users_email_idx on users (email);
+commit;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- expect_only_lint/safety/banDropColumn
+alter table test
+    add column email text,
+    drop column id;
\ No newline at end of file
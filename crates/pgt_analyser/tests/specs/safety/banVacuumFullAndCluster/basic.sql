@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banVacuumFullAndCluster
+vacuum full users;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banVacuumFullAndCluster
+cluster users using users_pkey;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+vacuum (full false, analyze) users;
\ No newline at end of file
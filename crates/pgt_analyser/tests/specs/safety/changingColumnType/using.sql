@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/changingColumnType
+alter table users alter column score set data type numeric using score::numeric;
\ No newline at end of file
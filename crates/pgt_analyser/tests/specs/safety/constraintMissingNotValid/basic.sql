@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/constraintMissingNotValid
+alter table orders add constraint orders_user_id_fkey foreign key (user_id) references users (id);
\ No newline at end of file
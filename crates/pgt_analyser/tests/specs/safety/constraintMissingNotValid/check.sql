@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/constraintMissingNotValid
+alter table orders add constraint "Positive Amount" check (amount > 0);
\ No newline at end of file
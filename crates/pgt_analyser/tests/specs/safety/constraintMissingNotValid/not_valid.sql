@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table orders add constraint orders_user_id_fkey foreign key (user_id) references users (id) not valid;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table orders validate constraint orders_user_id_fkey;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/requireConcurrentIndexCreation
+create index users_email_idx on users (email);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/requireConcurrentIndexCreation
+create unique index if not exists users_email_idx on public.users using btree (email);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/requireConcurrentIndexDeletion
+drop index users_email_idx;
\ No newline at end of file
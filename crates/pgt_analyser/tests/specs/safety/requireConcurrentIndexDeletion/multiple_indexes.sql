@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/requireConcurrentIndexDeletion
+drop index if exists users_email_idx, users_name_idx cascade;
\ No newline at end of file
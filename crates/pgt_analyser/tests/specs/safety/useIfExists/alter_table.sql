@@ -0,0 +1,2 @@
+-- each of the commands is reported
+alter table users drop column email, drop "Name", drop constraint users_age_check;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/useIfExists
+drop index concurrently users_email_idx;
\ No newline at end of file
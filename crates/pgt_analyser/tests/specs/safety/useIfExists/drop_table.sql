@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/useIfExists
+drop table users;
\ No newline at end of file
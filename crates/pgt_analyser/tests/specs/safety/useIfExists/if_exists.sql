@@ -0,0 +1,5 @@
+-- expect_no_diagnostics
+drop table if exists users;
+drop index concurrently if exists users_email_idx;
+alter table users drop column if exists email, drop constraint if exists users_age_check;
+drop view users_view;
\ No newline at end of file
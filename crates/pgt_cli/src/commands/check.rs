@@ -4,6 +4,7 @@ use biome_deserialize::Merge;
 use pgt_configuration::PartialConfiguration;
 use pgt_console::Console;
 use pgt_fs::FileSystem;
+use pgt_workspace::features::fix_file::FixFileMode;
 use pgt_workspace::{DynRef, Workspace, WorkspaceError, configuration::LoadedConfiguration};
 use std::ffi::OsString;
 
@@ -13,6 +14,8 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) write: bool,
+    pub(crate) unsafe_: bool,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
@@ -67,10 +70,30 @@ impl CommandRunner for CheckCommandPayload {
         console: &mut dyn Console,
         _workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
+        let fix_file_mode = if !self.write {
+            None
+        } else if self.unsafe_ {
+            Some(FixFileMode::SafeAndUnsafeFixes)
+        } else {
+            Some(FixFileMode::SafeFixes)
+        };
+
         Ok(Execution::new(TraversalMode::Check {
+            fix_file_mode,
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.unsafe_ && !self.write {
+            return Err(CliDiagnostic::missing_argument(
+                "--write",
+                Self::COMMAND_NAME,
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,48 @@
+use crate::cli_options::CliOptions;
+use crate::{CliDiagnostic, CliSession, setup_cli_subscriber};
+use biome_deserialize::Merge;
+use pgt_configuration::PartialConfiguration;
+use pgt_console::{ConsoleExt, markup};
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::workspace::UpdateSettingsParams;
+use std::path::PathBuf;
+
+/// Runs the dump-schema command
+pub(crate) fn dump_schema(
+    session: CliSession,
+    cli_options: &CliOptions,
+    configuration: Option<PartialConfiguration>,
+    output: PathBuf,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    let fs = &session.app.fs;
+    let mut fs_configuration =
+        load_configuration(fs, cli_options.as_configuration_path_hint())?.configuration;
+
+    if let Some(configuration) = configuration {
+        // overwrite fs config with cli args
+        fs_configuration.merge_with(configuration);
+    }
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            workspace_directory: fs.working_directory(),
+            configuration: fs_configuration,
+            vcs_base_path: None,
+            gitignore_matches: vec![],
+            skip_db: false,
+        })?;
+
+    let snapshot = session.app.workspace.dump_schema_snapshot()?;
+    std::fs::write(&output, snapshot)?;
+
+    let output = output.display().to_string();
+    session.app.console.log(markup! {
+        "Schema snapshot written to "<Emphasis>{output}</Emphasis>
+    });
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ use std::path::PathBuf;
 pub(crate) mod check;
 pub(crate) mod clean;
 pub(crate) mod daemon;
+pub(crate) mod dump_schema;
 pub(crate) mod init;
 pub(crate) mod version;
 
@@ -47,6 +48,15 @@ pub enum PgtCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Applies the safe fixes offered by the lint rules to the files.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// Also applies the unsafe fixes, e.g. fixes that change the semantics of a statement or
+        /// have to be completed by hand. Requires `--write`.
+        #[bpaf(long("unsafe"), switch)]
+        unsafe_: bool,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted. This option should be used when working locally.
         #[bpaf(long("staged"), switch)]
@@ -67,6 +77,22 @@ pub enum PgtCommand {
         paths: Vec<OsString>,
     },
 
+    /// Writes a snapshot of the database schema to a file.
+    ///
+    /// Set `db.schemaSnapshot` to the file to get schema-aware features without a running database.
+    #[bpaf(command("dump-schema"))]
+    DumpSchema {
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The file the snapshot is written to.
+        #[bpaf(long("output"), argument("PATH"))]
+        output: PathBuf,
+    },
+
     /// Starts the daemon server process.
     #[bpaf(command)]
     Start {
@@ -194,9 +220,9 @@ pub enum PgtCommand {
 impl PgtCommand {
     const fn cli_options(&self) -> Option<&CliOptions> {
         match self {
-            PgtCommand::Version(cli_options) | PgtCommand::Check { cli_options, .. } => {
-                Some(cli_options)
-            }
+            PgtCommand::Version(cli_options)
+            | PgtCommand::Check { cli_options, .. }
+            | PgtCommand::DumpSchema { cli_options, .. } => Some(cli_options),
             PgtCommand::LspProxy { .. }
             | PgtCommand::Start { .. }
             | PgtCommand::Stop
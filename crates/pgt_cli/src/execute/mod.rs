@@ -11,6 +11,7 @@ use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
 use pgt_diagnostics::{Category, category};
+use pgt_workspace::features::fix_file::FixFileMode;
 use std::borrow::Borrow;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
@@ -78,9 +79,8 @@ pub enum TraversalMode {
     Check {
         /// The type of fixes that should be applied when analyzing a file.
         ///
-        /// It's [None] if the `check` command is called without `--apply` or `--apply-suggested`
-        /// arguments.
-        // fix_file_mode: Option<FixFileMode>,
+        /// It's [None] if the `check` command is called without `--write`.
+        fix_file_mode: Option<FixFileMode>,
 
         /// An optional tuple.
         /// 1. The virtual path to the file
@@ -163,10 +163,7 @@ impl Execution {
 
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
-        match self.traversal_mode {
-            TraversalMode::Dummy => false,
-            TraversalMode::Check { .. } => false,
-        }
+        self.is_write()
     }
 
     pub(crate) fn as_stdin_file(&self) -> Option<&Stdin> {
@@ -186,15 +183,22 @@ impl Execution {
     }
 
     pub(crate) const fn is_check_apply(&self) -> bool {
-        false
+        self.is_write()
     }
 
-    #[allow(unused)]
-    /// Returns [true] if the user used the `--write`/`--fix` option
-    pub(crate) fn is_write(&self) -> bool {
+    /// Returns [true] if the user used the `--write` option
+    pub(crate) const fn is_write(&self) -> bool {
         match self.traversal_mode {
             TraversalMode::Dummy => false,
-            TraversalMode::Check { .. } => false,
+            TraversalMode::Check { fix_file_mode, .. } => fix_file_mode.is_some(),
+        }
+    }
+
+    /// Returns the type of fixes to apply, or [None] if fixes are not applied
+    pub(crate) fn fix_file_mode(&self) -> Option<FixFileMode> {
+        match self.traversal_mode {
+            TraversalMode::Dummy => None,
+            TraversalMode::Check { fix_file_mode, .. } => fix_file_mode,
         }
     }
 }
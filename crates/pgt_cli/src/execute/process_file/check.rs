@@ -7,6 +7,10 @@ use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraver
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
+/// The maximum number of times the fixes are applied to a file, in case fixes keep producing
+/// new fixes
+const MAX_FIX_PASSES: usize = 10;
+
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn check_file<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
@@ -22,8 +26,39 @@ pub(crate) fn check_with_guard<'ctx>(
 ) -> FileResult {
     tracing::info_span!("Processes check", path =? workspace_file.path.display()).in_scope(
         move || {
-            let input = workspace_file.input()?;
-            let changed = false;
+            let mut input = workspace_file.input()?;
+            let mut changed = false;
+
+            if let Some(fix_file_mode) = ctx.execution.fix_file_mode() {
+                let mut skipped_suggested_fixes = 0;
+
+                // a fix replaces the whole statement, so at most one fix per statement is applied
+                // in each pass
+                for _ in 0..MAX_FIX_PASSES {
+                    let fixed = workspace_file
+                        .guard()
+                        .fix_file(fix_file_mode, Vec::new(), Vec::new())
+                        .with_file_path_and_code(
+                            workspace_file.path.display().to_string(),
+                            category!("check"),
+                        )?;
+                    skipped_suggested_fixes = fixed.skipped_suggested_fixes;
+
+                    if fixed.fixes_applied == 0 || fixed.code == input {
+                        break;
+                    }
+
+                    workspace_file.update_file(fixed.code.as_str())?;
+                    input = fixed.code;
+                    changed = true;
+                }
+
+                if skipped_suggested_fixes > 0 {
+                    ctx.push_message(Message::SkippedFixes {
+                        skipped_suggested_fixes,
+                    });
+                }
+            }
 
             let (only, skip) = (Vec::new(), Vec::new());
 
@@ -71,6 +71,8 @@ impl<'app> CliSession<'app> {
                 configuration,
                 paths,
                 stdin_file_path,
+                write,
+                unsafe_,
                 staged,
                 changed,
                 since,
@@ -81,11 +83,18 @@ impl<'app> CliSession<'app> {
                     configuration,
                     paths,
                     stdin_file_path,
+                    write,
+                    unsafe_,
                     staged,
                     changed,
                     since,
                 },
             ),
+            PgtCommand::DumpSchema {
+                cli_options,
+                configuration,
+                output,
+            } => commands::dump_schema::dump_schema(self, &cli_options, configuration, output),
             PgtCommand::Clean => commands::clean::clean(self),
             PgtCommand::Start {
                 config_path,
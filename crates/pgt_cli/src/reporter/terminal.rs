@@ -185,6 +185,12 @@ impl fmt::Display for ConsoleTraversalSummary<'_> {
                 fmt.write_markup(markup!("\n"<Warn>"Found "{self.1.warnings}" warnings."</Warn>))?;
             }
         }
+        if self.1.suggested_fixes_skipped > 0 {
+            fmt.write_markup(markup!(
+                "\n"<Warn>"Skipped "{self.1.suggested_fixes_skipped}" unsafe fixes."</Warn>
+                "\nIf you wish to apply them, use the command "<Emphasis>"postgrestools check --write --unsafe"</Emphasis>"."
+            ))?;
+        }
         Ok(())
     }
 }
@@ -3,7 +3,7 @@ use std::path::Path;
 
 use crate::run_cli;
 use pgt_console::BufferConsole;
-use pgt_fs::MemoryFileSystem;
+use pgt_fs::{FileSystemExt, MemoryFileSystem};
 use pgt_workspace::DynRef;
 
 #[test]
@@ -22,3 +22,211 @@ fn syntax_error() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 }
+
+#[test]
+fn write_applies_safe_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("postgrestools.jsonc").into(),
+        r#"{ "linter": { "rules": { "safety": { "useIfExists": "warn" } } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "drop index users_email_idx;\nalter table users drop constraint users_age_check;"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut content = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(
+        content,
+        "drop index if exists users_email_idx;\nalter table users drop constraint if exists users_age_check;"
+    );
+}
+
+#[test]
+fn write_applies_unsafe_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("postgrestools.jsonc").into(),
+        r#"{ "linter": { "rules": { "safety": { "addingRequiredField": "warn" } } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "alter table users add column age int not null;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write",
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut content = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(
+        content,
+        "alter table users add column age int;\n-- backfill the existing rows of age here\nalter table users alter column age set not null;"
+    );
+}
+
+#[test]
+fn unsafe_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(file_path.into(), "select 1;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn reports_index_created_concurrently_in_transaction() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "begin;\ncreate index concurrently users_email_idx on users (email);\ncommit;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn suppresses_ranges_within_a_statement() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "alter table users\n  add column age int,\n  -- pgt-ignore-start lint/safety/banDropColumn: no longer read\n  drop column email;\n-- pgt-ignore-end lint/safety/banDropColumn".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn suppresses_file_level_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "begin;\n-- pgt-ignore lint/safety/banConcurrentIndexCreationInTransaction: runs outside of a transaction\ncreate index concurrently users_email_idx on users (email);\ncommit;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn skip_db_ignores_invalid_connection_settings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("postgrestools.jsonc").into(),
+        r#"{
+            "db": { "connectionString": "mysql://localhost/app" },
+            "linter": { "rules": { "safety": { "useIfExists": "warn" } } }
+        }"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("test.sql");
+    fs.insert(file_path.into(), "drop index users_email_idx;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--skip-db",
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut content = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(content, "drop index if exists users_email_idx;");
+}
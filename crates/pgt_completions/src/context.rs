@@ -15,6 +15,7 @@ pub enum ClauseType {
     From,
     Update,
     Delete,
+    Insert,
 }
 
 impl TryFrom<&str> for ClauseType {
@@ -27,6 +28,7 @@ impl TryFrom<&str> for ClauseType {
             "from" | "keyword_from" => Ok(Self::From),
             "update" => Ok(Self::Update),
             "delete" => Ok(Self::Delete),
+            "insert" => Ok(Self::Insert),
             _ => {
                 let message = format!("Unimplemented ClauseType: {}", value);
 
@@ -248,6 +250,10 @@ mod tests {
             ),
             (format!("delete{} from users;", CURSOR_POS), "delete"),
             (format!("delete from {}users;", CURSOR_POS), "from"),
+            (
+                format!("insert into users (id, na{}) values (1, 'a');", CURSOR_POS),
+                "insert",
+            ),
             (
                 format!("select name, age, location from public.u{}sers", CURSOR_POS),
                 "from",
@@ -1,12 +1,24 @@
 use crate::{
-    CompletionItem, CompletionItemKind, builder::CompletionBuilder, context::CompletionContext,
+    CompletionItem, CompletionItemKind,
+    builder::CompletionBuilder,
+    context::{ClauseType, CompletionContext},
     relevance::CompletionRelevanceData,
 };
 
 pub fn complete_columns(ctx: &CompletionContext, builder: &mut CompletionBuilder) {
-    let available_columns = &ctx.schema_cache.columns;
+    let available_columns = ctx.schema_cache.columns();
+
+    // Postgres rejects values for generated columns, so there's no point in suggesting them.
+    let skip_generated = matches!(
+        ctx.wrapping_clause_type,
+        Some(ClauseType::Insert) | Some(ClauseType::Update)
+    );
 
     for col in available_columns {
+        if skip_generated && col.generation.is_generated_always() {
+            continue;
+        }
+
         let item = CompletionItem {
             label: col.name.clone(),
             score: CompletionRelevanceData::Column(col).get_score(ctx),
@@ -151,4 +163,43 @@ mod tests {
 
         assert_eq!(labels, vec!["name", "narrator", "narrator_id"]);
     }
+
+    #[tokio::test]
+    async fn skips_generated_columns_in_insert() {
+        let setup = r#"
+            create table public.rooms (
+                id int generated always as identity primary key,
+                width smallint not null,
+                length smallint not null,
+                area int generated always as (width * length) stored
+            );
+        "#;
+
+        let test_cases = vec![
+            (
+                format!(r#"insert into rooms (wi{}) values (1);"#, CURSOR_POS),
+                true,
+            ),
+            (
+                format!(r#"insert into rooms (ar{}) values (1);"#, CURSOR_POS),
+                false,
+            ),
+            (
+                format!(r#"insert into rooms (i{}) values (1);"#, CURSOR_POS),
+                false,
+            ),
+        ];
+
+        for (query, expect_column_of_rooms) in test_cases {
+            let (tree, cache) = get_test_deps(setup, query.as_str().into()).await;
+            let params = get_test_params(&tree, &cache, query.as_str().into());
+            let items = complete(params);
+
+            let is_column_of_rooms = items
+                .first()
+                .is_some_and(|i| i.description == "Table: public.rooms");
+
+            assert_eq!(is_column_of_rooms, expect_column_of_rooms, "{}", query);
+        }
+    }
 }
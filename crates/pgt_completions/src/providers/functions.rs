@@ -4,7 +4,7 @@ use crate::{
 };
 
 pub fn complete_functions(ctx: &CompletionContext, builder: &mut CompletionBuilder) {
-    let available_functions = &ctx.schema_cache.functions;
+    let available_functions = ctx.schema_cache.functions();
 
     for func in available_functions {
         let item = CompletionItem {
@@ -155,4 +155,32 @@ mod tests {
         assert_eq!(label, "cool");
         assert_eq!(kind, CompletionItemKind::Function);
     }
+
+    #[tokio::test]
+    async fn prefers_user_functions_over_extension_functions() {
+        let setup = r#"
+          create extension pgcrypto;
+
+          create or replace function gen_uuid()
+          returns uuid
+          language sql
+          as $$
+            select gen_random_uuid();
+          $$;
+        "#;
+
+        let query = format!(r#"select gen{}"#, CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(setup, query.as_str().into()).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let results = complete(params);
+
+        let CompletionItem { label, kind, .. } = results
+            .into_iter()
+            .next()
+            .expect("Should return at least one completion item");
+
+        assert_eq!(label, "gen_uuid");
+        assert_eq!(kind, CompletionItemKind::Function);
+    }
 }
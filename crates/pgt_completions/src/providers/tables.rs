@@ -1,3 +1,5 @@
+use pgt_schema_cache::Table;
+
 use crate::{
     builder::CompletionBuilder,
     context::CompletionContext,
@@ -6,9 +8,13 @@ use crate::{
 };
 
 pub fn complete_tables(ctx: &CompletionContext, builder: &mut CompletionBuilder) {
-    let available_tables = &ctx.schema_cache.tables;
+    let available_tables = ctx.schema_cache.tables();
 
     for table in available_tables {
+        if is_hidden_partition(ctx, table) {
+            continue;
+        }
+
         let item = CompletionItem {
             label: table.name.clone(),
             score: CompletionRelevanceData::Table(table).get_score(ctx),
@@ -21,6 +27,25 @@ pub fn complete_tables(ctx: &CompletionContext, builder: &mut CompletionBuilder)
     }
 }
 
+/// Partitions are usually queried through their partitioned table, so they are only suggested
+/// once the input goes beyond the name of the partitioned table, e.g. `events_2` for `events_2024`.
+fn is_hidden_partition(ctx: &CompletionContext, table: &Table) -> bool {
+    if !table.is_partition {
+        return false;
+    }
+
+    let Some(root) = ctx.schema_cache.find_partition_root(table.id) else {
+        return false;
+    };
+
+    let content = ctx
+        .ts_node
+        .and_then(|node| ctx.get_ts_node_content(node))
+        .unwrap_or_default();
+
+    !(content.len() > root.name.len() && table.name.starts_with(content))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -173,4 +198,34 @@ mod tests {
         assert_eq!(label, "coos");
         assert_eq!(kind, CompletionItemKind::Table);
     }
+
+    #[tokio::test]
+    async fn hides_partitions_until_typed() {
+        let setup = r#"
+            create table events (
+                id serial,
+                created_at date
+            ) partition by range (created_at);
+
+            create table events_2024 partition of events
+                for values from ('2024-01-01') to ('2025-01-01');
+        "#;
+
+        let query = format!("select * from ev{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(setup, query.as_str().into()).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let items = complete(params);
+
+        assert_eq!(items[0].label, "events");
+        assert!(!items.iter().any(|i| i.label == "events_2024"));
+
+        let query = format!("select * from events_2{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(setup, query.as_str().into()).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let items = complete(params);
+
+        assert_eq!(items[0].label, "events_2024");
+    }
 }
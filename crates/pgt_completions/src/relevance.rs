@@ -31,6 +31,7 @@ pub(crate) struct CompletionRelevance<'a> {
 impl CompletionRelevance<'_> {
     pub fn into_score(mut self, ctx: &CompletionContext) -> i32 {
         self.check_is_user_defined();
+        self.check_is_extension_owned();
         self.check_matches_schema(ctx);
         self.check_matches_query_input(ctx);
         self.check_if_catalog(ctx);
@@ -78,6 +79,7 @@ impl CompletionRelevance<'_> {
                 ClauseType::From => 5,
                 ClauseType::Update => 15,
                 ClauseType::Delete => 15,
+                ClauseType::Insert => 15,
                 _ => -50,
             },
             CompletionRelevanceData::Function(_) => match clause_type {
@@ -90,6 +92,7 @@ impl CompletionRelevance<'_> {
                 ClauseType::Select if has_mentioned_tables => 10,
                 ClauseType::Select if !has_mentioned_tables => 0,
                 ClauseType::Where => 10,
+                ClauseType::Insert => 10,
                 _ => -15,
             },
         }
@@ -183,4 +186,11 @@ impl CompletionRelevance<'_> {
             self.score -= 10;
         }
     }
+
+    fn check_is_extension_owned(&mut self) {
+        self.score += match self.data {
+            CompletionRelevanceData::Function(f) if f.extension.is_some() => -10,
+            _ => 0,
+        };
+    }
 }
@@ -143,10 +143,21 @@ pub struct Safety {
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
+    #[doc = "Adding a column with a volatile default value rewrites the whole table while blocking reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_field_with_default:
+        Option<RuleConfiguration<pgt_analyser::options::AddingFieldWithDefault>>,
+    #[doc = "Setting a column to NOT NULL blocks reads and writes while the whole table is scanned."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_not_null_field: Option<RuleConfiguration<pgt_analyser::options::AddingNotNullField>>,
     #[doc = "Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub adding_required_field:
         Option<RuleConfiguration<pgt_analyser::options::AddingRequiredField>>,
+    #[doc = "CREATE INDEX CONCURRENTLY fails inside a transaction block."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_concurrent_index_creation_in_transaction:
+        Option<RuleConfiguration<pgt_analyser::options::BanConcurrentIndexCreationInTransaction>>,
     #[doc = "Dropping a column may break existing clients."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ban_drop_column: Option<RuleConfiguration<pgt_analyser::options::BanDropColumn>>,
@@ -156,27 +167,72 @@ pub struct Safety {
     #[doc = "Dropping a table may break existing clients."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ban_drop_table: Option<RuleConfiguration<pgt_analyser::options::BanDropTable>>,
+    #[doc = "VACUUM FULL and CLUSTER rewrite the whole table while blocking reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_vacuum_full_and_cluster:
+        Option<RuleConfiguration<pgt_analyser::options::BanVacuumFullAndCluster>>,
+    #[doc = "Changing the type of a column may rewrite the whole table while blocking reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changing_column_type: Option<RuleConfiguration<pgt_analyser::options::ChangingColumnType>>,
+    #[doc = "Adding a foreign key or check constraint without NOT VALID blocks writes while all existing rows are checked."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint_missing_not_valid:
+        Option<RuleConfiguration<pgt_analyser::options::ConstraintMissingNotValid>>,
+    #[doc = "Creating an index non-concurrently blocks writes to the table while the index is built."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_concurrent_index_creation:
+        Option<RuleConfiguration<pgt_analyser::options::RequireConcurrentIndexCreation>>,
+    #[doc = "Dropping an index non-concurrently blocks reads and writes to the table."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_concurrent_index_deletion:
+        Option<RuleConfiguration<pgt_analyser::options::RequireConcurrentIndexDeletion>>,
+    #[doc = "Dropping a table, index, column or constraint without IF EXISTS fails if the migration is run again."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_if_exists: Option<RuleConfiguration<pgt_analyser::options::UseIfExists>>,
 }
 impl Safety {
     const GROUP_NAME: &'static str = "safety";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "addingFieldWithDefault",
+        "addingNotNullField",
         "addingRequiredField",
+        "banConcurrentIndexCreationInTransaction",
+        "banDropColumn",
+        "banDropNotNull",
+        "banDropTable",
+        "banVacuumFullAndCluster",
+        "changingColumnType",
+        "constraintMissingNotValid",
+        "requireConcurrentIndexCreation",
+        "requireConcurrentIndexDeletion",
+        "useIfExists",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[
+        "banConcurrentIndexCreationInTransaction",
         "banDropColumn",
         "banDropNotNull",
         "banDropTable",
     ];
-    const RECOMMENDED_RULES: &'static [&'static str] =
-        &["banDropColumn", "banDropNotNull", "banDropTable"];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -193,50 +249,140 @@ impl Safety {
     }
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.adding_required_field.as_ref() {
+        if let Some(rule) = self.adding_field_with_default.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.ban_drop_column.as_ref() {
+        if let Some(rule) = self.adding_not_null_field.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+        if let Some(rule) = self.adding_required_field.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.ban_drop_table.as_ref() {
+        if let Some(rule) = self.ban_concurrent_index_creation_in_transaction.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
+        if let Some(rule) = self.ban_drop_column.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_table.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_vacuum_full_and_cluster.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.changing_column_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.constraint_missing_not_valid.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_creation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_deletion.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.use_if_exists.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.adding_required_field.as_ref() {
+        if let Some(rule) = self.adding_field_with_default.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.ban_drop_column.as_ref() {
+        if let Some(rule) = self.adding_not_null_field.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+        if let Some(rule) = self.adding_required_field.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.ban_drop_table.as_ref() {
+        if let Some(rule) = self.ban_concurrent_index_creation_in_transaction.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
+        if let Some(rule) = self.ban_drop_column.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_table.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_vacuum_full_and_cluster.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.changing_column_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.constraint_missing_not_valid.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_creation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_deletion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.use_if_exists.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -273,10 +419,22 @@ impl Safety {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
+            "addingFieldWithDefault" => self
+                .adding_field_with_default
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "addingNotNullField" => self
+                .adding_not_null_field
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "addingRequiredField" => self
                 .adding_required_field
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "banConcurrentIndexCreationInTransaction" => self
+                .ban_concurrent_index_creation_in_transaction
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "banDropColumn" => self
                 .ban_drop_column
                 .as_ref()
@@ -289,6 +447,30 @@ impl Safety {
                 .ban_drop_table
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "banVacuumFullAndCluster" => self
+                .ban_vacuum_full_and_cluster
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "changingColumnType" => self
+                .changing_column_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "constraintMissingNotValid" => self
+                .constraint_missing_not_valid
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireConcurrentIndexCreation" => self
+                .require_concurrent_index_creation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireConcurrentIndexDeletion" => self
+                .require_concurrent_index_deletion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useIfExists" => self
+                .use_if_exists
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
@@ -1,7 +1,9 @@
-use biome_deserialize::StringSet;
+use biome_deserialize::{Merge, StringSet};
 use biome_deserialize_macros::{Merge, Partial};
 use bpaf::Bpaf;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// The configuration of the database connection.
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -29,12 +31,68 @@ pub struct DatabaseConfiguration {
     #[partial(bpaf(long("database")))]
     pub database: String,
 
+    /// A connection string such as `postgres://user@localhost:5432/db`. The connection settings
+    /// above take precedence over the parts of the connection string.
+    #[partial(bpaf(long("connection_string")))]
+    pub connection_string: String,
+
+    /// The name of a service in the connection service file (`pg_service.conf`) to take the
+    /// connection settings from.
+    #[partial(bpaf(long("service")))]
+    pub service: String,
+
+    /// Whether and how the connection is encrypted with SSL.
+    #[partial(bpaf(
+        long("ssl_mode"),
+        argument("disable|allow|prefer|require|verify-ca|verify-full")
+    ))]
+    pub ssl_mode: SslMode,
+
+    /// The path to the certificate authorities the server certificate is verified with.
+    #[partial(bpaf(long("ssl_root_cert")))]
+    pub ssl_root_cert: String,
+
+    /// The path to the client certificate.
+    #[partial(bpaf(long("ssl_cert")))]
+    pub ssl_cert: String,
+
+    /// The path to the secret key of the client certificate.
+    #[partial(bpaf(long("ssl_key")))]
+    pub ssl_key: String,
+
     #[partial(bpaf(long("allow_statement_executions_against")))]
     pub allow_statement_executions_against: StringSet,
 
     /// The connection timeout in seconds.
     #[partial(bpaf(long("conn_timeout_secs"), fallback(Some(10)), debug_fallback))]
     pub conn_timeout_secs: u16,
+
+    /// A schema snapshot created via `postgrestools dump-schema`. If set, the schema is loaded
+    /// from the snapshot instead of the database. Features that need a connection, such as type
+    /// checking, are disabled.
+    #[partial(bpaf(long("schema_snapshot")))]
+    pub schema_snapshot: String,
+
+    /// Load the statistics of each column from `pg_stats` into the schema cache, e.g. to take the
+    /// number of distinct values into account. They are loaded the first time the lint rules
+    /// run, since this can be slow on large databases.
+    #[partial(bpaf(long("load_column_stats"), argument("true|false")))]
+    pub load_column_stats: bool,
+
+    /// How often, in seconds, the language server checks the database for schema changes, e.g.
+    /// after a migration was run, to update the diagnostics. Each check queries the catalog, so
+    /// it is disabled by default (`0`).
+    #[partial(bpaf(long("schema_refresh_interval_secs")))]
+    pub schema_refresh_interval_secs: u16,
+
+    /// Named connection profiles, e.g. for a local, a staging and a production database. Settings
+    /// a profile does not specify are taken from the connection settings above.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub profiles: DatabaseProfiles,
+
+    /// The profile to connect with. If not set, the connection settings above are used.
+    #[partial(bpaf(long("profile")))]
+    pub profile: String,
 }
 
 impl Default for DatabaseConfiguration {
@@ -45,8 +103,182 @@ impl Default for DatabaseConfiguration {
             username: "postgres".to_string(),
             password: "postgres".to_string(),
             database: "postgres".to_string(),
+            connection_string: Default::default(),
+            service: Default::default(),
+            ssl_mode: Default::default(),
+            ssl_root_cert: Default::default(),
+            ssl_cert: Default::default(),
+            ssl_key: Default::default(),
             allow_statement_executions_against: Default::default(),
             conn_timeout_secs: 10,
+            schema_snapshot: Default::default(),
+            load_column_stats: false,
+            schema_refresh_interval_secs: 0,
+            profiles: Default::default(),
+            profile: Default::default(),
+        }
+    }
+}
+
+/// Whether and how the connection is encrypted with SSL, as in `libpq`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SslMode {
+    /// Never use SSL.
+    Disable,
+    /// Only use SSL if the server requires it.
+    Allow,
+    /// Use SSL if the server supports it.
+    #[default]
+    Prefer,
+    /// Always use SSL, without verifying the server certificate.
+    Require,
+    /// Always use SSL and verify that the server certificate is issued by a trusted authority.
+    VerifyCa,
+    /// Always use SSL and verify the server certificate as well as the host name.
+    VerifyFull,
+}
+
+impl SslMode {
+    /// The name of the mode as used in connection strings.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Allow => "allow",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+}
+
+impl FromStr for SslMode {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disable" => Ok(Self::Disable),
+            "allow" => Ok(Self::Allow),
+            "prefer" => Ok(Self::Prefer),
+            "require" => Ok(Self::Require),
+            "verify-ca" => Ok(Self::VerifyCa),
+            "verify-full" => Ok(Self::VerifyFull),
+            _ => Err("Value not supported for SslMode"),
+        }
+    }
+}
+
+/// The connection profiles by name.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DatabaseProfiles(pub IndexMap<String, DatabaseProfile>);
+
+impl FromIterator<(String, DatabaseProfile)> for DatabaseProfiles {
+    fn from_iter<T: IntoIterator<Item = (String, DatabaseProfile)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Merge for DatabaseProfiles {
+    fn merge_with(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+/// The connection settings of a profile. Unset fields fall back to the settings in `db`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct DatabaseProfile {
+    /// The host of the database.
+    pub host: Option<String>,
+
+    /// The port of the database.
+    pub port: Option<u16>,
+
+    /// The username to connect to the database.
+    pub username: Option<String>,
+
+    /// The password to connect to the database.
+    pub password: Option<String>,
+
+    /// The name of the database.
+    pub database: Option<String>,
+
+    /// A connection string such as `postgres://user@localhost:5432/db`.
+    pub connection_string: Option<String>,
+
+    /// The name of a service in the connection service file (`pg_service.conf`).
+    pub service: Option<String>,
+
+    /// Whether and how the connection is encrypted with SSL.
+    pub ssl_mode: Option<SslMode>,
+
+    /// The path to the certificate authorities the server certificate is verified with.
+    pub ssl_root_cert: Option<String>,
+
+    /// The path to the client certificate.
+    pub ssl_cert: Option<String>,
+
+    /// The path to the secret key of the client certificate.
+    pub ssl_key: Option<String>,
+
+    /// Statements may only be executed against this profile if its `host/database` matches one
+    /// of these patterns.
+    pub allow_statement_executions_against: Option<StringSet>,
+
+    /// The connection timeout in seconds.
+    pub conn_timeout_secs: Option<u16>,
+}
+
+impl PartialDatabaseConfiguration {
+    /// Returns the connection settings of the profile, falling back to these settings for the
+    /// fields the profile does not set. The result has no profiles of its own.
+    ///
+    /// A profile with its own connection string or service does not inherit the host, port,
+    /// username, password and database, since they would take precedence over it.
+    pub fn with_profile(&self, profile: &DatabaseProfile) -> PartialDatabaseConfiguration {
+        let base = if profile.connection_string.is_some() || profile.service.is_some() {
+            PartialDatabaseConfiguration {
+                host: None,
+                port: None,
+                username: None,
+                password: None,
+                database: None,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        };
+
+        PartialDatabaseConfiguration {
+            host: profile.host.clone().or(base.host),
+            port: profile.port.or(base.port),
+            username: profile.username.clone().or(base.username),
+            password: profile.password.clone().or(base.password),
+            database: profile.database.clone().or(base.database),
+            connection_string: profile
+                .connection_string
+                .clone()
+                .or_else(|| self.connection_string.clone()),
+            service: profile.service.clone().or_else(|| self.service.clone()),
+            ssl_mode: profile.ssl_mode.or(self.ssl_mode),
+            ssl_root_cert: profile
+                .ssl_root_cert
+                .clone()
+                .or_else(|| self.ssl_root_cert.clone()),
+            ssl_cert: profile.ssl_cert.clone().or_else(|| self.ssl_cert.clone()),
+            ssl_key: profile.ssl_key.clone().or_else(|| self.ssl_key.clone()),
+            allow_statement_executions_against: profile
+                .allow_statement_executions_against
+                .clone()
+                .or_else(|| self.allow_statement_executions_against.clone()),
+            conn_timeout_secs: profile.conn_timeout_secs.or(self.conn_timeout_secs),
+            profiles: None,
+            profile: None,
+            ..self.clone()
         }
     }
 }
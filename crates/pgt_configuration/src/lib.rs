@@ -104,14 +104,27 @@ impl PartialConfiguration {
                 }),
                 ..Default::default()
             }),
+            // the connection settings are left out, so that they can be taken from the
+            // connection string, the `PG*` environment variables or the password file
             db: Some(PartialDatabaseConfiguration {
-                host: Some("127.0.0.1".to_string()),
-                port: Some(5432),
-                username: Some("postgres".to_string()),
-                password: Some("postgres".to_string()),
-                database: Some("postgres".to_string()),
+                host: None,
+                port: None,
+                username: None,
+                password: None,
+                database: None,
+                connection_string: None,
+                service: None,
+                ssl_mode: None,
+                ssl_root_cert: None,
+                ssl_cert: None,
+                ssl_key: None,
                 conn_timeout_secs: Some(10),
                 allow_statement_executions_against: Default::default(),
+                schema_snapshot: None,
+                load_column_stats: None,
+                schema_refresh_interval_secs: None,
+                profiles: None,
+                profile: None,
             }),
         }
     }
@@ -15,4 +15,9 @@ pub struct MigrationsConfiguration {
     /// Ignore any migrations before this timestamp
     #[partial(bpaf(long("after")))]
     pub after: u64,
+
+    /// Build the schema cache by replaying all migrations in order instead of loading it from the
+    /// database. Useful if there is no database to connect to.
+    #[partial(bpaf(long("schema-from-migrations"), argument("true|false")))]
+    pub schema_from_migrations: bool,
 }
@@ -19,7 +19,7 @@ use crate::{
 };
 
 pub use self::backtrace::{Backtrace, set_bottom_frame};
-pub use self::message::MessageAndDescription;
+pub use self::message::{MessageAndDescription, markup_to_string};
 
 /// Helper struct from printing the description of a diagnostic into any
 /// formatter implementing [std::fmt::Write].
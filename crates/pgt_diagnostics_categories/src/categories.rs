@@ -13,10 +13,19 @@
 // must be between `define_categories! {\n` and `\n    ;\n`.
 
 define_categories! {
+    "lint/safety/addingFieldWithDefault": "https://pgtools.dev/linter/rules/adding-field-with-default",
+    "lint/safety/addingNotNullField": "https://pgtools.dev/linter/rules/adding-not-null-field",
     "lint/safety/addingRequiredField": "https://pglt.dev/linter/rules/adding-required-field",
+    "lint/safety/banConcurrentIndexCreationInTransaction": "https://pgtools.dev/linter/rules/ban-concurrent-index-creation-in-transaction",
     "lint/safety/banDropColumn": "https://pglt.dev/linter/rules/ban-drop-column",
     "lint/safety/banDropNotNull": "https://pglt.dev/linter/rules/ban-drop-not-null",
     "lint/safety/banDropTable": "https://pglt.dev/linter/rules/ban-drop-table",
+    "lint/safety/banVacuumFullAndCluster": "https://pgtools.dev/linter/rules/ban-vacuum-full-and-cluster",
+    "lint/safety/changingColumnType": "https://pgtools.dev/linter/rules/changing-column-type",
+    "lint/safety/constraintMissingNotValid": "https://pgtools.dev/linter/rules/constraint-missing-not-valid",
+    "lint/safety/requireConcurrentIndexCreation": "https://pgtools.dev/linter/rules/require-concurrent-index-creation",
+    "lint/safety/requireConcurrentIndexDeletion": "https://pgtools.dev/linter/rules/require-concurrent-index-deletion",
+    "lint/safety/useIfExists": "https://pgtools.dev/linter/rules/use-if-exists",
     // end lint rules
     ;
     // General categories
@@ -24,6 +33,8 @@ define_categories! {
     "check",
     "configuration",
     "database/connection",
+    "database/schemaSnapshot",
+    "database/profile",
     "internalError/io",
     "internalError/runtime",
     "internalError/fs",
@@ -33,6 +44,9 @@ define_categories! {
     "internalError/panic",
     "syntax",
     "dummy",
+    "suppressions/parse",
+    "suppressions/unknownRule",
+    "suppressions/unused",
 
     // Lint groups start
     "lint",
@@ -18,6 +18,19 @@ pub struct PgTEnv {
     pub pgt_log_path: PgTEnvVariable,
     pub pgt_log_prefix: PgTEnvVariable,
     pub pgt_config_path: PgTEnvVariable,
+    pub pg_host: PgTEnvVariable,
+    pub pg_port: PgTEnvVariable,
+    pub pg_user: PgTEnvVariable,
+    pub pg_password: PgTEnvVariable,
+    pub pg_database: PgTEnvVariable,
+    pub pg_service: PgTEnvVariable,
+    pub pg_service_file: PgTEnvVariable,
+    pub pg_sysconfdir: PgTEnvVariable,
+    pub pg_passfile: PgTEnvVariable,
+    pub pg_sslmode: PgTEnvVariable,
+    pub pg_sslrootcert: PgTEnvVariable,
+    pub pg_sslcert: PgTEnvVariable,
+    pub pg_sslkey: PgTEnvVariable,
 }
 
 pub static PGT_ENV: OnceLock<PgTEnv> = OnceLock::new();
@@ -37,8 +50,76 @@ impl PgTEnv {
                 "PGT_CONFIG_PATH",
                 "A path to the configuration file",
             ),
+            pg_host: PgTEnvVariable::new(
+                "PGHOST",
+                "The host of the database, if neither the configuration nor the service set it.",
+            ),
+            pg_port: PgTEnvVariable::new(
+                "PGPORT",
+                "The port of the database, if neither the configuration nor the service set it.",
+            ),
+            pg_user: PgTEnvVariable::new(
+                "PGUSER",
+                "The username to connect with, if neither the configuration nor the service set it.",
+            ),
+            pg_password: PgTEnvVariable::new(
+                "PGPASSWORD",
+                "The password to connect with, if neither the configuration nor the service set it.",
+            ),
+            pg_database: PgTEnvVariable::new(
+                "PGDATABASE",
+                "The name of the database, if neither the configuration nor the service set it.",
+            ),
+            pg_service: PgTEnvVariable::new(
+                "PGSERVICE",
+                "The service to take the connection settings from, if the configuration does not set `db.service`.",
+            ),
+            pg_service_file: PgTEnvVariable::new(
+                "PGSERVICEFILE",
+                "A path to the connection service file. Default: `~/.pg_service.conf`",
+            ),
+            pg_sysconfdir: PgTEnvVariable::new(
+                "PGSYSCONFDIR",
+                "The directory of the system-wide `pg_service.conf`, used for services that are not in the user's service file.",
+            ),
+            pg_passfile: PgTEnvVariable::new(
+                "PGPASSFILE",
+                "A path to the password file, which is used if no password is set otherwise. Default: `~/.pgpass`",
+            ),
+            pg_sslmode: PgTEnvVariable::new(
+                "PGSSLMODE",
+                "The SSL mode of the connection, if neither the configuration nor the service set it.",
+            ),
+            pg_sslrootcert: PgTEnvVariable::new(
+                "PGSSLROOTCERT",
+                "A path to the certificate authorities the server certificate is verified with.",
+            ),
+            pg_sslcert: PgTEnvVariable::new("PGSSLCERT", "A path to the client certificate."),
+            pg_sslkey: PgTEnvVariable::new(
+                "PGSSLKEY",
+                "A path to the secret key of the client certificate.",
+            ),
         }
     }
+
+    /// The `libpq` variables the database connection settings are resolved from.
+    pub fn connection_variables(&self) -> [&PgTEnvVariable; 13] {
+        [
+            &self.pg_host,
+            &self.pg_port,
+            &self.pg_user,
+            &self.pg_password,
+            &self.pg_database,
+            &self.pg_service,
+            &self.pg_service_file,
+            &self.pg_sysconfdir,
+            &self.pg_passfile,
+            &self.pg_sslmode,
+            &self.pg_sslrootcert,
+            &self.pg_sslcert,
+            &self.pg_sslkey,
+        ]
+    }
 }
 
 pub struct PgTEnvVariable {
@@ -103,6 +184,21 @@ impl Display for PgTEnv {
             }
         };
 
+        for variable in self.connection_variables() {
+            match variable.value() {
+                None => {
+                    KeyValuePair(variable.name, markup! { <Dim>"unset"</Dim> }).fmt(fmt)?;
+                }
+                // never print the password
+                Some(_) if variable.name == self.pg_password.name => {
+                    KeyValuePair(variable.name, markup! { <Dim>"set"</Dim> }).fmt(fmt)?;
+                }
+                Some(value) => {
+                    KeyValuePair(variable.name, markup! {{DebugDisplay(value)}}).fmt(fmt)?;
+                }
+            };
+        }
+
         Ok(())
     }
 }
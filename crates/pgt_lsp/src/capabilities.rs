@@ -7,7 +7,7 @@ use tower_lsp::lsp_types::{
     TextDocumentSyncOptions, TextDocumentSyncSaveOptions, WorkDoneProgressOptions,
 };
 
-use crate::handlers::code_actions::command_id;
+use crate::handlers::code_actions::{SELECT_PROFILE_COMMAND, command_id};
 
 /// The capabilities to send from server as part of [`InitializeResult`]
 ///
@@ -53,6 +53,7 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         execute_command_provider: Some(ExecuteCommandOptions {
             commands: CommandActionCategory::iter()
                 .map(|c| command_id(&c))
+                .chain(std::iter::once(SELECT_PROFILE_COMMAND.to_string()))
                 .collect::<Vec<String>>(),
 
             ..Default::default()
@@ -1,14 +1,28 @@
-use crate::{adapters::get_cursor_position, session::Session};
+use std::collections::HashMap;
+
+use crate::{
+    adapters::{get_cursor_position, line_index::LineIndex, to_lsp},
+    session::Session,
+};
 use anyhow::{Result, anyhow};
 use tower_lsp::lsp_types::{
     self, CodeAction, CodeActionDisabled, CodeActionOrCommand, Command, ExecuteCommandParams,
-    MessageType,
+    MessageType, TextEdit, WorkspaceEdit,
 };
 
-use pgt_workspace::features::code_actions::{
-    CodeActionKind, CodeActionsParams, CommandActionCategory, ExecuteStatementParams,
+use pgt_analyse::SUPPRESSION_ACTION_CATEGORY;
+use pgt_workspace::{
+    features::code_actions::{
+        CodeActionKind, CodeActionsParams, CommandAction, CommandActionCategory, EditAction,
+        EditActionCategory, ExecuteStatementParams,
+    },
+    workspace::SelectProfileParams,
 };
 
+/// Selects the database connection profile. Takes the name of the profile, or `null` for the
+/// default connection, and optionally the URI of the workspace folder to select it for.
+pub const SELECT_PROFILE_COMMAND: &str = "pgt.selectProfile";
+
 #[tracing::instrument(level = "debug", skip(session), err)]
 pub fn get_actions(
     session: &Session,
@@ -18,6 +32,10 @@ pub fn get_actions(
     let path = session.file_path(&url)?;
 
     let cursor_position = get_cursor_position(session, &url, params.range.start)?;
+    let line_index = session
+        .document(&url)
+        .map(|doc| doc.line_index)
+        .map_err(|_| anyhow!("Document not found."))?;
 
     let workspace_actions = session.workspace.pull_code_actions(CodeActionsParams {
         path,
@@ -29,34 +47,38 @@ pub fn get_actions(
     let actions: Vec<CodeAction> = workspace_actions
         .actions
         .into_iter()
-        .filter_map(|action| match action.kind {
-            CodeActionKind::Command(command) => {
-                let command_id: String = command_id(&command.category);
-                let title = action.title;
-
-                match command.category {
-                    CommandActionCategory::ExecuteStatement(stmt_id) => Some(CodeAction {
-                        title: title.clone(),
-                        kind: Some(lsp_types::CodeActionKind::EMPTY),
-                        command: Some({
-                            Command {
-                                title: title.clone(),
-                                command: command_id,
-                                arguments: Some(vec![
-                                    serde_json::Value::Number(stmt_id.into()),
-                                    serde_json::to_value(&url).unwrap(),
-                                ]),
-                            }
-                        }),
-                        disabled: action
-                            .disabled_reason
-                            .map(|reason| CodeActionDisabled { reason }),
-                        ..Default::default()
-                    }),
-                }
+        .map(|action| {
+            let disabled = action
+                .disabled_reason
+                .map(|reason| CodeActionDisabled { reason });
+
+            match action.kind {
+                CodeActionKind::Command(command) => CodeAction {
+                    kind: Some(lsp_types::CodeActionKind::EMPTY),
+                    command: Some(lsp_command(&url, action.title.clone(), command)),
+                    title: action.title,
+                    disabled,
+                    ..Default::default()
+                },
+
+                CodeActionKind::Edit(edit) => CodeAction {
+                    title: action.title,
+                    kind: Some(edit_kind(&edit.category)),
+                    edit: Some(workspace_edit(&url, &line_index, session, edit)),
+                    disabled,
+                    ..Default::default()
+                },
+
+                // the client runs the command after applying the edit
+                CodeActionKind::EditAndCommand(edit, command) => CodeAction {
+                    kind: Some(edit_kind(&edit.category)),
+                    edit: Some(workspace_edit(&url, &line_index, session, edit)),
+                    command: Some(lsp_command(&url, action.title.clone(), command)),
+                    title: action.title,
+                    disabled,
+                    ..Default::default()
+                },
             }
-
-            _ => todo!(),
         })
         .collect();
 
@@ -66,6 +88,51 @@ pub fn get_actions(
         .collect())
 }
 
+fn edit_kind(category: &EditActionCategory) -> lsp_types::CodeActionKind {
+    match category {
+        EditActionCategory::QuickFix => lsp_types::CodeActionKind::QUICKFIX,
+        EditActionCategory::SuppressRule => {
+            lsp_types::CodeActionKind::new(SUPPRESSION_ACTION_CATEGORY)
+        }
+    }
+}
+
+fn lsp_command(url: &lsp_types::Url, title: String, command: CommandAction) -> Command {
+    let command_id = command_id(&command.category);
+
+    match command.category {
+        CommandActionCategory::ExecuteStatement(stmt_id) => Command {
+            title,
+            command: command_id,
+            arguments: Some(vec![
+                serde_json::Value::Number(stmt_id.into()),
+                serde_json::to_value(url).unwrap(),
+            ]),
+        },
+    }
+}
+
+fn workspace_edit(
+    url: &lsp_types::Url,
+    line_index: &LineIndex,
+    session: &Session,
+    edit: EditAction,
+) -> WorkspaceEdit {
+    let edits = edit
+        .edits
+        .into_iter()
+        .filter_map(|edit| {
+            let range = to_lsp::range(line_index, edit.range, session.position_encoding()).ok()?;
+            Some(TextEdit::new(range, edit.new_text))
+        })
+        .collect();
+
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(url.clone(), edits)])),
+        ..Default::default()
+    }
+}
+
 pub fn command_id(command: &CommandActionCategory) -> String {
     match command {
         CommandActionCategory::ExecuteStatement(_) => "pgt.executeStatement".into(),
@@ -107,6 +174,49 @@ pub async fn execute_command(
             Ok(None)
         }
 
+        SELECT_PROFILE_COMMAND => {
+            let profile: Option<String> = params
+                .arguments
+                .first()
+                .cloned()
+                .map(serde_json::from_value)
+                .transpose()?
+                .flatten();
+            let folder: Option<lsp_types::Url> = params
+                .arguments
+                .get(1)
+                .cloned()
+                .map(serde_json::from_value)
+                .transpose()?
+                .flatten();
+
+            let workspace_folder = folder
+                .map(|url| {
+                    url.to_file_path()
+                        .map_err(|_| anyhow!("Invalid workspace folder: {}", url))
+                })
+                .transpose()?;
+
+            session.workspace.select_profile(SelectProfileParams {
+                profile: profile.clone(),
+                workspace_folder,
+            })?;
+
+            // the diagnostics now come from a different database
+            session.update_all_diagnostics().await;
+
+            let message = match profile {
+                Some(profile) => format!("Connected with profile \"{}\".", profile),
+                None => "Connected without a profile.".into(),
+            };
+            session
+                .client
+                .show_message(MessageType::INFO, message)
+                .await;
+
+            Ok(None)
+        }
+
         any => Err(anyhow!(format!("Unknown command: {}", any))),
     }
 }
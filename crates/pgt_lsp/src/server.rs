@@ -144,6 +144,8 @@ impl LanguageServer for LSPServer {
 
         // Diagnostics are disabled by default, so update them after fetching workspace config
         self.session.update_all_diagnostics().await;
+
+        self.session.watch_schema_changes();
     }
 
     #[tracing::instrument(level = "info", skip_all)]
@@ -270,16 +272,24 @@ type Sessions = Arc<Mutex<FxHashMap<SessionKey, SessionHandle>>>;
 /// the [LSPServer]
 macro_rules! workspace_method {
     ( $builder:ident, $method:ident ) => {
+        workspace_method!($builder, $method, |workspace, params| workspace.$method(params));
+    };
+    // methods without parameters
+    ( $builder:ident, $method:ident, () ) => {
+        workspace_method!($builder, $method, |workspace, ()| workspace.$method());
+    };
+    ( $builder:ident, $method:ident, |$workspace:ident, $params:pat_param| $call:expr ) => {
         $builder = $builder.custom_method(
             concat!("pgt/", stringify!($method)),
             |server: &LSPServer, params| {
                 let span = tracing::trace_span!(concat!("pgt/", stringify!($method)), params = ?params).or_current();
                 tracing::info!("Received request: {}", stringify!($method));
 
-                let workspace = server.session.workspace.clone();
+                let $workspace = server.session.workspace.clone();
                 let result = spawn_blocking(move || {
                     let _guard = span.entered();
-                    workspace.$method(params)
+                    let $params = params;
+                    $call
                 });
 
                 result.map(move |result| {
@@ -389,13 +399,17 @@ impl ServerFactory {
         });
 
         workspace_method!(builder, is_path_ignored);
+        workspace_method!(builder, select_profile);
         workspace_method!(builder, update_settings);
         workspace_method!(builder, get_file_content);
         workspace_method!(builder, open_file);
         workspace_method!(builder, change_file);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
+        workspace_method!(builder, fix_file);
         workspace_method!(builder, get_completions);
+        workspace_method!(builder, dump_schema_snapshot, ());
+        workspace_method!(builder, refresh_schema_cache, ());
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -22,13 +22,16 @@ use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
+use std::time::Duration;
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
+use tokio::sync::watch;
+use tokio::task::spawn_blocking;
 use tower_lsp::lsp_types::Url;
 use tower_lsp::lsp_types::{self, ClientCapabilities};
 use tower_lsp::lsp_types::{MessageType, Registration};
 use tower_lsp::lsp_types::{Unregistration, WorkspaceFolder};
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 /// Key, uniquely identifying a LSP session.
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
@@ -61,6 +64,10 @@ pub(crate) struct Session {
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
+
+    /// How often the database is checked for schema changes, as configured by
+    /// `db.schemaRefreshIntervalSecs`. `None` if the checks are disabled.
+    schema_refresh_interval: watch::Sender<Option<Duration>>,
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -152,6 +159,7 @@ impl Session {
             cancellation,
             config_path: None,
             notified_broken_configuration: AtomicBool::new(false),
+            schema_refresh_interval: watch::Sender::new(None),
         }
     }
 
@@ -306,6 +314,50 @@ impl Session {
         }
     }
 
+    /// Periodically checks the database for schema changes and updates the diagnostics of all
+    /// documents whenever the schema cache changed, e.g. after a migration was run.
+    ///
+    /// The checks only run while `db.schemaRefreshIntervalSecs` is set, and stop once the session
+    /// is dropped.
+    pub(crate) fn watch_schema_changes(self: &Arc<Self>) {
+        let session = Arc::downgrade(self);
+        let mut interval = self.schema_refresh_interval.subscribe();
+
+        tokio::spawn(async move {
+            loop {
+                let period = *interval.borrow_and_update();
+
+                // wait for the next check, starting over whenever the interval is reconfigured.
+                // The channel closes once the session is dropped.
+                let reconfigured = match period {
+                    Some(period) => tokio::select! {
+                        _ = tokio::time::sleep(period) => None,
+                        changed = interval.changed() => Some(changed),
+                    },
+                    None => Some(interval.changed().await),
+                };
+                match reconfigured {
+                    Some(Ok(())) => continue,
+                    Some(Err(_)) => break,
+                    None => {}
+                }
+
+                let Some(session) = session.upgrade() else {
+                    break;
+                };
+
+                let workspace = session.workspace.clone();
+                match spawn_blocking(move || workspace.refresh_schema_cache()).await {
+                    Ok(Ok(true)) => session.update_all_diagnostics().await,
+                    Ok(Ok(false)) => {}
+                    // the database might just be unavailable, so don't spam the logs
+                    Ok(Err(err)) => debug!("Failed to refresh schema cache: {}", err),
+                    Err(err) => error!("Schema cache refresh panicked: {}", err),
+                }
+            }
+        });
+    }
+
     /// Get a [`Document`] matching the provided [`lsp_types::Url`]
     ///
     /// If document does not exist, result is [WorkspaceError::NotFound]
@@ -439,6 +491,18 @@ impl Session {
                 info!("Configuration loaded successfully from disk.");
                 info!("Update workspace settings.");
 
+                let schema_refresh_interval = fs_configuration
+                    .db
+                    .as_ref()
+                    .and_then(|db| db.schema_refresh_interval_secs)
+                    .filter(|secs| *secs > 0)
+                    .map(|secs| Duration::from_secs(secs.into()));
+                self.schema_refresh_interval.send_if_modified(|interval| {
+                    let modified = *interval != schema_refresh_interval;
+                    *interval = schema_refresh_interval;
+                    modified
+                });
+
                 let result = fs_configuration
                     .retrieve_gitignore_matches(&self.fs, configuration_path.as_deref());
 
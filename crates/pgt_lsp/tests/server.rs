@@ -108,7 +108,24 @@ impl Server {
         P: Serialize,
         R: DeserializeOwned,
     {
-        self.service
+        self.request_with_timeout(method, id, params, Duration::from_secs(1))
+            .await
+    }
+
+    /// Sends a request that may take longer than the default timeout, e.g. because it loads the
+    /// schema cache
+    async fn request_with_timeout<P, R>(
+        &mut self,
+        method: &'static str,
+        id: &'static str,
+        params: P,
+        timeout: Duration,
+    ) -> Result<Option<R>>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        Timeout::new(self.service.get_mut(), timeout)
             .ready()
             .await
             .map_err(Error::msg)
@@ -431,6 +448,170 @@ async fn test_database_connection() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_schema_refresh() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    test_db
+        .execute("create table public.users (id serial primary key);")
+        .await
+        .expect("Failed to setup test database");
+
+    let mut conf = PartialConfiguration::init();
+    conf.merge_with(PartialConfiguration {
+        db: Some(PartialDatabaseConfiguration {
+            database: Some(
+                test_db
+                    .connect_options()
+                    .get_database()
+                    .unwrap()
+                    .to_string(),
+            ),
+            schema_refresh_interval_secs: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_document("select email from public.users; ")
+        .await?;
+
+    let unknown_column = |msg: &PublishDiagnosticsParams| {
+        msg.diagnostics
+            .iter()
+            .any(|d| d.message.contains("column \"email\" does not exist"))
+    };
+
+    let notification = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(ServerNotification::PublishDiagnostics(msg)) = receiver.next().await {
+                if unknown_column(&msg) {
+                    return;
+                }
+            }
+        }
+    })
+    .await
+    .is_ok();
+
+    assert!(notification, "expected diagnostics for unknown column");
+
+    test_db
+        .execute("alter table public.users add column email text;")
+        .await
+        .expect("Failed to alter test database");
+
+    // the schema change is picked up without any change to the document
+    let notification = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(ServerNotification::PublishDiagnostics(msg)) = receiver.next().await {
+                if !unknown_column(&msg) {
+                    return;
+                }
+            }
+        }
+    })
+    .await
+    .is_ok();
+
+    assert!(notification, "expected diagnostics to be updated");
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schema_cache_methods() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    test_db
+        .execute("create table public.users (id serial primary key);")
+        .await
+        .expect("Failed to setup test database");
+
+    let mut conf = PartialConfiguration::init();
+    conf.merge_with(PartialConfiguration {
+        db: Some(PartialDatabaseConfiguration {
+            database: Some(
+                test_db
+                    .connect_options()
+                    .get_database()
+                    .unwrap()
+                    .to_string(),
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let changed: bool = server
+        .request("pgt/refresh_schema_cache", "refresh_schema_cache", ())
+        .await?
+        .context("pgt/refresh_schema_cache returned None")?;
+    assert!(!changed);
+
+    let snapshot: String = server
+        .request_with_timeout(
+            "pgt/dump_schema_snapshot",
+            "dump_schema_snapshot",
+            (),
+            Duration::from_secs(10),
+        )
+        .await?
+        .context("pgt/dump_schema_snapshot returned None")?;
+    assert!(snapshot.contains("\"users\""));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn server_shutdown() -> Result<()> {
     let factory = ServerFactory::default();
@@ -898,6 +1079,393 @@ async fn test_execute_statement() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_suppress_rule_code_action() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    let database = test_db
+        .connect_options()
+        .get_database()
+        .unwrap()
+        .to_string();
+    let host = test_db.connect_options().get_host().to_string();
+
+    let conf = PartialConfiguration {
+        db: Some(PartialDatabaseConfiguration {
+            database: Some(database),
+            host: Some(host),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let doc_content = "select 1;\n    alter table users drop column email;\n";
+    let doc_url = url!("test.sql");
+
+    server
+        .open_named_document(doc_content.to_string(), doc_url.clone())
+        .await?;
+
+    let code_actions_response = server
+        .request::<CodeActionParams, CodeActionResponse>(
+            "textDocument/codeAction",
+            "_code_action",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: doc_url.clone(),
+                },
+                range: Range {
+                    start: Position::new(1, 10),
+                    end: Position::new(1, 10),
+                },
+                context: CodeActionContext::default(),
+                partial_result_params: PartialResultParams::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .unwrap();
+
+    let edit = code_actions_response
+        .iter()
+        .find_map(|action_or_cmd| match action_or_cmd {
+            lsp::CodeActionOrCommand::CodeAction(code_action)
+                if code_action.title
+                    == "Suppress rule lint/safety/banDropColumn for this statement" =>
+            {
+                code_action.edit.clone()
+            }
+            _ => None,
+        })
+        .expect("Did not find the suppression code action!");
+
+    let text_edits = edit.changes.unwrap().remove(&doc_url).unwrap();
+    assert_eq!(
+        text_edits,
+        vec![lsp::TextEdit::new(
+            Range::new(Position::new(1, 0), Position::new(1, 0)),
+            "    -- pgt-ignore lint/safety/banDropColumn: <explanation>\n".to_string()
+        )]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rule_fix_code_action() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    let database = test_db
+        .connect_options()
+        .get_database()
+        .unwrap()
+        .to_string();
+    let host = test_db.connect_options().get_host().to_string();
+
+    let conf = serde_json::json!({
+        "db": { "database": database, "host": host },
+        "linter": { "rules": { "safety": { "addingRequiredField": "warn" } } }
+    });
+
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let doc_content = "select 1;\nalter table users add column age int not null;\n";
+    let doc_url = url!("test.sql");
+
+    server
+        .open_named_document(doc_content.to_string(), doc_url.clone())
+        .await?;
+
+    let code_actions_response = server
+        .request::<CodeActionParams, CodeActionResponse>(
+            "textDocument/codeAction",
+            "_code_action",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: doc_url.clone(),
+                },
+                range: Range {
+                    start: Position::new(1, 10),
+                    end: Position::new(1, 10),
+                },
+                context: CodeActionContext::default(),
+                partial_result_params: PartialResultParams::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .unwrap();
+
+    let code_action = code_actions_response
+        .iter()
+        .find_map(|action_or_cmd| match action_or_cmd {
+            lsp::CodeActionOrCommand::CodeAction(code_action)
+                if code_action.kind == Some(lsp::CodeActionKind::QUICKFIX) =>
+            {
+                Some(code_action.clone())
+            }
+            _ => None,
+        })
+        .expect("Did not find the quick fix!");
+
+    assert_eq!(
+        code_action.title,
+        "Add the column as nullable, backfill it, and then set it to NOT NULL."
+    );
+
+    let text_edits = code_action
+        .edit
+        .unwrap()
+        .changes
+        .unwrap()
+        .remove(&doc_url)
+        .unwrap();
+    assert_eq!(
+        text_edits,
+        vec![lsp::TextEdit::new(
+            Range::new(Position::new(1, 0), Position::new(1, 46)),
+            "alter table users add column age int;\n-- backfill the existing rows of age here\nalter table users alter column age set not null;".to_string()
+        )]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_suppress_file_rule_code_action() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    let database = test_db
+        .connect_options()
+        .get_database()
+        .unwrap()
+        .to_string();
+    let host = test_db.connect_options().get_host().to_string();
+
+    let conf = PartialConfiguration {
+        db: Some(PartialDatabaseConfiguration {
+            database: Some(database),
+            host: Some(host),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let doc_content =
+        "begin;\ncreate index concurrently users_email_idx on users (email);\ncommit;\n";
+    let doc_url = url!("test.sql");
+
+    server
+        .open_named_document(doc_content.to_string(), doc_url.clone())
+        .await?;
+
+    let code_actions_response = server
+        .request::<CodeActionParams, CodeActionResponse>(
+            "textDocument/codeAction",
+            "_code_action",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: doc_url.clone(),
+                },
+                range: Range {
+                    start: Position::new(1, 10),
+                    end: Position::new(1, 10),
+                },
+                context: CodeActionContext::default(),
+                partial_result_params: PartialResultParams::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .unwrap();
+
+    let edit = code_actions_response
+        .iter()
+        .find_map(|action_or_cmd| match action_or_cmd {
+            lsp::CodeActionOrCommand::CodeAction(code_action)
+                if code_action.title
+                    == "Suppress rule lint/safety/banConcurrentIndexCreationInTransaction for this statement" =>
+            {
+                code_action.edit.clone()
+            }
+            _ => None,
+        })
+        .expect("Did not find the suppression code action!");
+
+    let text_edits = edit.changes.unwrap().remove(&doc_url).unwrap();
+    assert_eq!(
+        text_edits,
+        vec![lsp::TextEdit::new(
+            Range::new(Position::new(1, 0), Position::new(1, 0)),
+            "-- pgt-ignore lint/safety/banConcurrentIndexCreationInTransaction: <explanation>\n"
+                .to_string()
+        )]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_rule_code_action_targets_its_statement() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let test_db = get_new_test_db().await;
+
+    let database = test_db
+        .connect_options()
+        .get_database()
+        .unwrap()
+        .to_string();
+    let host = test_db.connect_options().get_host().to_string();
+
+    let conf = PartialConfiguration {
+        db: Some(PartialDatabaseConfiguration {
+            database: Some(database),
+            host: Some(host),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    fs.insert(
+        url!("postgrestools.jsonc").to_file_path().unwrap(),
+        serde_json::to_string_pretty(&conf).unwrap(),
+    );
+
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    let doc_content =
+        "begin;\ncreate index concurrently users_email_idx on users (email);\ncommit;\n";
+    let doc_url = url!("test.sql");
+
+    server
+        .open_named_document(doc_content.to_string(), doc_url.clone())
+        .await?;
+
+    // the diagnostic points at the index, not at the statement that opens the transaction
+    for (line, expected) in [(0, false), (1, true), (2, false)] {
+        let code_actions_response = server
+            .request::<CodeActionParams, CodeActionResponse>(
+                "textDocument/codeAction",
+                "_code_action",
+                CodeActionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: doc_url.clone(),
+                    },
+                    range: Range {
+                        start: Position::new(line, 2),
+                        end: Position::new(line, 2),
+                    },
+                    context: CodeActionContext::default(),
+                    partial_result_params: PartialResultParams::default(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                },
+            )
+            .await?
+            .unwrap();
+
+        let found = code_actions_response.iter().any(|action_or_cmd| {
+            matches!(
+                action_or_cmd,
+                lsp::CodeActionOrCommand::CodeAction(code_action)
+                    if code_action.title.contains("banConcurrentIndexCreationInTransaction")
+            )
+        });
+        assert_eq!(found, expected, "unexpected code actions on line {line}");
+    }
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_issue_281() -> Result<()> {
     let factory = ServerFactory::default();
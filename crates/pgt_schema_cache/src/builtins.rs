@@ -0,0 +1,465 @@
+use crate::casts::{Cast, CastContext};
+use crate::functions::{Behavior, Function, FunctionArg, FunctionArgs};
+use crate::schema_cache::SchemaCache;
+use crate::schemas::Schema;
+use crate::types::{PostgresType, TypeKind};
+
+/// `(oid, name, format, array oid, kind, range subtype oid, preferred)`
+type BuiltinType = (i64, &'static str, &'static str, i64, TypeKind, i64, bool);
+
+/// The built-in types that are available without loading the schema from a database. The `oid`s
+/// of built-in objects are stable across Postgres versions.
+#[rustfmt::skip]
+const TYPES: &[BuiltinType] = &[
+    (16, "bool", "boolean", 1000, TypeKind::Base, 0, true),
+    (17, "bytea", "bytea", 1001, TypeKind::Base, 0, false),
+    (18, "char", "\"char\"", 1002, TypeKind::Base, 0, false),
+    (19, "name", "name", 1003, TypeKind::Base, 0, false),
+    (20, "int8", "bigint", 1016, TypeKind::Base, 0, false),
+    (21, "int2", "smallint", 1005, TypeKind::Base, 0, false),
+    (23, "int4", "integer", 1007, TypeKind::Base, 0, false),
+    (25, "text", "text", 1009, TypeKind::Base, 0, true),
+    (26, "oid", "oid", 1028, TypeKind::Base, 0, true),
+    (114, "json", "json", 199, TypeKind::Base, 0, false),
+    (142, "xml", "xml", 143, TypeKind::Base, 0, false),
+    (600, "point", "point", 1017, TypeKind::Base, 0, false),
+    (650, "cidr", "cidr", 651, TypeKind::Base, 0, false),
+    (700, "float4", "real", 1021, TypeKind::Base, 0, false),
+    (701, "float8", "double precision", 1022, TypeKind::Base, 0, true),
+    (790, "money", "money", 791, TypeKind::Base, 0, false),
+    (829, "macaddr", "macaddr", 1040, TypeKind::Base, 0, false),
+    (869, "inet", "inet", 1041, TypeKind::Base, 0, true),
+    (1042, "bpchar", "character", 1014, TypeKind::Base, 0, false),
+    (1043, "varchar", "character varying", 1015, TypeKind::Base, 0, false),
+    (1082, "date", "date", 1182, TypeKind::Base, 0, false),
+    (1083, "time", "time without time zone", 1183, TypeKind::Base, 0, false),
+    (1114, "timestamp", "timestamp without time zone", 1115, TypeKind::Base, 0, false),
+    (1184, "timestamptz", "timestamp with time zone", 1185, TypeKind::Base, 0, true),
+    (1186, "interval", "interval", 1187, TypeKind::Base, 0, true),
+    (1266, "timetz", "time with time zone", 1270, TypeKind::Base, 0, false),
+    (1560, "bit", "bit", 1561, TypeKind::Base, 0, false),
+    (1562, "varbit", "bit varying", 1563, TypeKind::Base, 0, true),
+    (1700, "numeric", "numeric", 1231, TypeKind::Base, 0, false),
+    (2205, "regclass", "regclass", 2210, TypeKind::Base, 0, false),
+    (2206, "regtype", "regtype", 2211, TypeKind::Base, 0, false),
+    (2249, "record", "record", 2287, TypeKind::Pseudo, 0, false),
+    (2275, "cstring", "cstring", 1263, TypeKind::Pseudo, 0, false),
+    (2276, "any", "\"any\"", 0, TypeKind::Pseudo, 0, false),
+    (2277, "anyarray", "anyarray", 0, TypeKind::Pseudo, 0, false),
+    (2278, "void", "void", 0, TypeKind::Pseudo, 0, false),
+    (2279, "trigger", "trigger", 0, TypeKind::Pseudo, 0, false),
+    (2283, "anyelement", "anyelement", 0, TypeKind::Pseudo, 0, false),
+    (2950, "uuid", "uuid", 2951, TypeKind::Base, 0, false),
+    (3614, "tsvector", "tsvector", 3643, TypeKind::Base, 0, false),
+    (3615, "tsquery", "tsquery", 3645, TypeKind::Base, 0, false),
+    (3802, "jsonb", "jsonb", 3807, TypeKind::Base, 0, false),
+    (3838, "event_trigger", "event_trigger", 0, TypeKind::Pseudo, 0, false),
+    (3904, "int4range", "int4range", 3905, TypeKind::Range, 23, false),
+    (3906, "numrange", "numrange", 3907, TypeKind::Range, 1700, false),
+    (3908, "tsrange", "tsrange", 3909, TypeKind::Range, 1114, false),
+    (3910, "tstzrange", "tstzrange", 3911, TypeKind::Range, 1184, false),
+    (3912, "daterange", "daterange", 3913, TypeKind::Range, 1082, false),
+    (3926, "int8range", "int8range", 3927, TypeKind::Range, 20, false),
+    (5077, "anycompatible", "anycompatible", 0, TypeKind::Pseudo, 0, false),
+    (5078, "anycompatiblearray", "anycompatiblearray", 0, TypeKind::Pseudo, 0, false),
+];
+
+/// `(oid, name, argument type ids, number of arguments with defaults, return type id, returns set,
+/// last argument is variadic, behavior)` of the built-in functions.
+type BuiltinFunction = (
+    i64,
+    &'static str,
+    &'static [i64],
+    usize,
+    i64,
+    bool,
+    bool,
+    Behavior,
+);
+
+#[rustfmt::skip]
+const FUNCTIONS: &[BuiltinFunction] = &[
+    (1394, "abs", &[700], 0, 700, false, false, Behavior::Immutable),
+    (1395, "abs", &[701], 0, 701, false, false, Behavior::Immutable),
+    (1396, "abs", &[20], 0, 20, false, false, Behavior::Immutable),
+    (1397, "abs", &[23], 0, 23, false, false, Behavior::Immutable),
+    (1398, "abs", &[21], 0, 21, false, false, Behavior::Immutable),
+    (1705, "abs", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (1199, "age", &[1184, 1184], 0, 1186, false, false, Behavior::Immutable),
+    (1386, "age", &[1184], 0, 1186, false, false, Behavior::Stable),
+    (2058, "age", &[1114, 1114], 0, 1186, false, false, Behavior::Immutable),
+    (2059, "age", &[1114], 0, 1186, false, false, Behavior::Stable),
+    (378, "array_append", &[5078, 5077], 0, 5078, false, false, Behavior::Immutable),
+    (2176, "array_length", &[2277, 23], 0, 23, false, false, Behavior::Immutable),
+    (3277, "array_position", &[5078, 5077], 0, 23, false, false, Behavior::Immutable),
+    (3278, "array_position", &[5078, 5077, 23], 0, 23, false, false, Behavior::Immutable),
+    (3167, "array_remove", &[5078, 5077], 0, 5078, false, false, Behavior::Immutable),
+    (384, "array_to_string", &[2277, 25, 25], 0, 25, false, false, Behavior::Stable),
+    (395, "array_to_string", &[2277, 25], 0, 25, false, false, Behavior::Stable),
+    (884, "btrim", &[25, 25], 0, 25, false, false, Behavior::Immutable),
+    (885, "btrim", &[25], 0, 25, false, false, Behavior::Immutable),
+    (2015, "btrim", &[17, 17], 0, 17, false, false, Behavior::Immutable),
+    (3179, "cardinality", &[2277], 0, 23, false, false, Behavior::Immutable),
+    (1711, "ceil", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (2308, "ceil", &[701], 0, 701, false, false, Behavior::Immutable),
+    (1372, "char_length", &[1042], 0, 23, false, false, Behavior::Immutable),
+    (1381, "char_length", &[25], 0, 23, false, false, Behavior::Immutable),
+    (2649, "clock_timestamp", &[], 0, 1184, false, false, Behavior::Volatile),
+    (3058, "concat", &[2276], 0, 25, false, true, Behavior::Stable),
+    (3059, "concat_ws", &[25, 2276], 0, 25, false, true, Behavior::Stable),
+    (2077, "current_setting", &[25], 0, 25, false, false, Behavior::Stable),
+    (3294, "current_setting", &[25, 16], 0, 25, false, false, Behavior::Stable),
+    (1575, "currval", &[2205], 0, 20, false, false, Behavior::Volatile),
+    (1171, "date_part", &[25, 1184], 0, 701, false, false, Behavior::Stable),
+    (1172, "date_part", &[25, 1186], 0, 701, false, false, Behavior::Immutable),
+    (1273, "date_part", &[25, 1266], 0, 701, false, false, Behavior::Immutable),
+    (1384, "date_part", &[25, 1082], 0, 701, false, false, Behavior::Immutable),
+    (1385, "date_part", &[25, 1083], 0, 701, false, false, Behavior::Immutable),
+    (2021, "date_part", &[25, 1114], 0, 701, false, false, Behavior::Immutable),
+    (1217, "date_trunc", &[25, 1184], 0, 1184, false, false, Behavior::Stable),
+    (1218, "date_trunc", &[25, 1186], 0, 1186, false, false, Behavior::Immutable),
+    (1284, "date_trunc", &[25, 1184, 25], 0, 1184, false, false, Behavior::Stable),
+    (2020, "date_trunc", &[25, 1114], 0, 1114, false, false, Behavior::Immutable),
+    (1947, "decode", &[25, 25], 0, 17, false, false, Behavior::Immutable),
+    (1946, "encode", &[17, 25], 0, 25, false, false, Behavior::Immutable),
+    (1712, "floor", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (2309, "floor", &[701], 0, 701, false, false, Behavior::Immutable),
+    (3539, "format", &[25, 2276], 0, 25, false, true, Behavior::Stable),
+    (3540, "format", &[25], 0, 25, false, false, Behavior::Stable),
+    (3432, "gen_random_uuid", &[], 0, 2950, false, false, Behavior::Volatile),
+    (938, "generate_series", &[1114, 1114, 1186], 0, 1114, true, false, Behavior::Immutable),
+    (939, "generate_series", &[1184, 1184, 1186], 0, 1184, true, false, Behavior::Stable),
+    (1066, "generate_series", &[23, 23, 23], 0, 23, true, false, Behavior::Immutable),
+    (1067, "generate_series", &[23, 23], 0, 23, true, false, Behavior::Immutable),
+    (1068, "generate_series", &[20, 20, 20], 0, 20, true, false, Behavior::Immutable),
+    (1069, "generate_series", &[20, 20], 0, 20, true, false, Behavior::Immutable),
+    (3259, "generate_series", &[1700, 1700, 1700], 0, 1700, true, false, Behavior::Immutable),
+    (3260, "generate_series", &[1700, 1700], 0, 1700, true, false, Behavior::Immutable),
+    (872, "initcap", &[25], 0, 25, false, false, Behavior::Immutable),
+    (3200, "json_build_object", &[2276], 0, 114, false, true, Behavior::Stable),
+    (3201, "json_build_object", &[], 0, 114, false, false, Behavior::Stable),
+    (3207, "jsonb_array_length", &[3802], 0, 23, false, false, Behavior::Immutable),
+    (3273, "jsonb_build_object", &[2276], 0, 3802, false, true, Behavior::Stable),
+    (3274, "jsonb_build_object", &[], 0, 3802, false, false, Behavior::Stable),
+    (3305, "jsonb_set", &[3802, 1009, 3802, 16], 1, 3802, false, false, Behavior::Immutable),
+    (3262, "jsonb_strip_nulls", &[3802], 0, 3802, false, false, Behavior::Immutable),
+    (3210, "jsonb_typeof", &[3802], 0, 25, false, false, Behavior::Immutable),
+    (3060, "left", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (1317, "length", &[25], 0, 23, false, false, Behavior::Immutable),
+    (1318, "length", &[1042], 0, 23, false, false, Behavior::Immutable),
+    (1681, "length", &[1560], 0, 23, false, false, Behavior::Immutable),
+    (1713, "length", &[17, 19], 0, 23, false, false, Behavior::Stable),
+    (2010, "length", &[17], 0, 23, false, false, Behavior::Immutable),
+    (3711, "length", &[3614], 0, 23, false, false, Behavior::Immutable),
+    (870, "lower", &[25], 0, 25, false, false, Behavior::Immutable),
+    (873, "lpad", &[25, 23, 25], 0, 25, false, false, Behavior::Immutable),
+    (879, "lpad", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (875, "ltrim", &[25, 25], 0, 25, false, false, Behavior::Immutable),
+    (881, "ltrim", &[25], 0, 25, false, false, Behavior::Immutable),
+    (6195, "ltrim", &[17, 17], 0, 17, false, false, Behavior::Immutable),
+    (2311, "md5", &[25], 0, 25, false, false, Behavior::Immutable),
+    (2321, "md5", &[17], 0, 25, false, false, Behavior::Immutable),
+    (940, "mod", &[21, 21], 0, 21, false, false, Behavior::Immutable),
+    (941, "mod", &[23, 23], 0, 23, false, false, Behavior::Immutable),
+    (947, "mod", &[20, 20], 0, 20, false, false, Behavior::Immutable),
+    (1728, "mod", &[1700, 1700], 0, 1700, false, false, Behavior::Immutable),
+    (1574, "nextval", &[2205], 0, 20, false, false, Behavior::Volatile),
+    (1299, "now", &[], 0, 1184, false, false, Behavior::Stable),
+    (2626, "pg_sleep", &[701], 0, 2278, false, false, Behavior::Volatile),
+    (1619, "pg_typeof", &[2276], 0, 2206, false, false, Behavior::Stable),
+    (1368, "power", &[701, 701], 0, 701, false, false, Behavior::Immutable),
+    (2169, "power", &[1700, 1700], 0, 1700, false, false, Behavior::Immutable),
+    (1598, "random", &[], 0, 701, false, false, Behavior::Volatile),
+    (2284, "regexp_replace", &[25, 25, 25], 0, 25, false, false, Behavior::Immutable),
+    (2285, "regexp_replace", &[25, 25, 25, 25], 0, 25, false, false, Behavior::Immutable),
+    (6251, "regexp_replace", &[25, 25, 25, 23, 23, 25], 0, 25, false, false, Behavior::Immutable),
+    (6252, "regexp_replace", &[25, 25, 25, 23, 23], 0, 25, false, false, Behavior::Immutable),
+    (6253, "regexp_replace", &[25, 25, 25, 23], 0, 25, false, false, Behavior::Immutable),
+    (1622, "repeat", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (2087, "replace", &[25, 25, 25], 0, 25, false, false, Behavior::Immutable),
+    (3062, "reverse", &[25], 0, 25, false, false, Behavior::Immutable),
+    (3061, "right", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (1342, "round", &[701], 0, 701, false, false, Behavior::Immutable),
+    (1707, "round", &[1700, 23], 0, 1700, false, false, Behavior::Immutable),
+    (1708, "round", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (3155, "row_to_json", &[2249], 0, 114, false, false, Behavior::Stable),
+    (3156, "row_to_json", &[2249, 16], 0, 114, false, false, Behavior::Stable),
+    (874, "rpad", &[25, 23, 25], 0, 25, false, false, Behavior::Immutable),
+    (880, "rpad", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (876, "rtrim", &[25, 25], 0, 25, false, false, Behavior::Immutable),
+    (882, "rtrim", &[25], 0, 25, false, false, Behavior::Immutable),
+    (6196, "rtrim", &[17, 17], 0, 17, false, false, Behavior::Immutable),
+    (2078, "set_config", &[25, 25, 16], 0, 25, false, false, Behavior::Volatile),
+    (1576, "setval", &[2205, 20], 0, 20, false, false, Behavior::Volatile),
+    (1765, "setval", &[2205, 20, 16], 0, 20, false, false, Behavior::Volatile),
+    (3420, "sha256", &[17], 0, 17, false, false, Behavior::Immutable),
+    (2088, "split_part", &[25, 25, 23], 0, 25, false, false, Behavior::Immutable),
+    (1344, "sqrt", &[701], 0, 701, false, false, Behavior::Immutable),
+    (1730, "sqrt", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (2648, "statement_timestamp", &[], 0, 1184, false, false, Behavior::Stable),
+    (376, "string_to_array", &[25, 25, 25], 0, 1009, false, false, Behavior::Immutable),
+    (394, "string_to_array", &[25, 25], 0, 1009, false, false, Behavior::Immutable),
+    (868, "strpos", &[25, 25], 0, 23, false, false, Behavior::Immutable),
+    (877, "substr", &[25, 23, 23], 0, 25, false, false, Behavior::Immutable),
+    (883, "substr", &[25, 23], 0, 25, false, false, Behavior::Immutable),
+    (2085, "substr", &[17, 23, 23], 0, 17, false, false, Behavior::Immutable),
+    (2086, "substr", &[17, 23], 0, 17, false, false, Behavior::Immutable),
+    (1768, "to_char", &[1186, 25], 0, 25, false, false, Behavior::Stable),
+    (1770, "to_char", &[1184, 25], 0, 25, false, false, Behavior::Stable),
+    (1772, "to_char", &[1700, 25], 0, 25, false, false, Behavior::Stable),
+    (1773, "to_char", &[23, 25], 0, 25, false, false, Behavior::Stable),
+    (1774, "to_char", &[20, 25], 0, 25, false, false, Behavior::Stable),
+    (1775, "to_char", &[700, 25], 0, 25, false, false, Behavior::Stable),
+    (1776, "to_char", &[701, 25], 0, 25, false, false, Behavior::Stable),
+    (2049, "to_char", &[1114, 25], 0, 25, false, false, Behavior::Stable),
+    (1780, "to_date", &[25, 25], 0, 1082, false, false, Behavior::Stable),
+    (3176, "to_json", &[2283], 0, 114, false, false, Behavior::Stable),
+    (3787, "to_jsonb", &[2283], 0, 3802, false, false, Behavior::Stable),
+    (1777, "to_number", &[25, 25], 0, 1700, false, false, Behavior::Stable),
+    (1158, "to_timestamp", &[701], 0, 1184, false, false, Behavior::Immutable),
+    (1778, "to_timestamp", &[25, 25], 0, 1184, false, false, Behavior::Stable),
+    (2647, "transaction_timestamp", &[], 0, 1184, false, false, Behavior::Stable),
+    (753, "trunc", &[829], 0, 829, false, false, Behavior::Immutable),
+    (1343, "trunc", &[701], 0, 701, false, false, Behavior::Immutable),
+    (1709, "trunc", &[1700, 23], 0, 1700, false, false, Behavior::Immutable),
+    (1710, "trunc", &[1700], 0, 1700, false, false, Behavior::Immutable),
+    (2331, "unnest", &[2277], 0, 2283, true, false, Behavior::Immutable),
+    (871, "upper", &[25], 0, 25, false, false, Behavior::Immutable),
+];
+
+/// `(source type id, target type id, function id)` of the implicit casts between the built-in
+/// types. A function id of `0` means the types are binary coercible.
+const CASTS: &[(i64, i64, i64)] = &[
+    (18, 25, 946),
+    (19, 25, 406),
+    (20, 26, 1287),
+    (20, 700, 652),
+    (20, 701, 482),
+    (20, 1700, 1781),
+    (20, 2205, 1287),
+    (20, 2206, 1287),
+    (21, 20, 754),
+    (21, 23, 313),
+    (21, 26, 313),
+    (21, 700, 236),
+    (21, 701, 235),
+    (21, 1700, 1782),
+    (21, 2205, 313),
+    (21, 2206, 313),
+    (23, 20, 481),
+    (23, 26, 0),
+    (23, 700, 318),
+    (23, 701, 316),
+    (23, 1700, 1740),
+    (23, 2205, 0),
+    (23, 2206, 0),
+    (25, 19, 407),
+    (25, 1042, 0),
+    (25, 1043, 0),
+    (25, 2205, 1079),
+    (26, 2205, 0),
+    (26, 2206, 0),
+    (650, 869, 0),
+    (700, 701, 311),
+    (1042, 19, 409),
+    (1042, 25, 401),
+    (1042, 1042, 668),
+    (1042, 1043, 401),
+    (1043, 19, 1400),
+    (1043, 25, 0),
+    (1043, 1042, 0),
+    (1043, 1043, 669),
+    (1043, 2205, 1079),
+    (1082, 1114, 2024),
+    (1082, 1184, 1174),
+    (1083, 1083, 1968),
+    (1083, 1186, 1370),
+    (1083, 1266, 2047),
+    (1114, 1114, 1961),
+    (1114, 1184, 2028),
+    (1184, 1184, 1967),
+    (1186, 1186, 1200),
+    (1266, 1266, 1969),
+    (1560, 1560, 1685),
+    (1560, 1562, 0),
+    (1562, 1560, 0),
+    (1562, 1562, 1687),
+    (1700, 700, 1745),
+    (1700, 701, 1746),
+    (1700, 1700, 1703),
+    (2205, 26, 0),
+    (2206, 26, 0),
+];
+
+impl SchemaCache {
+    /// A cache that only contains the `pg_catalog` and `public` schemas and the most common
+    /// built-in types and functions.
+    ///
+    /// Used as the starting point when the schema is not loaded from a database, e.g. when it is
+    /// built by replaying migrations via [SchemaCache::mutate].
+    pub fn builtin() -> SchemaCache {
+        let schemas = vec![
+            Schema {
+                id: 11,
+                name: "pg_catalog".to_string(),
+                owner: "postgres".to_string(),
+            },
+            Schema {
+                id: 2200,
+                name: "public".to_string(),
+                owner: "postgres".to_string(),
+            },
+        ];
+
+        let mut types = Vec::new();
+        for (id, name, format, array_id, kind, range_subtype_id, is_preferred) in TYPES {
+            types.push(PostgresType {
+                kind: kind.clone(),
+                range_subtype_id: (*range_subtype_id != 0).then_some(*range_subtype_id),
+                is_preferred: *is_preferred,
+                ..builtin_type(*id, name.to_string(), format.to_string())
+            });
+            if *array_id != 0 {
+                types.push(builtin_type(
+                    *array_id,
+                    format!("_{}", name),
+                    format!("{}[]", format),
+                ));
+            }
+        }
+
+        let functions = FUNCTIONS
+            .iter()
+            .map(|function| builtin_function(&types, function))
+            .collect();
+
+        let casts = CASTS
+            .iter()
+            .enumerate()
+            .map(
+                |(idx, (source_type_id, target_type_id, function_id))| Cast {
+                    id: -(idx as i64) - 1,
+                    source_type_id: *source_type_id,
+                    target_type_id: *target_type_id,
+                    function_id: (*function_id != 0).then_some(*function_id),
+                    context: CastContext::Implicit,
+                },
+            )
+            .collect();
+
+        let mut cache = SchemaCache::default();
+        *cache.schemas_mut() = schemas;
+        *cache.types_mut() = types;
+        *cache.functions_mut() = functions;
+        *cache.casts_mut() = casts;
+        cache
+    }
+}
+
+fn builtin_type(id: i64, name: String, format: String) -> PostgresType {
+    PostgresType {
+        id,
+        name,
+        schema: "pg_catalog".to_string(),
+        format,
+        ..Default::default()
+    }
+}
+
+fn builtin_function(types: &[PostgresType], function: &BuiltinFunction) -> Function {
+    let (
+        id,
+        name,
+        arg_type_ids,
+        defaults,
+        return_type_id,
+        is_set_returning_function,
+        is_variadic,
+        behavior,
+    ) = function;
+
+    let format = |type_id: i64| {
+        types
+            .iter()
+            .find(|t| t.id == type_id)
+            .map(|t| t.format.clone())
+            .unwrap_or_default()
+    };
+
+    let args = arg_type_ids
+        .iter()
+        .enumerate()
+        .map(|(idx, type_id)| FunctionArg {
+            mode: if *is_variadic && idx == arg_type_ids.len() - 1 {
+                "variadic".to_string()
+            } else {
+                "in".to_string()
+            },
+            name: String::new(),
+            type_id: *type_id,
+            has_default: Some(idx >= arg_type_ids.len() - *defaults),
+        })
+        .collect();
+
+    let argument_types = if arg_type_ids.is_empty() {
+        None
+    } else {
+        Some(
+            arg_type_ids
+                .iter()
+                .map(|type_id| format(*type_id))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    };
+
+    Function {
+        id: *id,
+        schema: "pg_catalog".to_string(),
+        name: name.to_string(),
+        language: "internal".to_string(),
+        body: None,
+        definition: None,
+        args: FunctionArgs { args },
+        identity_argument_types: argument_types.clone(),
+        argument_types,
+        return_type_id: *return_type_id,
+        return_type: format(*return_type_id),
+        return_type_relation_id: None,
+        is_set_returning_function: *is_set_returning_function,
+        behavior: behavior.clone(),
+        security_definer: false,
+        extension: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SchemaCache;
+
+    #[test]
+    fn contains_common_types_and_functions() {
+        let cache = SchemaCache::builtin();
+
+        let int4 = cache
+            .types()
+            .iter()
+            .find(|t| t.schema == "pg_catalog" && t.name == "int4")
+            .unwrap();
+        assert_eq!(int4.id, 23);
+        assert_eq!(int4.format, "integer");
+
+        let text_array = cache.types().iter().find(|t| t.name == "_text").unwrap();
+        assert_eq!(text_array.format, "text[]");
+
+        let lower = cache
+            .functions()
+            .iter()
+            .find(|f| f.name == "lower")
+            .unwrap();
+        assert_eq!(lower.return_type, "text");
+        assert_eq!(lower.argument_types.as_deref(), Some("text"));
+
+        assert!(cache.schemas().iter().any(|s| s.name == "public"));
+    }
+}
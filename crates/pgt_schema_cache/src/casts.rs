@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// The contexts in which a cast is applied without being requested explicitly.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CastContext {
+    /// The cast is applied implicitly in any context, e.g. when resolving function calls.
+    Implicit,
+    /// The cast is applied implicitly when assigning to a column.
+    Assignment,
+    /// The cast is only applied if requested via `cast(.. as ..)` or `::`.
+    #[default]
+    Explicit,
+}
+
+impl From<String> for CastContext {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "IMPLICIT" => CastContext::Implicit,
+            "ASSIGNMENT" => CastContext::Assignment,
+            "EXPLICIT" => CastContext::Explicit,
+            _ => panic!("Invalid cast context"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cast {
+    /// The Id (`oid`) of the cast.
+    pub id: i64,
+
+    /// Refers to the type that is converted in the `pg_type` table.
+    pub source_type_id: i64,
+
+    /// Refers to the type that is converted to in the `pg_type` table.
+    pub target_type_id: i64,
+
+    /// The function that performs the conversion. `None` if the types are binary coercible or the
+    /// conversion uses their I/O functions.
+    pub function_id: Option<i64>,
+
+    pub context: CastContext,
+}
+
+impl SchemaCacheItem for Cast {
+    type Item = Cast;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Cast>, sqlx::Error> {
+        sqlx::query_file_as!(Cast, "src/queries/casts.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{SchemaCache, casts::CastContext};
+
+    #[tokio::test]
+    async fn loads_casts() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create type public.meters as (value numeric);
+
+            create function public.meters_to_numeric(m public.meters) returns numeric
+            language sql immutable as $$ select m.value $$;
+
+            create cast (public.meters as numeric)
+                with function public.meters_to_numeric(public.meters)
+                as assignment;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let int4 = cache.find_type("int4", Some("pg_catalog")).unwrap();
+        let int8 = cache.find_type("int8", Some("pg_catalog")).unwrap();
+        let int4_to_int8 = cache.find_cast(int4.id, int8.id).unwrap();
+        assert_eq!(int4_to_int8.context, CastContext::Implicit);
+        assert!(int4_to_int8.function_id.is_some());
+
+        let meters = cache.find_type("meters", Some("public")).unwrap();
+        let numeric = cache.find_type("numeric", Some("pg_catalog")).unwrap();
+        let meters_to_numeric = cache.find_cast(meters.id, numeric.id).unwrap();
+        assert_eq!(meters_to_numeric.context, CastContext::Assignment);
+        assert_eq!(
+            meters_to_numeric.function_id,
+            cache
+                .find_functions("meters_to_numeric", Some("public"))
+                .first()
+                .map(|f| f.id)
+        );
+    }
+}
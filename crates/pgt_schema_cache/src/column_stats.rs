@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// The planner statistics of a column, as gathered by `ANALYZE`. See `pg_stats`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub table_oid: i64,
+    pub schema_name: String,
+    pub table_name: String,
+    pub column_name: String,
+
+    /// Whether the statistics cover the rows of child tables and partitions, too. Only the
+    /// statistics of the whole hierarchy are loaded for tables that have children.
+    pub inherited: bool,
+
+    /// The fraction of rows that are `null`.
+    pub null_frac: f32,
+
+    /// The average size of the values in bytes.
+    pub avg_width: i32,
+
+    /// The number of distinct values if positive. If negative, the negated fraction of rows that
+    /// are distinct, e.g. `-1` for a unique column. See [ColumnStats::distinct_values].
+    pub n_distinct: f32,
+
+    /// The most common values, rendered as text.
+    pub most_common_values: Option<Vec<String>>,
+
+    /// The frequencies of the [ColumnStats::most_common_values].
+    pub most_common_freqs: Option<Vec<f32>>,
+
+    /// How closely the physical order of the rows matches the order of the values, from `-1` to
+    /// `1`.
+    pub correlation: Option<f32>,
+}
+
+impl ColumnStats {
+    /// The estimated number of distinct values in a table with `row_count` rows.
+    pub fn distinct_values(&self, row_count: i64) -> f64 {
+        if self.n_distinct < 0.0 {
+            -f64::from(self.n_distinct) * row_count as f64
+        } else {
+            f64::from(self.n_distinct)
+        }
+    }
+
+    /// The estimated fraction of rows matching `column = value` in a table with `row_count` rows,
+    /// estimated the same way the planner does.
+    pub fn selectivity(&self, value: &str, row_count: i64) -> f64 {
+        let mcvs = self.most_common_values.as_deref().unwrap_or_default();
+        let freqs = self.most_common_freqs.as_deref().unwrap_or_default();
+
+        if let Some(idx) = mcvs.iter().position(|v| v == value) {
+            return freqs.get(idx).copied().map(f64::from).unwrap_or_default();
+        }
+
+        // values that are not among the most common ones share the remaining rows evenly
+        let remaining_frac =
+            1.0 - f64::from(self.null_frac) - freqs.iter().copied().map(f64::from).sum::<f64>();
+        let remaining_distinct = self.distinct_values(row_count) - mcvs.len() as f64;
+
+        if remaining_distinct < 1.0 {
+            return 0.0;
+        }
+
+        (remaining_frac / remaining_distinct).clamp(0.0, 1.0)
+    }
+}
+
+impl SchemaCacheItem for ColumnStats {
+    type Item = ColumnStats;
+
+    async fn load(pool: &PgPool) -> Result<Vec<ColumnStats>, sqlx::Error> {
+        sqlx::query_file_as!(ColumnStats, "src/queries/column_stats.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::SchemaCache;
+
+    #[tokio::test]
+    async fn loads_column_stats_on_demand() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.orders (
+                id int primary key,
+                status text,
+                note text
+            );
+
+            insert into public.orders
+            select
+                i,
+                case when i % 10 = 0 then 'open' else 'closed' end,
+                null
+            from generate_series(1, 1000) as i;
+
+            analyze public.orders;
+
+            create materialized view public.open_orders as
+            select id from public.orders where status = 'open';
+
+            analyze public.open_orders;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        assert!(cache.column_stats().is_none());
+        assert!(cache.find_column_stats("id", "orders", None).is_none());
+
+        cache
+            .load_column_stats(&test_db)
+            .await
+            .expect("Failed to load column stats");
+
+        let id = cache.find_column_stats("id", "orders", None).unwrap();
+        assert_eq!(id.n_distinct, -1.0);
+        assert_eq!(id.distinct_values(1000), 1000.0);
+        assert!(id.most_common_values.is_none());
+
+        let status = cache.find_column_stats("status", "orders", None).unwrap();
+        assert_eq!(status.distinct_values(1000), 2.0);
+        assert_eq!(
+            status.most_common_values,
+            Some(vec!["closed".to_string(), "open".to_string()])
+        );
+        assert!((status.selectivity("open", 1000) - 0.1).abs() < 0.001);
+        assert_eq!(status.selectivity("cancelled", 1000), 0.0);
+
+        let note = cache.find_column_stats("note", "orders", None).unwrap();
+        assert_eq!(note.null_frac, 1.0);
+
+        let id = cache.find_column_stats("id", "open_orders", None).unwrap();
+        assert_eq!(id.distinct_values(100), 100.0);
+    }
+}
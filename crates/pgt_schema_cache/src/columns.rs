@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::schema_cache::SchemaCacheItem;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ColumnClassKind {
     OrdinaryTable,
     View,
@@ -37,7 +39,53 @@ impl From<char> for ColumnClassKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Whether, and how, Postgres generates the values of a column.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColumnGeneration {
+    /// A regular column. Its values are provided by the user or by the column's default.
+    #[default]
+    None,
+    /// `GENERATED ALWAYS AS IDENTITY`. Values can only be provided with `OVERRIDING SYSTEM VALUE`.
+    IdentityAlways,
+    /// `GENERATED BY DEFAULT AS IDENTITY`.
+    IdentityByDefault,
+    /// `GENERATED ALWAYS AS (...) STORED`. Values can never be provided.
+    Stored,
+    /// `GENERATED ALWAYS AS (...) VIRTUAL`, available since Postgres 18. Values can never be provided.
+    Virtual,
+}
+
+impl ColumnGeneration {
+    pub fn is_identity(&self) -> bool {
+        matches!(
+            self,
+            ColumnGeneration::IdentityAlways | ColumnGeneration::IdentityByDefault
+        )
+    }
+
+    /// Whether Postgres rejects values for the column in a plain `INSERT` or `UPDATE`.
+    pub fn is_generated_always(&self) -> bool {
+        matches!(
+            self,
+            ColumnGeneration::IdentityAlways | ColumnGeneration::Stored | ColumnGeneration::Virtual
+        )
+    }
+}
+
+impl From<String> for ColumnGeneration {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "NONE" => ColumnGeneration::None,
+            "IDENTITY ALWAYS" => ColumnGeneration::IdentityAlways,
+            "IDENTITY BY DEFAULT" => ColumnGeneration::IdentityByDefault,
+            "STORED" => ColumnGeneration::Stored,
+            "VIRTUAL" => ColumnGeneration::Virtual,
+            _ => panic!("Invalid column generation"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Column {
     pub name: String,
 
@@ -53,7 +101,10 @@ pub struct Column {
     pub is_primary_key: bool,
     pub is_unique: bool,
 
+    pub generation: ColumnGeneration,
+
     /// The Default "value" of the column. Might be a function call, hence "_expr".
+    /// For stored and virtual generated columns, this is the generation expression.
     pub default_expr: Option<String>,
 
     pub varchar_length: Option<i32>,
@@ -62,7 +113,7 @@ pub struct Column {
     pub comment: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ForeignKeyReference {
     pub schema: Option<String>,
     pub table: String,
@@ -84,7 +135,10 @@ mod tests {
     use pgt_test_utils::test_database::get_new_test_db;
     use sqlx::Executor;
 
-    use crate::{SchemaCache, columns::ColumnClassKind};
+    use crate::{
+        SchemaCache,
+        columns::{ColumnClassKind, ColumnGeneration},
+    };
 
     #[tokio::test]
     async fn loads_columns() {
@@ -126,7 +180,7 @@ mod tests {
             .expect("Failed to load Schema Cache");
 
         let public_schema_columns = cache
-            .columns
+            .columns()
             .iter()
             .filter(|c| c.schema_name.as_str() == "public")
             .count();
@@ -134,7 +188,7 @@ mod tests {
         assert_eq!(public_schema_columns, 4);
 
         let real_estate_schema_columns = cache
-            .columns
+            .columns()
             .iter()
             .filter(|c| c.schema_name.as_str() == "real_estate")
             .count();
@@ -199,4 +253,47 @@ mod tests {
         assert!(!properties_owner_id_col.is_unique);
         assert_eq!(properties_owner_id_col.varchar_length, None);
     }
+
+    #[tokio::test]
+    async fn loads_identity_and_generated_columns() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.rooms (
+                id int generated always as identity primary key,
+                property_id int generated by default as identity,
+                width smallint not null,
+                length smallint not null,
+                area int generated always as (width * length) stored
+            );
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let room_id_col = cache.find_col("id", "rooms", None).unwrap();
+        assert_eq!(room_id_col.generation, ColumnGeneration::IdentityAlways);
+        assert!(room_id_col.generation.is_generated_always());
+
+        let room_property_id_col = cache.find_col("property_id", "rooms", None).unwrap();
+        assert_eq!(
+            room_property_id_col.generation,
+            ColumnGeneration::IdentityByDefault
+        );
+        assert!(!room_property_id_col.generation.is_generated_always());
+
+        let room_area_col = cache.find_col("area", "rooms", None).unwrap();
+        assert_eq!(room_area_col.generation, ColumnGeneration::Stored);
+        assert_eq!(room_area_col.default_expr, Some("(width * length)".into()));
+
+        let width_col = cache.find_col("width", "rooms", None).unwrap();
+        assert_eq!(width_col.generation, ColumnGeneration::None);
+        assert!(!width_col.generation.is_identity());
+    }
 }
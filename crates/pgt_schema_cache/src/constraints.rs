@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    PrimaryKey,
+    ForeignKey,
+    Check,
+    Unique,
+    Exclusion,
+    /// A constraint trigger, created via `CREATE CONSTRAINT TRIGGER`.
+    Trigger,
+    NotNull,
+}
+
+impl From<String> for ConstraintKind {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "PRIMARY KEY" => ConstraintKind::PrimaryKey,
+            "FOREIGN KEY" => ConstraintKind::ForeignKey,
+            "CHECK" => ConstraintKind::Check,
+            "UNIQUE" => ConstraintKind::Unique,
+            "EXCLUDE" => ConstraintKind::Exclusion,
+            "TRIGGER" => ConstraintKind::Trigger,
+            "NOT NULL" => ConstraintKind::NotNull,
+            _ => panic!("Invalid constraint kind"),
+        }
+    }
+}
+
+/// What happens to the referencing rows when a referenced row is updated or deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ForeignKeyAction {
+    #[default]
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl From<Option<String>> for ForeignKeyAction {
+    fn from(s: Option<String>) -> Self {
+        match s {
+            Some(s) => match s.as_str() {
+                "NO ACTION" => ForeignKeyAction::NoAction,
+                "RESTRICT" => ForeignKeyAction::Restrict,
+                "CASCADE" => ForeignKeyAction::Cascade,
+                "SET NULL" => ForeignKeyAction::SetNull,
+                "SET DEFAULT" => ForeignKeyAction::SetDefault,
+                _ => panic!("Invalid foreign key action"),
+            },
+            None => ForeignKeyAction::NoAction,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constraint {
+    /// The Id (`oid`) of the constraint.
+    pub id: i64,
+
+    /// The name of the schema the constraint belongs to.
+    pub schema: String,
+
+    pub name: String,
+
+    /// The Id (`oid`) of the table the constraint is defined on.
+    pub table_id: i64,
+
+    pub table_name: String,
+
+    pub kind: ConstraintKind,
+
+    /// The constrained columns, in constraint order. Empty for constraints that only consist of an
+    /// expression, such as most `CHECK` constraints.
+    pub columns: Vec<String>,
+
+    /// The Id (`oid`) of the referenced table. Only set for foreign keys.
+    pub referenced_table_id: Option<i64>,
+
+    /// Only set for foreign keys.
+    pub referenced_schema: Option<String>,
+
+    /// Only set for foreign keys.
+    pub referenced_table: Option<String>,
+
+    /// The referenced columns, matching `columns` by position. Only set for foreign keys.
+    pub referenced_columns: Vec<String>,
+
+    /// `ON UPDATE` action of a foreign key. `NoAction` for all other constraint kinds.
+    pub on_update: ForeignKeyAction,
+
+    /// `ON DELETE` action of a foreign key. `NoAction` for all other constraint kinds.
+    pub on_delete: ForeignKeyAction,
+
+    pub is_deferrable: bool,
+
+    pub is_initially_deferred: bool,
+
+    /// `false` if the constraint was added with `NOT VALID` and has not been validated since.
+    pub is_validated: bool,
+
+    /// The constraint as it would appear in `ALTER TABLE ... ADD CONSTRAINT`, e.g.
+    /// `FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE`.
+    pub definition: String,
+
+    /// Comment inserted via `COMMENT ON CONSTRAINT my_constraint ON my_table IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for Constraint {
+    type Item = Constraint;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Constraint>, sqlx::Error> {
+        sqlx::query_file_as!(Constraint, "src/queries/constraints.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{
+        SchemaCache,
+        constraints::{ConstraintKind, ForeignKeyAction},
+    };
+
+    #[tokio::test]
+    async fn loads_constraints() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                email text unique deferrable initially deferred
+            );
+
+            create schema booking;
+
+            create table booking.reservations (
+                id serial primary key,
+                user_id int not null,
+                during tstzrange not null,
+                seats int not null,
+                exclude using gist (during with &&)
+            );
+
+            alter table booking.reservations
+                add constraint reservations_user_id_fkey
+                foreign key (user_id) references public.users (id)
+                on delete cascade on update restrict;
+
+            alter table booking.reservations
+                add constraint reservations_seats_check check (seats > 0) not valid;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let find = |name: &str| {
+            cache
+                .constraints()
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap_or_else(|| panic!("constraint {name} not found"))
+        };
+
+        let pkey = find("users_pkey");
+        assert_eq!(pkey.kind, ConstraintKind::PrimaryKey);
+        assert_eq!(pkey.columns, vec!["id".to_string()]);
+        assert_eq!(pkey.referenced_table, None);
+
+        let unique = find("users_email_key");
+        assert_eq!(unique.kind, ConstraintKind::Unique);
+        assert!(unique.is_deferrable);
+        assert!(unique.is_initially_deferred);
+
+        let fkey = find("reservations_user_id_fkey");
+        assert_eq!(fkey.kind, ConstraintKind::ForeignKey);
+        assert_eq!(fkey.schema, "booking");
+        assert_eq!(fkey.table_name, "reservations");
+        assert_eq!(fkey.columns, vec!["user_id".to_string()]);
+        assert_eq!(fkey.referenced_schema, Some("public".into()));
+        assert_eq!(fkey.referenced_table, Some("users".into()));
+        assert_eq!(fkey.referenced_columns, vec!["id".to_string()]);
+        assert_eq!(fkey.on_delete, ForeignKeyAction::Cascade);
+        assert_eq!(fkey.on_update, ForeignKeyAction::Restrict);
+        assert!(fkey.is_validated);
+
+        let check = find("reservations_seats_check");
+        assert_eq!(check.kind, ConstraintKind::Check);
+        assert_eq!(check.columns, vec!["seats".to_string()]);
+        assert!(!check.is_validated);
+        assert_eq!(check.definition, "CHECK ((seats > 0)) NOT VALID");
+        assert_eq!(check.on_delete, ForeignKeyAction::NoAction);
+
+        let exclusion = find("reservations_during_excl");
+        assert_eq!(exclusion.kind, ConstraintKind::Exclusion);
+        assert_eq!(exclusion.columns, vec!["during".to_string()]);
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// An extension that is available on the server. It may or may not be installed in the database.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Extension {
+    pub name: String,
+
+    /// The schema the extension's objects were installed into. `None` if the extension is not installed.
+    pub schema: Option<String>,
+
+    /// `None` if the extension is not installed.
+    pub installed_version: Option<String>,
+
+    /// The version that `CREATE EXTENSION` or `ALTER EXTENSION ... UPDATE` would install.
+    pub default_version: Option<String>,
+
+    pub comment: Option<String>,
+}
+
+impl Extension {
+    pub fn is_installed(&self) -> bool {
+        self.installed_version.is_some()
+    }
+
+    /// The version the installed extension could be updated to, if it is outdated.
+    pub fn available_update(&self) -> Option<&str> {
+        match (&self.installed_version, &self.default_version) {
+            (Some(installed), Some(default)) if installed != default => Some(default.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl SchemaCacheItem for Extension {
+    type Item = Extension;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Extension>, sqlx::Error> {
+        sqlx::query_file_as!(Extension, "src/queries/extensions.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::SchemaCache;
+
+    #[tokio::test]
+    async fn loads_extensions() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create schema extensions;
+            create extension citext with schema extensions;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let citext = cache
+            .extensions()
+            .iter()
+            .find(|e| e.name == "citext")
+            .unwrap();
+        assert!(citext.is_installed());
+        assert_eq!(citext.schema, Some("extensions".into()));
+        assert_eq!(citext.installed_version, citext.default_version);
+        assert_eq!(citext.available_update(), None);
+
+        let hstore = cache
+            .extensions()
+            .iter()
+            .find(|e| e.name == "hstore")
+            .unwrap();
+        assert!(!hstore.is_installed());
+        assert_eq!(hstore.schema, None);
+        assert!(hstore.default_version.is_some());
+
+        let citext_type = cache
+            .types()
+            .iter()
+            .find(|t| t.name == "citext" && t.schema == "extensions")
+            .unwrap();
+        assert_eq!(citext_type.extension, Some("citext".into()));
+
+        let citext_fn = cache
+            .functions()
+            .iter()
+            .find(|f| f.name == "citext_eq")
+            .unwrap();
+        assert_eq!(citext_fn.extension, Some("citext".into()));
+
+        let builtin_fn = cache
+            .functions()
+            .iter()
+            .find(|f| f.name == "lower")
+            .unwrap();
+        assert_eq!(builtin_fn.extension, None);
+    }
+}
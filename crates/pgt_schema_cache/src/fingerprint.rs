@@ -0,0 +1,124 @@
+use sqlx::PgPool;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CatalogFingerprint {
+    catalog: String,
+    fingerprint: String,
+}
+
+/// A cheap summary of the system catalogs, used to detect schema changes without reloading the
+/// whole [crate::SchemaCache].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaFingerprint {
+    catalogs: Vec<CatalogFingerprint>,
+}
+
+impl SchemaFingerprint {
+    pub async fn load(pool: &PgPool) -> Result<SchemaFingerprint, sqlx::Error> {
+        let catalogs = sqlx::query_file_as!(CatalogFingerprint, "src/queries/fingerprint.sql")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(SchemaFingerprint { catalogs })
+    }
+
+    /// Returns the names of the catalogs that differ between the two fingerprints, e.g.
+    /// `pg_class`.
+    pub fn changed_catalogs(&self, previous: &SchemaFingerprint) -> Vec<String> {
+        self.catalogs
+            .iter()
+            .filter(|current| !previous.catalogs.contains(current))
+            .map(|current| current.catalog.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::{Executor, PgPool};
+
+    use crate::{SchemaCache, SchemaFingerprint};
+
+    #[tokio::test]
+    async fn detects_changed_catalogs() {
+        let test_db = get_new_test_db().await;
+
+        let before = SchemaFingerprint::load(&test_db).await.unwrap();
+        assert!(
+            SchemaFingerprint::load(&test_db)
+                .await
+                .unwrap()
+                .changed_catalogs(&before)
+                .is_empty()
+        );
+
+        test_db
+            .execute("create table public.users (id serial primary key);")
+            .await
+            .expect("Failed to setup test database");
+
+        let after = SchemaFingerprint::load(&test_db).await.unwrap();
+        let changed = after.changed_catalogs(&before);
+
+        assert!(changed.contains(&"pg_class".to_string()));
+        assert!(changed.contains(&"pg_attribute".to_string()));
+        assert!(!changed.contains(&"pg_proc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn refreshes_changed_items() {
+        let test_db = get_new_test_db().await;
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+        let before = SchemaFingerprint::load(&test_db).await.unwrap();
+
+        test_db
+            .execute("create table public.users (id serial primary key, name text);")
+            .await
+            .expect("Failed to setup test database");
+
+        let changed = SchemaFingerprint::load(&test_db)
+            .await
+            .unwrap()
+            .changed_catalogs(&before);
+        cache.refresh(&test_db, &changed).await.unwrap();
+
+        assert!(cache.find_table("users", Some("public")).is_some());
+        assert!(cache.find_col("name", "users", Some("public")).is_some());
+    }
+
+    #[tokio::test]
+    async fn refreshes_search_path() {
+        let test_db = get_new_test_db().await;
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+        assert!(!cache.search_path().contains(&"audit".to_string()));
+
+        let database = test_db
+            .connect_options()
+            .get_database()
+            .unwrap()
+            .to_string();
+        let setup = format!(
+            "create schema audit;
+            alter database \"{database}\" set search_path = audit, public;"
+        );
+        test_db
+            .execute(setup.as_str())
+            .await
+            .expect("Failed to setup test database");
+
+        // the setting only applies to new sessions
+        let pool = PgPool::connect_with(test_db.connect_options().as_ref().clone())
+            .await
+            .unwrap();
+        cache.refresh(&pool, &[]).await.unwrap();
+
+        assert_eq!(cache.search_path(), ["pg_catalog", "audit", "public"]);
+    }
+}
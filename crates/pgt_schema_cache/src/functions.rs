@@ -104,6 +104,9 @@ pub struct Function {
 
     /// Is the function's security set to `Definer` (true) or `Invoker` (false)?
     pub security_definer: bool,
+
+    /// The name of the extension that installed the function, e.g. `pgcrypto`. `None` for functions that are not owned by an extension.
+    pub extension: Option<String>,
 }
 
 impl SchemaCacheItem for Function {
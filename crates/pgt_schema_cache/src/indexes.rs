@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Index {
+    /// The Id (`oid`) of the index.
+    pub id: i64,
+
+    /// The name of the schema the index belongs to.
+    pub schema: String,
+
+    pub name: String,
+
+    /// The Id (`oid`) of the indexed table.
+    pub table_id: i64,
+
+    pub table_name: String,
+
+    /// The access method of the index, e.g. `btree`, `gin` or `gist`.
+    pub method: String,
+
+    /// The names of the plain columns that are part of the index key, in key order.
+    /// Does not contain `INCLUDE` columns.
+    pub columns: Vec<String>,
+
+    /// The expressions that are part of the index key, e.g. `lower(email)`.
+    pub expressions: Vec<String>,
+
+    pub is_unique: bool,
+
+    pub is_primary: bool,
+
+    /// `false` if the index is not usable for queries yet, for example because a
+    /// `CREATE INDEX CONCURRENTLY` failed or is still in progress.
+    pub is_valid: bool,
+
+    /// The `WHERE` clause of a partial index.
+    pub predicate: Option<String>,
+
+    /// The full `CREATE INDEX ...` statement.
+    pub definition: String,
+
+    pub bytes: i64,
+
+    /// Human-readable size, e.g. `16 kB`.
+    pub size: String,
+
+    /// Comment inserted via `COMMENT ON INDEX my_index IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for Index {
+    type Item = Index;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Index>, sqlx::Error> {
+        sqlx::query_file_as!(Index, "src/queries/indexes.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::SchemaCache;
+
+    #[tokio::test]
+    async fn loads_indexes() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                email text not null,
+                name text,
+                deleted_at timestamptz
+            );
+
+            create unique index users_email_idx on public.users (lower(email));
+
+            create index users_name_idx on public.users using hash (name)
+                where deleted_at is null;
+
+            create index users_name_email_idx on public.users (name) include (email);
+
+            comment on index users_name_idx is 'only active users';
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let user_indexes = cache
+            .indexes()
+            .iter()
+            .filter(|i| i.table_name == "users" && i.schema == "public")
+            .count();
+
+        assert_eq!(user_indexes, 4);
+
+        let pkey = cache
+            .indexes()
+            .iter()
+            .find(|i| i.name == "users_pkey")
+            .unwrap();
+        assert!(pkey.is_primary);
+        assert!(pkey.is_unique);
+        assert!(pkey.is_valid);
+        assert_eq!(pkey.method, "btree");
+        assert_eq!(pkey.columns, vec!["id".to_string()]);
+        assert!(pkey.expressions.is_empty());
+        assert_eq!(pkey.predicate, None);
+
+        let email_idx = cache
+            .indexes()
+            .iter()
+            .find(|i| i.name == "users_email_idx")
+            .unwrap();
+        assert!(!email_idx.is_primary);
+        assert!(email_idx.is_unique);
+        assert!(email_idx.columns.is_empty());
+        assert_eq!(email_idx.expressions, vec!["lower(email)".to_string()]);
+
+        let name_idx = cache
+            .indexes()
+            .iter()
+            .find(|i| i.name == "users_name_idx")
+            .unwrap();
+        assert_eq!(name_idx.method, "hash");
+        assert_eq!(name_idx.columns, vec!["name".to_string()]);
+        assert_eq!(name_idx.predicate, Some("(deleted_at IS NULL)".into()));
+        assert_eq!(name_idx.comment, Some("only active users".into()));
+        assert!(
+            name_idx
+                .definition
+                .starts_with("CREATE INDEX users_name_idx ON public.users USING hash")
+        );
+
+        let covering_idx = cache
+            .indexes()
+            .iter()
+            .find(|i| i.name == "users_name_email_idx")
+            .unwrap();
+        assert_eq!(covering_idx.columns, vec!["name".to_string()]);
+    }
+}
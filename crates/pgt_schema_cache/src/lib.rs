@@ -2,15 +2,48 @@
 
 #![allow(dead_code)]
 
+mod builtins;
+mod casts;
+mod column_stats;
 mod columns;
+mod constraints;
+mod extensions;
+mod fingerprint;
 mod functions;
+mod indexes;
+mod mutations;
+mod policies;
+mod privileges;
+mod roles;
 mod schema_cache;
 mod schemas;
+mod sequences;
+mod snapshot;
 mod tables;
+mod triggers;
 mod types;
 mod versions;
+mod views;
 
+pub use casts::{Cast, CastContext};
+pub use column_stats::ColumnStats;
 pub use columns::*;
+pub use constraints::{Constraint, ConstraintKind, ForeignKeyAction};
+pub use extensions::Extension;
+pub use fingerprint::SchemaFingerprint;
 pub use functions::{Behavior, Function, FunctionArg, FunctionArgs};
+pub use indexes::Index;
+pub use policies::{Policy, PolicyCommand};
+pub use privileges::{Privilege, PrivilegeObjectKind, PrivilegeType};
+pub use roles::Role;
 pub use schema_cache::SchemaCache;
-pub use tables::{ReplicaIdentity, Table};
+pub use sequences::Sequence;
+pub use snapshot::{SNAPSHOT_FORMAT_VERSION, SnapshotError};
+pub use tables::{ReplicaIdentity, Table, TableKind};
+pub use triggers::{EventTrigger, Trigger, TriggerEnabled, TriggerLevel, TriggerTiming};
+pub use types::{
+    DomainConstraint, DomainConstraints, Enums, PostgresType, PostgresTypeAttribute,
+    TypeAttributes, TypeKind,
+};
+pub use versions::Version;
+pub use views::{View, ViewColumnDependencies, ViewColumnDependency};
@@ -0,0 +1,1633 @@
+use pgt_query_ext::{NodeEnum, protobuf};
+use protobuf::{AlterTableType, ConstrType, FunctionParameterMode, ObjectType};
+
+use crate::columns::{Column, ColumnClassKind, ColumnGeneration};
+use crate::functions::{Behavior, Function, FunctionArg, FunctionArgs};
+use crate::schema_cache::SchemaCache;
+use crate::schemas::Schema;
+use crate::tables::{Table, TableKind};
+use crate::types::{
+    DomainConstraint, DomainConstraints, Enums, PostgresType, PostgresTypeAttribute,
+    TypeAttributes, TypeKind,
+};
+
+impl SchemaCache {
+    /// Applies a DDL statement to the cache, so that it reflects the database as if the statement
+    /// had been executed.
+    ///
+    /// For example, `alter table add column` will add the column to the table if it does not
+    /// exist yet. Objects created this way have not been assigned an `oid` by Postgres, so they get
+    /// negative ids. Statements that do not change the schema are ignored.
+    pub fn mutate(&mut self, node: &NodeEnum) {
+        match node {
+            NodeEnum::CreateSchemaStmt(stmt) => self.create_schema(stmt),
+            NodeEnum::CreateStmt(stmt) => self.create_table(stmt),
+            NodeEnum::AlterTableStmt(stmt) => self.alter_table(stmt),
+            NodeEnum::RenameStmt(stmt) => self.rename(stmt),
+            NodeEnum::DropStmt(stmt) => self.drop(stmt),
+            NodeEnum::CreateFunctionStmt(stmt) => self.create_function(stmt),
+            NodeEnum::CreateEnumStmt(stmt) => self.create_enum(stmt),
+            NodeEnum::CompositeTypeStmt(stmt) => self.create_composite_type(stmt),
+            NodeEnum::CreateDomainStmt(stmt) => self.create_domain(stmt),
+            NodeEnum::CreateRangeStmt(stmt) => self.create_range(stmt),
+            _ => {}
+        }
+    }
+
+    /// Whether [SchemaCache::mutate] would change the cache for this statement.
+    pub fn is_mutation(node: &NodeEnum) -> bool {
+        matches!(
+            node,
+            NodeEnum::CreateSchemaStmt(_)
+                | NodeEnum::CreateStmt(_)
+                | NodeEnum::AlterTableStmt(_)
+                | NodeEnum::RenameStmt(_)
+                | NodeEnum::DropStmt(_)
+                | NodeEnum::CreateFunctionStmt(_)
+                | NodeEnum::CreateEnumStmt(_)
+                | NodeEnum::CompositeTypeStmt(_)
+                | NodeEnum::CreateDomainStmt(_)
+                | NodeEnum::CreateRangeStmt(_)
+        )
+    }
+
+    fn create_schema(&mut self, stmt: &protobuf::CreateSchemaStmt) {
+        let owner = stmt
+            .authrole
+            .as_ref()
+            .map(|role| role.rolename.clone())
+            .unwrap_or_default();
+
+        // `create schema authorization my_role` creates a schema named after the role
+        let name = if stmt.schemaname.is_empty() {
+            owner.clone()
+        } else {
+            stmt.schemaname.clone()
+        };
+
+        if self.schemas().iter().any(|s| s.name == name) {
+            return;
+        }
+
+        let id = self.next_id();
+        self.schemas_mut().push(Schema { id, name, owner });
+    }
+
+    fn create_table(&mut self, stmt: &protobuf::CreateStmt) {
+        let Some(relation) = &stmt.relation else {
+            return;
+        };
+        let (schema, name) = self.new_relation_name(relation);
+
+        if self.table_exists(&schema, &name) {
+            return;
+        }
+
+        let parent_ids: Vec<i64> = stmt
+            .inh_relations
+            .iter()
+            .filter_map(|n| match &n.node {
+                Some(NodeEnum::RangeVar(parent)) => self.find_relation_id(parent),
+                _ => None,
+            })
+            .collect();
+
+        let table = Table {
+            id: self.next_id(),
+            schema,
+            name,
+            kind: if stmt.partspec.is_some() {
+                TableKind::Partitioned
+            } else {
+                TableKind::Ordinary
+            },
+            is_partitioned: stmt.partspec.is_some(),
+            partition_key: stmt
+                .partspec
+                .as_ref()
+                .and_then(|partspec| deparse_partition_key(relation, partspec)),
+            is_partition: stmt.partbound.is_some(),
+            partition_bound: stmt
+                .partbound
+                .as_ref()
+                .and_then(|bound| deparse_partition_bound(relation, bound)),
+            parent_ids,
+            ..Default::default()
+        };
+
+        let class_kind = if stmt.partspec.is_some() {
+            ColumnClassKind::PartitionedTable
+        } else {
+            ColumnClassKind::OrdinaryTable
+        };
+
+        // a child table starts out with the columns of its parents
+        for parent_id in &table.parent_ids {
+            let inherited: Vec<Column> =
+                self.columns()
+                    .iter()
+                    .filter(|c| c.table_oid == *parent_id)
+                    .map(|c| Column {
+                        table_name: table.name.clone(),
+                        table_oid: table.id,
+                        schema_name: table.schema.clone(),
+                        class_kind: class_kind.clone(),
+                        // only partitions share the unique constraints of their parent
+                        is_primary_key: c.is_primary_key && table.is_partition,
+                        is_unique: c.is_unique && table.is_partition,
+                        ..c.clone()
+                    })
+                    .filter(|c| {
+                        !self.columns().iter().any(|existing| {
+                            existing.table_oid == table.id && existing.name == c.name
+                        })
+                    })
+                    .collect();
+            self.columns_mut().extend(inherited);
+        }
+
+        for elt in &stmt.table_elts {
+            if let Some(NodeEnum::ColumnDef(def)) = &elt.node {
+                let column = self.column_from_def(&table, class_kind.clone(), def);
+                self.columns_mut().push(column);
+            }
+        }
+
+        for elt in &stmt.table_elts {
+            if let Some(NodeEnum::Constraint(constraint)) = &elt.node {
+                self.apply_table_constraint(&table.schema, &table.name, constraint);
+            }
+        }
+
+        self.tables_mut().push(table);
+    }
+
+    fn alter_table(&mut self, stmt: &protobuf::AlterTableStmt) {
+        let Some(relation) = &stmt.relation else {
+            return;
+        };
+        let (schema, table_name) = self.relation_name(relation);
+
+        for cmd in &stmt.cmds {
+            let Some(NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                continue;
+            };
+
+            let def = cmd.def.as_ref().and_then(|def| def.node.as_ref());
+
+            match cmd.subtype() {
+                AlterTableType::AtAddColumn => {
+                    let Some(NodeEnum::ColumnDef(def)) = def else {
+                        continue;
+                    };
+
+                    let Some(table) = self
+                        .tables()
+                        .iter()
+                        .find(|t| t.schema == schema && t.name == table_name)
+                        .cloned()
+                    else {
+                        continue;
+                    };
+
+                    if self
+                        .find_column_mut(&schema, &table_name, &def.colname)
+                        .is_some()
+                    {
+                        continue;
+                    }
+
+                    let class_kind = self
+                        .columns()
+                        .iter()
+                        .find(|c| c.table_oid == table.id)
+                        .map(|c| c.class_kind.clone())
+                        .unwrap_or(ColumnClassKind::OrdinaryTable);
+
+                    let column = self.column_from_def(&table, class_kind, def);
+                    self.columns_mut().push(column);
+                }
+                AlterTableType::AtDropColumn => {
+                    self.columns_mut().retain(|c| {
+                        !(c.schema_name == schema
+                            && c.table_name == table_name
+                            && c.name == cmd.name)
+                    });
+                }
+                AlterTableType::AtAlterColumnType => {
+                    let Some(NodeEnum::ColumnDef(def)) = def else {
+                        continue;
+                    };
+                    let Some(type_name) = &def.type_name else {
+                        continue;
+                    };
+
+                    let type_id = self.resolve_type_id(type_name);
+                    if let Some(column) = self.find_column_mut(&schema, &table_name, &cmd.name) {
+                        column.type_id = type_id;
+                        column.varchar_length = varchar_length(type_name);
+                    }
+                }
+                AlterTableType::AtSetNotNull => {
+                    if let Some(column) = self.find_column_mut(&schema, &table_name, &cmd.name) {
+                        column.is_nullable = false;
+                    }
+                }
+                AlterTableType::AtDropNotNull => {
+                    if let Some(column) = self.find_column_mut(&schema, &table_name, &cmd.name) {
+                        column.is_nullable = true;
+                    }
+                }
+                AlterTableType::AtColumnDefault => {
+                    let default_expr = cmd.def.as_deref().and_then(deparse_expr);
+                    if let Some(column) = self.find_column_mut(&schema, &table_name, &cmd.name) {
+                        column.default_expr = default_expr;
+                    }
+                }
+                AlterTableType::AtAddConstraint => {
+                    if let Some(NodeEnum::Constraint(constraint)) = def {
+                        self.apply_table_constraint(&schema, &table_name, constraint);
+                    }
+                }
+                AlterTableType::AtAttachPartition => {
+                    let Some(NodeEnum::PartitionCmd(partition_cmd)) = def else {
+                        continue;
+                    };
+                    let Some(partition) = &partition_cmd.name else {
+                        continue;
+                    };
+
+                    let parent_id = self.find_relation_id(relation);
+                    let partition_bound = partition_cmd
+                        .bound
+                        .as_ref()
+                        .and_then(|bound| deparse_partition_bound(partition, bound));
+
+                    if let Some(table) = self.find_table_mut(partition) {
+                        table.is_partition = true;
+                        table.partition_bound = partition_bound;
+                        table.parent_ids = parent_id.into_iter().collect();
+                    }
+                }
+                AlterTableType::AtDetachPartition => {
+                    let Some(NodeEnum::PartitionCmd(partition_cmd)) = def else {
+                        continue;
+                    };
+                    let Some(partition) = &partition_cmd.name else {
+                        continue;
+                    };
+
+                    if let Some(table) = self.find_table_mut(partition) {
+                        table.is_partition = false;
+                        table.partition_bound = None;
+                        table.parent_ids.clear();
+                    }
+                }
+                AlterTableType::AtAddInherit | AlterTableType::AtDropInherit => {
+                    let Some(NodeEnum::RangeVar(parent)) = def else {
+                        continue;
+                    };
+                    let Some(parent_id) = self.find_relation_id(parent) else {
+                        continue;
+                    };
+
+                    let add = cmd.subtype() == AlterTableType::AtAddInherit;
+                    if let Some(table) = self.find_table_mut(relation) {
+                        table.parent_ids.retain(|id| *id != parent_id);
+                        if add {
+                            table.parent_ids.push(parent_id);
+                        }
+                    }
+                }
+                AlterTableType::AtEnableRowSecurity
+                | AlterTableType::AtDisableRowSecurity
+                | AlterTableType::AtForceRowSecurity
+                | AlterTableType::AtNoForceRowSecurity => {
+                    let subtype = cmd.subtype();
+                    if let Some(table) = self
+                        .tables_mut()
+                        .iter_mut()
+                        .find(|t| t.schema == schema && t.name == table_name)
+                    {
+                        match subtype {
+                            AlterTableType::AtEnableRowSecurity => table.rls_enabled = true,
+                            AlterTableType::AtDisableRowSecurity => table.rls_enabled = false,
+                            AlterTableType::AtForceRowSecurity => table.rls_forced = true,
+                            _ => table.rls_forced = false,
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn rename(&mut self, stmt: &protobuf::RenameStmt) {
+        let Some(relation) = &stmt.relation else {
+            return;
+        };
+        let (schema, name) = self.relation_name(relation);
+        let new_name = stmt.newname.clone();
+
+        match stmt.rename_type() {
+            ObjectType::ObjectTable | ObjectType::ObjectView | ObjectType::ObjectMatview => {
+                for table in self
+                    .tables_mut()
+                    .iter_mut()
+                    .filter(|t| t.schema == schema && t.name == name)
+                {
+                    table.name = new_name.clone();
+                }
+                for view in self
+                    .views_mut()
+                    .iter_mut()
+                    .filter(|v| v.schema == schema && v.name == name)
+                {
+                    view.name = new_name.clone();
+                }
+                for column in self
+                    .columns_mut()
+                    .iter_mut()
+                    .filter(|c| c.schema_name == schema && c.table_name == name)
+                {
+                    column.table_name = new_name.clone();
+                }
+                for index in self
+                    .indexes_mut()
+                    .iter_mut()
+                    .filter(|i| i.schema == schema && i.table_name == name)
+                {
+                    index.table_name = new_name.clone();
+                }
+                for constraint in self
+                    .constraints_mut()
+                    .iter_mut()
+                    .filter(|c| c.schema == schema && c.table_name == name)
+                {
+                    constraint.table_name = new_name.clone();
+                }
+                for policy in self
+                    .policies_mut()
+                    .iter_mut()
+                    .filter(|p| p.schema == schema && p.table_name == name)
+                {
+                    policy.table_name = new_name.clone();
+                }
+                for trigger in self
+                    .triggers_mut()
+                    .iter_mut()
+                    .filter(|t| t.schema == schema && t.table_name == name)
+                {
+                    trigger.table_name = new_name.clone();
+                }
+                // foreign keys and views refer to the table by name as well
+                for constraint in self.constraints_mut().iter_mut().filter(|c| {
+                    c.referenced_schema.as_deref() == Some(schema.as_str())
+                        && c.referenced_table.as_deref() == Some(name.as_str())
+                }) {
+                    constraint.referenced_table = Some(new_name.clone());
+                }
+                for dependency in self
+                    .views_mut()
+                    .iter_mut()
+                    .flat_map(|v| v.column_dependencies.columns.iter_mut())
+                    .filter(|d| d.schema == schema && d.table_name == name)
+                {
+                    dependency.table_name = new_name.clone();
+                }
+            }
+            ObjectType::ObjectColumn => {
+                let old_name = stmt.subname.as_str();
+
+                if let Some(column) = self.find_column_mut(&schema, &name, old_name) {
+                    column.name = new_name.clone();
+                }
+
+                let rename_column = |columns: &mut Vec<String>| {
+                    for column in columns.iter_mut().filter(|c| c.as_str() == old_name) {
+                        *column = new_name.clone();
+                    }
+                };
+
+                self.indexes_mut()
+                    .iter_mut()
+                    .filter(|i| i.schema == schema && i.table_name == name)
+                    .for_each(|i| rename_column(&mut i.columns));
+                self.constraints_mut()
+                    .iter_mut()
+                    .filter(|c| c.schema == schema && c.table_name == name)
+                    .for_each(|c| rename_column(&mut c.columns));
+                self.constraints_mut()
+                    .iter_mut()
+                    .filter(|c| {
+                        c.referenced_schema.as_deref() == Some(schema.as_str())
+                            && c.referenced_table.as_deref() == Some(name.as_str())
+                    })
+                    .for_each(|c| rename_column(&mut c.referenced_columns));
+                for dependency in self
+                    .views_mut()
+                    .iter_mut()
+                    .flat_map(|v| v.column_dependencies.columns.iter_mut())
+                    .filter(|d| {
+                        d.schema == schema && d.table_name == name && d.column_name == old_name
+                    })
+                {
+                    dependency.column_name = new_name.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn drop(&mut self, stmt: &protobuf::DropStmt) {
+        let remove_type = stmt.remove_type();
+
+        for object in &stmt.objects {
+            let Some(object) = &object.node else {
+                continue;
+            };
+
+            if let NodeEnum::ObjectWithArgs(function) = object {
+                if matches!(
+                    remove_type,
+                    ObjectType::ObjectFunction
+                        | ObjectType::ObjectProcedure
+                        | ObjectType::ObjectRoutine
+                ) {
+                    self.drop_function(function);
+                }
+                continue;
+            }
+
+            let names = match object {
+                NodeEnum::List(list) => string_values(&list.items),
+                NodeEnum::TypeName(type_name) => string_values(&type_name.names),
+                NodeEnum::String(s) => vec![s.sval.clone()],
+                _ => continue,
+            };
+            let Some((schema, name)) = self.object_name(names, |schema, name| match remove_type {
+                ObjectType::ObjectIndex => self
+                    .indexes()
+                    .iter()
+                    .any(|i| i.schema == schema && i.name == name),
+                ObjectType::ObjectSequence => self
+                    .sequences()
+                    .iter()
+                    .any(|s| s.schema == schema && s.name == name),
+                ObjectType::ObjectType | ObjectType::ObjectDomain => self
+                    .types()
+                    .iter()
+                    .any(|t| t.schema == schema && t.name == name),
+                _ => self.relation_exists(schema, name),
+            }) else {
+                continue;
+            };
+
+            match remove_type {
+                ObjectType::ObjectTable
+                | ObjectType::ObjectForeignTable
+                | ObjectType::ObjectView
+                | ObjectType::ObjectMatview => self.drop_relation(&schema, &name),
+                ObjectType::ObjectIndex => {
+                    self.indexes_mut()
+                        .retain(|i| !(i.schema == schema && i.name == name));
+                }
+                ObjectType::ObjectSequence => {
+                    self.sequences_mut()
+                        .retain(|s| !(s.schema == schema && s.name == name));
+                }
+                ObjectType::ObjectType | ObjectType::ObjectDomain => {
+                    self.types_mut()
+                        .retain(|t| !(t.schema == schema && t.name == name));
+                }
+                ObjectType::ObjectSchema => self.drop_schema(&name),
+                _ => {}
+            }
+        }
+    }
+
+    fn drop_relation(&mut self, schema: &str, name: &str) {
+        let mut ids: Vec<i64> = self
+            .tables()
+            .iter()
+            .filter(|t| t.schema == schema && t.name == name)
+            .map(|t| t.id)
+            .chain(
+                self.views()
+                    .iter()
+                    .filter(|v| v.schema == schema && v.name == name)
+                    .map(|v| v.id),
+            )
+            .collect();
+
+        // partitions are dropped along with the partitioned table
+        let mut idx = 0;
+        while idx < ids.len() {
+            let partitions: Vec<i64> = self
+                .tables()
+                .iter()
+                .filter(|t| t.is_partition && t.parent_ids.contains(&ids[idx]))
+                .map(|t| t.id)
+                .collect();
+            ids.extend(partitions);
+            idx += 1;
+        }
+
+        self.tables_mut().retain(|t| !ids.contains(&t.id));
+        for table in self.tables_mut() {
+            table.parent_ids.retain(|id| !ids.contains(id));
+        }
+        self.views_mut().retain(|v| !ids.contains(&v.id));
+        self.columns_mut().retain(|c| !ids.contains(&c.table_oid));
+        self.indexes_mut().retain(|i| !ids.contains(&i.table_id));
+        self.constraints_mut()
+            .retain(|c| !ids.contains(&c.table_id));
+        self.policies_mut().retain(|p| !ids.contains(&p.table_id));
+        self.triggers_mut().retain(|t| !ids.contains(&t.table_id));
+    }
+
+    fn drop_schema(&mut self, name: &str) {
+        self.schemas_mut().retain(|s| s.name != name);
+        self.tables_mut().retain(|t| t.schema != name);
+        self.views_mut().retain(|v| v.schema != name);
+        self.columns_mut().retain(|c| c.schema_name != name);
+        self.indexes_mut().retain(|i| i.schema != name);
+        self.constraints_mut().retain(|c| c.schema != name);
+        self.policies_mut().retain(|p| p.schema != name);
+        self.triggers_mut().retain(|t| t.schema != name);
+        self.functions_mut().retain(|f| f.schema != name);
+        self.types_mut().retain(|t| t.schema != name);
+        self.sequences_mut().retain(|s| s.schema != name);
+    }
+
+    fn drop_function(&mut self, function: &protobuf::ObjectWithArgs) {
+        let Some((schema, name)) =
+            self.object_name(string_values(&function.objname), |schema, name| {
+                self.functions()
+                    .iter()
+                    .any(|f| f.schema == schema && f.name == name)
+            })
+        else {
+            return;
+        };
+
+        // `drop function my_func` is only valid if there is a single function with that name
+        let arg_type_ids: Option<Vec<i64>> = (!function.args_unspecified).then(|| {
+            function
+                .objargs
+                .iter()
+                .filter_map(|arg| match &arg.node {
+                    Some(NodeEnum::TypeName(type_name)) => Some(self.resolve_type_id(type_name)),
+                    _ => None,
+                })
+                .collect()
+        });
+
+        self.functions_mut().retain(|f| {
+            !(f.schema == schema
+                && f.name == name
+                && arg_type_ids
+                    .as_ref()
+                    .is_none_or(|ids| *ids == input_arg_type_ids(&f.args)))
+        });
+    }
+
+    fn create_function(&mut self, stmt: &protobuf::CreateFunctionStmt) {
+        let Some((schema, name)) = self.new_object_name(string_values(&stmt.funcname)) else {
+            return;
+        };
+
+        let args: Vec<FunctionArg> = stmt
+            .parameters
+            .iter()
+            .filter_map(|param| match &param.node {
+                Some(NodeEnum::FunctionParameter(param)) => Some(FunctionArg {
+                    mode: match param.mode() {
+                        FunctionParameterMode::FuncParamOut => "out",
+                        FunctionParameterMode::FuncParamInout => "inout",
+                        FunctionParameterMode::FuncParamVariadic => "variadic",
+                        FunctionParameterMode::FuncParamTable => "table",
+                        _ => "in",
+                    }
+                    .to_string(),
+                    name: param.name.clone(),
+                    type_id: param
+                        .arg_type
+                        .as_ref()
+                        .map(|t| self.resolve_type_id(t))
+                        .unwrap_or_default(),
+                    has_default: Some(param.defexpr.is_some()),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let return_type = stmt
+            .return_type
+            .as_ref()
+            .and_then(|type_name| self.resolve_type(type_name));
+
+        let mut function = Function {
+            id: self.next_id(),
+            schema,
+            name,
+            args: FunctionArgs { args },
+            return_type_id: return_type.map(|t| t.id).unwrap_or_default(),
+            return_type: return_type
+                .map(|t| t.format.clone())
+                .unwrap_or_else(|| "void".to_string()),
+            is_set_returning_function: stmt.return_type.as_ref().is_some_and(|t| t.setof),
+            definition: NodeEnum::CreateFunctionStmt(Box::new(stmt.clone()))
+                .deparse()
+                .ok(),
+            ..Default::default()
+        };
+
+        for option in &stmt.options {
+            let Some(NodeEnum::DefElem(option)) = &option.node else {
+                continue;
+            };
+            let arg = option.arg.as_ref().and_then(|arg| arg.node.as_ref());
+
+            match (option.defname.as_str(), arg) {
+                ("language", Some(NodeEnum::String(language))) => {
+                    function.language = language.sval.clone();
+                }
+                ("as", Some(NodeEnum::List(body))) => {
+                    function.body = string_values(&body.items).into_iter().next();
+                }
+                ("volatility", Some(NodeEnum::String(volatility))) => {
+                    function.behavior = Behavior::from(Some(volatility.sval.to_uppercase()));
+                }
+                ("security", Some(NodeEnum::Boolean(security_definer))) => {
+                    function.security_definer = security_definer.boolval;
+                }
+                _ => {}
+            }
+        }
+
+        // functions are identified by their name and input argument types, so a function with the
+        // same signature is replaced
+        let arg_type_ids = input_arg_type_ids(&function.args);
+        match self.functions_mut().iter_mut().find(|f| {
+            f.schema == function.schema
+                && f.name == function.name
+                && input_arg_type_ids(&f.args) == arg_type_ids
+        }) {
+            Some(existing) => {
+                function.id = existing.id;
+                *existing = function;
+            }
+            None => self.functions_mut().push(function),
+        }
+    }
+
+    fn create_enum(&mut self, stmt: &protobuf::CreateEnumStmt) {
+        let Some((schema, name)) = self.new_object_name(string_values(&stmt.type_name)) else {
+            return;
+        };
+
+        let values = string_values(&stmt.vals);
+        self.create_type(PostgresType {
+            schema,
+            name,
+            kind: TypeKind::Enum,
+            enums: Enums { values },
+            ..Default::default()
+        });
+    }
+
+    fn create_composite_type(&mut self, stmt: &protobuf::CompositeTypeStmt) {
+        let Some(typevar) = &stmt.typevar else {
+            return;
+        };
+        let (schema, name) = self.new_relation_name(typevar);
+
+        let attrs = stmt
+            .coldeflist
+            .iter()
+            .filter_map(|def| match &def.node {
+                Some(NodeEnum::ColumnDef(def)) => Some(PostgresTypeAttribute {
+                    name: def.colname.clone(),
+                    type_id: def
+                        .type_name
+                        .as_ref()
+                        .map(|t| self.resolve_type_id(t))
+                        .unwrap_or_default(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        self.create_type(PostgresType {
+            schema,
+            name,
+            kind: TypeKind::Composite,
+            attributes: TypeAttributes { attrs },
+            ..Default::default()
+        });
+    }
+
+    fn create_domain(&mut self, stmt: &protobuf::CreateDomainStmt) {
+        let Some((schema, name)) = self.new_object_name(string_values(&stmt.domainname)) else {
+            return;
+        };
+
+        let base_type_id = stmt
+            .type_name
+            .as_ref()
+            .and_then(|t| self.resolve_type(t))
+            .map(|t| t.id);
+
+        let mut not_null = false;
+        let mut constraints = Vec::new();
+        for constraint in &stmt.constraints {
+            let Some(NodeEnum::Constraint(constraint)) = &constraint.node else {
+                continue;
+            };
+
+            match constraint.contype() {
+                ConstrType::ConstrNotnull => not_null = true,
+                ConstrType::ConstrNull => not_null = false,
+                ConstrType::ConstrCheck => {
+                    let Some(expr) = constraint.raw_expr.as_deref().and_then(deparse_expr) else {
+                        continue;
+                    };
+
+                    // Postgres names unnamed domain constraints after the domain
+                    let name = if constraint.conname.is_empty() {
+                        format!("{}_check", name)
+                    } else {
+                        constraint.conname.clone()
+                    };
+
+                    constraints.push(DomainConstraint {
+                        name,
+                        definition: format!("CHECK ({})", expr),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        self.create_type(PostgresType {
+            schema,
+            name,
+            kind: TypeKind::Domain,
+            base_type_id,
+            not_null,
+            domain_constraints: DomainConstraints { constraints },
+            ..Default::default()
+        });
+    }
+
+    fn create_range(&mut self, stmt: &protobuf::CreateRangeStmt) {
+        let Some((schema, name)) = self.new_object_name(string_values(&stmt.type_name)) else {
+            return;
+        };
+
+        let range_subtype_id = stmt.params.iter().find_map(|param| match &param.node {
+            Some(NodeEnum::DefElem(param)) if param.defname == "subtype" => {
+                match param.arg.as_ref().and_then(|a| a.node.as_ref()) {
+                    Some(NodeEnum::TypeName(type_name)) => {
+                        self.resolve_type(type_name).map(|t| t.id)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+
+        // every range type comes with a multirange type, e.g. `int4multirange` for `int4range`
+        let multirange_name = match name.find("range") {
+            Some(idx) => format!("{}multi{}", &name[..idx], &name[idx..]),
+            None => format!("{}_multirange", name),
+        };
+
+        self.create_type(PostgresType {
+            schema: schema.clone(),
+            name,
+            kind: TypeKind::Range,
+            range_subtype_id,
+            ..Default::default()
+        });
+        self.create_type(PostgresType {
+            schema,
+            name: multirange_name,
+            kind: TypeKind::Multirange,
+            range_subtype_id,
+            ..Default::default()
+        });
+    }
+
+    /// Adds the type with a new id, unless a type with the same name exists already.
+    fn create_type(&mut self, postgres_type: PostgresType) {
+        if self
+            .types()
+            .iter()
+            .any(|t| t.schema == postgres_type.schema && t.name == postgres_type.name)
+        {
+            return;
+        }
+
+        let id = self.next_id();
+        self.types_mut().push(PostgresType {
+            id,
+            format: postgres_type.name.clone(),
+            ..postgres_type
+        });
+    }
+
+    fn column_from_def(
+        &self,
+        table: &Table,
+        class_kind: ColumnClassKind,
+        def: &protobuf::ColumnDef,
+    ) -> Column {
+        let serial_type = def.type_name.as_ref().and_then(serial_type);
+
+        let type_id = match (serial_type, &def.type_name) {
+            (Some(serial_type), _) => self
+                .types()
+                .iter()
+                .find(|t| t.schema == "pg_catalog" && t.name == serial_type)
+                .map(|t| t.id)
+                .unwrap_or_default(),
+            (None, Some(type_name)) => self.resolve_type_id(type_name),
+            (None, None) => 0,
+        };
+
+        let mut column = Column {
+            name: def.colname.clone(),
+            table_name: table.name.clone(),
+            table_oid: table.id,
+            class_kind,
+            schema_name: table.schema.clone(),
+            type_id,
+            is_nullable: !def.is_not_null,
+            is_primary_key: false,
+            is_unique: false,
+            generation: ColumnGeneration::None,
+            default_expr: def.raw_default.as_deref().and_then(deparse_expr),
+            varchar_length: def.type_name.as_ref().and_then(varchar_length),
+            comment: None,
+        };
+
+        if serial_type.is_some() {
+            let sequence_name = format!("{}_{}_seq", table.name, def.colname);
+            // `regclass` only qualifies the name if the schema is not in the search path
+            let sequence_name = if self
+                .schemas_to_search(None)
+                .contains(&table.schema.as_str())
+            {
+                sequence_name
+            } else {
+                format!("{}.{}", table.schema, sequence_name)
+            };
+
+            column.is_nullable = false;
+            column.default_expr = Some(format!("nextval('{}'::regclass)", sequence_name));
+        }
+
+        for constraint in &def.constraints {
+            let Some(NodeEnum::Constraint(constraint)) = &constraint.node else {
+                continue;
+            };
+
+            match constraint.contype() {
+                ConstrType::ConstrNull => column.is_nullable = true,
+                ConstrType::ConstrNotnull => column.is_nullable = false,
+                ConstrType::ConstrDefault => {
+                    column.default_expr = constraint.raw_expr.as_deref().and_then(deparse_expr);
+                }
+                ConstrType::ConstrIdentity => {
+                    column.is_nullable = false;
+                    column.generation = if constraint.generated_when == "a" {
+                        ColumnGeneration::IdentityAlways
+                    } else {
+                        ColumnGeneration::IdentityByDefault
+                    };
+                }
+                ConstrType::ConstrGenerated => {
+                    column.generation = ColumnGeneration::Stored;
+                    column.default_expr = constraint.raw_expr.as_deref().and_then(deparse_expr);
+                }
+                ConstrType::ConstrPrimary => {
+                    column.is_nullable = false;
+                    column.is_primary_key = true;
+                    column.is_unique = true;
+                }
+                ConstrType::ConstrUnique => column.is_unique = true,
+                _ => {}
+            }
+        }
+
+        column
+    }
+
+    fn apply_table_constraint(
+        &mut self,
+        schema: &str,
+        table_name: &str,
+        constraint: &protobuf::Constraint,
+    ) {
+        let contype = constraint.contype();
+        if !matches!(
+            contype,
+            ConstrType::ConstrPrimary | ConstrType::ConstrUnique
+        ) {
+            return;
+        }
+
+        for key in string_values(&constraint.keys) {
+            if let Some(column) = self.find_column_mut(schema, table_name, &key) {
+                column.is_unique = true;
+                if contype == ConstrType::ConstrPrimary {
+                    column.is_primary_key = true;
+                    column.is_nullable = false;
+                }
+            }
+        }
+    }
+
+    fn find_column_mut(&mut self, schema: &str, table: &str, name: &str) -> Option<&mut Column> {
+        self.columns_mut()
+            .iter_mut()
+            .find(|c| c.schema_name == schema && c.table_name == table && c.name == name)
+    }
+
+    fn find_relation_id(&self, relation: &protobuf::RangeVar) -> Option<i64> {
+        let (schema, name) = self.relation_name(relation);
+        self.tables()
+            .iter()
+            .find(|t| t.schema == schema && t.name == name)
+            .map(|t| t.id)
+    }
+
+    fn find_table_mut(&mut self, relation: &protobuf::RangeVar) -> Option<&mut Table> {
+        let (schema, name) = self.relation_name(relation);
+        self.tables_mut()
+            .iter_mut()
+            .find(|t| t.schema == schema && t.name == name)
+    }
+
+    fn table_exists(&self, schema: &str, name: &str) -> bool {
+        self.tables()
+            .iter()
+            .any(|t| t.schema == schema && t.name == name)
+    }
+
+    fn resolve_type(&self, type_name: &protobuf::TypeName) -> Option<&PostgresType> {
+        let mut names = string_values(&type_name.names);
+        let name = names.pop()?;
+        let schema = names.pop();
+
+        // array types are prefixed with an underscore in `pg_type`
+        let name = if type_name.array_bounds.is_empty() {
+            name
+        } else {
+            format!("_{}", name)
+        };
+
+        self.find_type(&name, schema.as_deref())
+    }
+
+    fn resolve_type_id(&self, type_name: &protobuf::TypeName) -> i64 {
+        self.resolve_type(type_name)
+            .map(|t| t.id)
+            .unwrap_or_default()
+    }
+
+    /// The schema objects are created in if a statement does not qualify their name, which is the
+    /// first schema of the search path that is not searched implicitly.
+    fn creation_schema(&self) -> String {
+        self.schemas_to_search(None)
+            .into_iter()
+            .find(|schema| *schema != "pg_catalog" && !schema.starts_with("pg_temp"))
+            .unwrap_or("public")
+            .to_string()
+    }
+
+    /// Splits the possibly schema-qualified name of an existing object into schema and name.
+    /// Unqualified names are resolved via the search path to the first schema `exists` returns
+    /// `true` for.
+    fn object_name(
+        &self,
+        mut names: Vec<String>,
+        exists: impl Fn(&str, &str) -> bool,
+    ) -> Option<(String, String)> {
+        let name = names.pop()?;
+        let schema = names.pop().unwrap_or_else(|| {
+            self.schemas_to_search(None)
+                .into_iter()
+                .find(|schema| exists(schema, &name))
+                .map(str::to_string)
+                .unwrap_or_else(|| self.creation_schema())
+        });
+        Some((schema, name))
+    }
+
+    /// Splits the possibly schema-qualified name of an object that is created into schema and
+    /// name.
+    fn new_object_name(&self, mut names: Vec<String>) -> Option<(String, String)> {
+        let name = names.pop()?;
+        let schema = names.pop().unwrap_or_else(|| self.creation_schema());
+        Some((schema, name))
+    }
+
+    /// The schema and name of an existing table or view.
+    fn relation_name(&self, relation: &protobuf::RangeVar) -> (String, String) {
+        self.object_name(range_var_names(relation), |schema, name| {
+            self.relation_exists(schema, name)
+        })
+        .unwrap_or_default()
+    }
+
+    /// The schema and name of a table or type that is created.
+    fn new_relation_name(&self, relation: &protobuf::RangeVar) -> (String, String) {
+        self.new_object_name(range_var_names(relation))
+            .unwrap_or_default()
+    }
+
+    fn relation_exists(&self, schema: &str, name: &str) -> bool {
+        self.table_exists(schema, name)
+            || self
+                .views()
+                .iter()
+                .any(|v| v.schema == schema && v.name == name)
+    }
+
+    /// Objects created via [SchemaCache::mutate] get decreasing negative ids, so they never clash
+    /// with an `oid` loaded from the database.
+    fn next_id(&self) -> i64 {
+        let min_id = self
+            .schemas()
+            .iter()
+            .map(|s| s.id)
+            .chain(self.tables().iter().map(|t| t.id))
+            .chain(self.functions().iter().map(|f| f.id))
+            .chain(self.types().iter().map(|t| t.id))
+            .min()
+            .unwrap_or_default();
+
+        min_id.min(0) - 1
+    }
+}
+
+fn string_values(nodes: &[protobuf::Node]) -> Vec<String> {
+    nodes
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(NodeEnum::String(s)) => Some(s.sval.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn range_var_names(relation: &protobuf::RangeVar) -> Vec<String> {
+    [&relation.schemaname, &relation.relname]
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .cloned()
+        .collect()
+}
+
+fn input_arg_type_ids(args: &FunctionArgs) -> Vec<i64> {
+    args.args
+        .iter()
+        .filter(|arg| matches!(arg.mode.as_str(), "in" | "inout" | "variadic"))
+        .map(|arg| arg.type_id)
+        .collect()
+}
+
+/// `serial` types are no real types, but a shorthand for an integer column with a sequence.
+fn serial_type(type_name: &protobuf::TypeName) -> Option<&'static str> {
+    if type_name.names.len() != 1 || !type_name.array_bounds.is_empty() {
+        return None;
+    }
+
+    match string_values(&type_name.names).first()?.as_str() {
+        "smallserial" | "serial2" => Some("int2"),
+        "serial" | "serial4" => Some("int4"),
+        "bigserial" | "serial8" => Some("int8"),
+        _ => None,
+    }
+}
+
+fn varchar_length(type_name: &protobuf::TypeName) -> Option<i32> {
+    let name = string_values(&type_name.names).pop()?;
+    if !matches!(name.as_str(), "varchar" | "bpchar") || !type_name.array_bounds.is_empty() {
+        return None;
+    }
+
+    match type_name.typmods.first().and_then(|n| n.node.as_ref()) {
+        Some(NodeEnum::AConst(protobuf::AConst {
+            val: Some(protobuf::a_const::Val::Ival(length)),
+            ..
+        })) => Some(length.ival),
+        _ => None,
+    }
+}
+
+/// Renders an expression as SQL by deparsing it as the target of a `SELECT`.
+fn deparse_expr(expr: &protobuf::Node) -> Option<String> {
+    let select = NodeEnum::SelectStmt(Box::new(protobuf::SelectStmt {
+        target_list: vec![protobuf::Node {
+            node: Some(NodeEnum::ResTarget(Box::new(protobuf::ResTarget {
+                val: Some(Box::new(expr.clone())),
+                ..Default::default()
+            }))),
+        }],
+        limit_option: protobuf::LimitOption::Default.into(),
+        op: protobuf::SetOperation::SetopNone.into(),
+        ..Default::default()
+    }));
+
+    select
+        .deparse()
+        .ok()?
+        .strip_prefix("SELECT ")
+        .map(|expr| expr.to_string())
+}
+
+/// Renders a clause of a `create table` statement, e.g. `PARTITION BY RANGE (created_at)`, by
+/// deparsing the statement and returning everything after `keyword`.
+fn deparse_create_clause(stmt: protobuf::CreateStmt, keyword: &str) -> Option<String> {
+    let stmt = protobuf::CreateStmt {
+        oncommit: protobuf::OnCommitAction::OncommitNoop.into(),
+        ..stmt
+    };
+
+    let sql = NodeEnum::CreateStmt(stmt).deparse().ok()?;
+    let start = sql.find(keyword)?;
+    Some(sql[start + keyword.len()..].to_string())
+}
+
+/// Renders a partition key the way `pg_get_partkeydef` does, e.g. `RANGE (created_at)`.
+fn deparse_partition_key(
+    relation: &protobuf::RangeVar,
+    partspec: &protobuf::PartitionSpec,
+) -> Option<String> {
+    let stmt = protobuf::CreateStmt {
+        relation: Some(relation.clone()),
+        partspec: Some(partspec.clone()),
+        ..Default::default()
+    };
+
+    let key = deparse_create_clause(stmt, "PARTITION BY ")?;
+    let (strategy, columns) = key.split_once('(')?;
+    Some(format!("{} ({}", strategy.trim_end(), columns))
+}
+
+/// Renders a partition bound the way `pg_get_expr` does, e.g. `FOR VALUES IN (1, 2)` or `DEFAULT`.
+fn deparse_partition_bound(
+    partition: &protobuf::RangeVar,
+    bound: &protobuf::PartitionBoundSpec,
+) -> Option<String> {
+    let stmt = protobuf::CreateStmt {
+        relation: Some(partition.clone()),
+        inh_relations: vec![protobuf::Node {
+            node: Some(NodeEnum::RangeVar(partition.clone())),
+        }],
+        partbound: Some(bound.clone()),
+        ..Default::default()
+    };
+
+    if bound.is_default {
+        return Some("DEFAULT".to_string());
+    }
+
+    deparse_create_clause(stmt, " FOR VALUES ").map(|bound| format!("FOR VALUES {}", bound))
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{SchemaCache, columns::ColumnGeneration};
+
+    async fn mutated_cache(sql: &str) -> SchemaCache {
+        let test_db = get_new_test_db().await;
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        for stmt in sql.split(';').filter(|s| !s.trim().is_empty()) {
+            let ast = pgt_query_ext::parse(stmt).expect("Failed to parse statement");
+            cache.mutate(&ast);
+        }
+
+        cache
+    }
+
+    #[tokio::test]
+    async fn creates_tables_and_columns() {
+        let cache = mutated_cache(
+            r#"
+            create schema app;
+
+            create table app.users (
+                id serial primary key,
+                name varchar(255) not null,
+                tags text[],
+                created_at timestamptz default now()
+            );
+
+            create table public.posts (
+                id bigint generated always as identity,
+                user_id int not null,
+                slug text,
+                primary key (id),
+                unique (user_id, slug)
+            );
+            "#,
+        )
+        .await;
+
+        let table = cache
+            .tables()
+            .iter()
+            .find(|t| t.name == "users" && t.schema == "app")
+            .unwrap();
+        assert!(table.id < 0);
+        assert!(cache.schemas().iter().any(|s| s.name == "app"));
+
+        let find_type_id = |name: &str| {
+            cache
+                .types()
+                .iter()
+                .find(|t| t.schema == "pg_catalog" && t.name == name)
+                .unwrap()
+                .id
+        };
+
+        let id = cache.find_col("id", "users", Some("app")).unwrap();
+        assert_eq!(id.table_oid, table.id);
+        assert_eq!(id.type_id, find_type_id("int4"));
+        assert!(id.is_primary_key);
+        assert!(!id.is_nullable);
+        assert_eq!(
+            id.default_expr,
+            Some("nextval('app.users_id_seq'::regclass)".into())
+        );
+
+        let name = cache.find_col("name", "users", Some("app")).unwrap();
+        assert_eq!(name.type_id, find_type_id("varchar"));
+        assert_eq!(name.varchar_length, Some(255));
+        assert!(!name.is_nullable);
+
+        let tags = cache.find_col("tags", "users", Some("app")).unwrap();
+        assert_eq!(tags.type_id, find_type_id("_text"));
+        assert!(tags.is_nullable);
+
+        let created_at = cache.find_col("created_at", "users", Some("app")).unwrap();
+        assert_eq!(created_at.default_expr, Some("now()".into()));
+
+        let post_id = cache.find_col("id", "posts", Some("public")).unwrap();
+        assert_eq!(post_id.generation, ColumnGeneration::IdentityAlways);
+        assert!(post_id.is_primary_key);
+
+        let slug = cache.find_col("slug", "posts", Some("public")).unwrap();
+        assert!(slug.is_unique);
+        assert!(!slug.is_primary_key);
+    }
+
+    #[tokio::test]
+    async fn alters_renames_and_drops_tables() {
+        let cache = mutated_cache(
+            r#"
+            create table public.users (
+                id int,
+                name text,
+                email text
+            );
+
+            create table public.audit_log (id int);
+
+            alter table public.users
+                add column is_admin bool not null default false,
+                drop column email,
+                alter column id set not null,
+                alter column name type varchar(100);
+
+            alter table users rename column name to full_name;
+
+            alter table users rename to accounts;
+
+            drop table audit_log;
+            "#,
+        )
+        .await;
+
+        assert!(cache.tables().iter().any(|t| t.name == "accounts"));
+        assert!(!cache.tables().iter().any(|t| t.name == "users"));
+        assert!(!cache.tables().iter().any(|t| t.name == "audit_log"));
+        assert!(cache.find_col("id", "audit_log", None).is_none());
+
+        let mut columns: Vec<_> = cache
+            .columns()
+            .iter()
+            .filter(|c| c.table_name == "accounts")
+            .map(|c| c.name.as_str())
+            .collect();
+        columns.sort();
+        assert_eq!(columns, vec!["full_name", "id", "is_admin"]);
+
+        let is_admin = cache.find_col("is_admin", "accounts", None).unwrap();
+        assert!(!is_admin.is_nullable);
+        assert_eq!(is_admin.default_expr, Some("false".into()));
+
+        assert!(!cache.find_col("id", "accounts", None).unwrap().is_nullable);
+        assert_eq!(
+            cache
+                .find_col("full_name", "accounts", None)
+                .unwrap()
+                .varchar_length,
+            Some(100)
+        );
+    }
+
+    #[tokio::test]
+    async fn creates_objects_in_first_schema_of_search_path() {
+        let test_db = get_new_test_db().await;
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+        *cache.search_path_mut() = vec!["pg_catalog".into(), "app".into(), "public".into()];
+
+        for stmt in [
+            "create schema app",
+            "create table users (id serial)",
+            "alter table users add column name text",
+            "create function greet(name text) returns text language sql as $$ select name $$",
+        ] {
+            cache.mutate(&pgt_query_ext::parse(stmt).unwrap());
+        }
+
+        let users = cache.find_table("users", None).unwrap();
+        assert_eq!(users.schema, "app");
+        assert_eq!(
+            cache.find_col("name", "users", None).unwrap().schema_name,
+            "app"
+        );
+        assert_eq!(
+            cache.find_col("id", "users", None).unwrap().default_expr,
+            Some("nextval('users_id_seq'::regclass)".into())
+        );
+        assert!(
+            cache
+                .functions()
+                .iter()
+                .any(|f| f.schema == "app" && f.name == "greet")
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_column_types_via_search_path() {
+        let test_db = get_new_test_db().await;
+
+        test_db
+            .execute(
+                "create schema audit;
+                create type audit.status as enum ('logged');
+                create type public.status as enum ('active', 'inactive');",
+            )
+            .await
+            .expect("Failed to setup test database");
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+        *cache.search_path_mut() = vec!["pg_catalog".into(), "public".into(), "audit".into()];
+
+        cache.mutate(&pgt_query_ext::parse("create table users (s status)").unwrap());
+
+        let status = cache.find_type("status", Some("public")).unwrap().id;
+        assert_eq!(cache.find_col("s", "users", None).unwrap().type_id, status);
+    }
+
+    #[tokio::test]
+    async fn renames_references_to_tables_and_columns() {
+        let test_db = get_new_test_db().await;
+
+        test_db
+            .execute(
+                r#"
+                create table public.users (id int primary key);
+                create table public.orders (user_id int references public.users (id));
+                create view public.user_ids as select id from public.users;
+                "#,
+            )
+            .await
+            .expect("Failed to setup test database");
+
+        let mut cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        for stmt in [
+            "alter table users rename column id to user_id",
+            "alter table users rename to accounts",
+        ] {
+            cache.mutate(&pgt_query_ext::parse(stmt).unwrap());
+        }
+
+        let foreign_key = cache
+            .constraints()
+            .iter()
+            .find(|c| c.table_name == "orders" && c.referenced_table.is_some())
+            .unwrap();
+        assert_eq!(foreign_key.referenced_table.as_deref(), Some("accounts"));
+        assert_eq!(foreign_key.referenced_columns, vec!["user_id"]);
+
+        let view = cache.views().iter().find(|v| v.name == "user_ids").unwrap();
+        let dependencies: Vec<_> = view
+            .column_dependencies
+            .columns
+            .iter()
+            .map(|d| (d.table_name.as_str(), d.column_name.as_str()))
+            .collect();
+        assert_eq!(dependencies, vec![("accounts", "user_id")]);
+    }
+
+    #[tokio::test]
+    async fn tracks_partitions_and_inheritance() {
+        let cache = mutated_cache(
+            r#"
+            create table public.events (id int primary key, created_at date)
+                partition by range (created_at);
+
+            create table public.events_2024 partition of public.events
+                for values from ('2024-01-01') to ('2025-01-01');
+
+            create table public.events_2025 (id int primary key, created_at date);
+
+            alter table public.events attach partition public.events_2025
+                for values from ('2025-01-01') to ('2026-01-01');
+
+            create table public.events_default partition of public.events default;
+
+            alter table public.events detach partition public.events_default;
+
+            create table public.cities (name text);
+
+            create table public.capitals (country text) inherits (public.cities);
+
+            create table public.old_events (id int, created_at date)
+                partition by list (id);
+
+            create table public.old_events_1 partition of public.old_events for values in (1);
+
+            drop table public.old_events;
+            "#,
+        )
+        .await;
+
+        let events = cache.find_table("events", None).unwrap();
+        assert!(events.is_partitioned);
+        assert_eq!(events.partition_key.as_deref(), Some("RANGE (created_at)"));
+
+        let events_2024 = cache.find_table("events_2024", None).unwrap();
+        assert!(events_2024.is_partition);
+        assert_eq!(events_2024.parent_ids, vec![events.id]);
+        assert_eq!(
+            events_2024.partition_bound.as_deref(),
+            Some("FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')")
+        );
+        assert!(
+            cache
+                .find_col("id", "events_2024", None)
+                .unwrap()
+                .is_primary_key
+        );
+
+        let events_2025 = cache.find_table("events_2025", None).unwrap();
+        assert_eq!(events_2025.parent_ids, vec![events.id]);
+        assert_eq!(
+            events_2025.partition_bound.as_deref(),
+            Some("FOR VALUES FROM ('2025-01-01') TO ('2026-01-01')")
+        );
+
+        let events_default = cache.find_table("events_default", None).unwrap();
+        assert!(!events_default.is_partition);
+        assert!(events_default.parent_ids.is_empty());
+
+        let capitals = cache.find_table("capitals", None).unwrap();
+        assert_eq!(
+            capitals.parent_ids,
+            vec![cache.find_table("cities", None).unwrap().id]
+        );
+        assert!(cache.find_col("name", "capitals", None).is_some());
+        assert!(cache.find_col("country", "capitals", None).is_some());
+
+        assert!(cache.find_table("old_events_1", None).is_none());
+    }
+
+    #[tokio::test]
+    async fn creates_and_drops_functions_and_types() {
+        let cache = mutated_cache(
+            r#"
+            create type public.mood as enum ('happy', 'sad');
+
+            create type public.address as (street text, city text);
+
+            create type public.unused as enum ('a');
+
+            drop type public.unused;
+
+            create domain public.positive as int not null check (value > 0);
+
+            create type public.floatrange as range (subtype = float8);
+
+            create function public.add(a int, b int default 1)
+            returns int
+            language sql
+            immutable
+            as $$ select a + b $$;
+
+            create function public.add(a text, b text)
+            returns text
+            language sql
+            as $$ select a || b $$;
+
+            create or replace function public.add(a int, b int default 2)
+            returns int
+            language sql
+            stable
+            as $$ select a + b $$;
+
+            drop function public.add(text, text);
+            "#,
+        )
+        .await;
+
+        let mood = cache.types().iter().find(|t| t.name == "mood").unwrap();
+        assert_eq!(mood.enums.values, vec!["happy", "sad"]);
+
+        let address = cache.types().iter().find(|t| t.name == "address").unwrap();
+        assert_eq!(
+            address
+                .attributes
+                .attrs
+                .iter()
+                .map(|a| a.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["street", "city"]
+        );
+
+        assert!(!cache.types().iter().any(|t| t.name == "unused"));
+
+        let positive = cache.types().iter().find(|t| t.name == "positive").unwrap();
+        assert_eq!(positive.kind, crate::TypeKind::Domain);
+        assert_eq!(
+            positive.base_type_id,
+            cache.find_type("int4", None).map(|t| t.id)
+        );
+        assert!(positive.not_null);
+        assert_eq!(
+            positive.domain_constraints.constraints[0].definition,
+            "CHECK (value > 0)"
+        );
+
+        let floatrange = cache
+            .types()
+            .iter()
+            .find(|t| t.name == "floatrange")
+            .unwrap();
+        assert_eq!(floatrange.kind, crate::TypeKind::Range);
+        assert_eq!(
+            floatrange.range_subtype_id,
+            cache.find_type("float8", None).map(|t| t.id)
+        );
+        assert!(
+            cache
+                .types()
+                .iter()
+                .any(|t| t.name == "floatmultirange" && t.kind == crate::TypeKind::Multirange)
+        );
+
+        let add_functions: Vec<_> = cache
+            .functions()
+            .iter()
+            .filter(|f| f.name == "add")
+            .collect();
+        assert_eq!(add_functions.len(), 1);
+
+        let add = add_functions[0];
+        assert_eq!(add.language, "sql");
+        assert_eq!(add.return_type, "integer");
+        assert_eq!(add.behavior, crate::Behavior::Stable);
+        assert_eq!(add.body, Some(" select a + b ".into()));
+        assert_eq!(
+            add.args
+                .args
+                .iter()
+                .map(|a| (a.name.as_str(), a.has_default))
+                .collect::<Vec<_>>(),
+            vec![("a", Some(false)), ("b", Some(true))]
+        );
+    }
+}
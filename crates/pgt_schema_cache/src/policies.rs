@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// The command a policy applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PolicyCommand {
+    #[default]
+    All,
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+impl From<String> for PolicyCommand {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "ALL" => PolicyCommand::All,
+            "SELECT" => PolicyCommand::Select,
+            "INSERT" => PolicyCommand::Insert,
+            "UPDATE" => PolicyCommand::Update,
+            "DELETE" => PolicyCommand::Delete,
+            _ => panic!("Invalid policy command"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Policy {
+    /// The Id (`oid`) of the policy.
+    pub id: i64,
+
+    /// The name of the schema the table of the policy belongs to.
+    pub schema: String,
+
+    /// The Id (`oid`) of the table the policy is defined on.
+    pub table_id: i64,
+
+    pub table_name: String,
+
+    pub name: String,
+
+    pub command: PolicyCommand,
+
+    /// Permissive policies are combined with `OR`, restrictive ones with `AND`.
+    pub is_permissive: bool,
+
+    /// The roles the policy applies to. Contains `public` if it applies to all roles.
+    pub roles: Vec<String>,
+
+    /// The `USING` expression, checked against existing rows.
+    pub using: Option<String>,
+
+    /// The `WITH CHECK` expression, checked against new rows.
+    pub with_check: Option<String>,
+}
+
+impl SchemaCacheItem for Policy {
+    type Item = Policy;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Policy>, sqlx::Error> {
+        sqlx::query_file_as!(Policy, "src/queries/policies.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{SchemaCache, policies::PolicyCommand};
+
+    #[tokio::test]
+    async fn loads_policies() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            do $$
+            begin
+                if not exists (select 1 from pg_roles where rolname = 'owner') then
+                    create role owner;
+                end if;
+            end
+            $$;
+
+            create table public.documents (
+                id serial primary key,
+                owner_id int not null,
+                is_public bool not null default false
+            );
+
+            alter table public.documents enable row level security;
+
+            create policy "public documents are visible" on public.documents
+                for select
+                using (is_public);
+
+            create policy "owners can insert" on public.documents
+                as restrictive
+                for insert
+                to owner
+                with check (owner_id = 1);
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let document_policies: Vec<_> = cache
+            .policies()
+            .iter()
+            .filter(|p| p.table_name == "documents")
+            .collect();
+
+        assert_eq!(document_policies.len(), 2);
+
+        let select_policy = document_policies
+            .iter()
+            .find(|p| p.name == "public documents are visible")
+            .unwrap();
+        assert_eq!(select_policy.schema, "public");
+        assert_eq!(select_policy.command, PolicyCommand::Select);
+        assert!(select_policy.is_permissive);
+        assert_eq!(select_policy.roles, vec!["public".to_string()]);
+        assert_eq!(select_policy.using, Some("is_public".into()));
+        assert_eq!(select_policy.with_check, None);
+
+        let insert_policy = document_policies
+            .iter()
+            .find(|p| p.name == "owners can insert")
+            .unwrap();
+        assert_eq!(insert_policy.command, PolicyCommand::Insert);
+        assert!(!insert_policy.is_permissive);
+        assert_eq!(insert_policy.roles, vec!["owner".to_string()]);
+        assert_eq!(insert_policy.using, None);
+        assert_eq!(insert_policy.with_check, Some("(owner_id = 1)".into()));
+    }
+}
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// The kind of object a privilege was granted on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrivilegeObjectKind {
+    /// Tables, views, materialized views and foreign tables.
+    Table,
+    Column,
+    Sequence,
+    Function,
+    Schema,
+}
+
+impl From<String> for PrivilegeObjectKind {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "TABLE" => PrivilegeObjectKind::Table,
+            "COLUMN" => PrivilegeObjectKind::Column,
+            "SEQUENCE" => PrivilegeObjectKind::Sequence,
+            "FUNCTION" => PrivilegeObjectKind::Function,
+            "SCHEMA" => PrivilegeObjectKind::Schema,
+            _ => panic!("Invalid privilege object kind"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrivilegeType {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Execute,
+    Usage,
+    Create,
+    Maintain,
+}
+
+impl From<String> for PrivilegeType {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "SELECT" => PrivilegeType::Select,
+            "INSERT" => PrivilegeType::Insert,
+            "UPDATE" => PrivilegeType::Update,
+            "DELETE" => PrivilegeType::Delete,
+            "TRUNCATE" => PrivilegeType::Truncate,
+            "REFERENCES" => PrivilegeType::References,
+            "TRIGGER" => PrivilegeType::Trigger,
+            "EXECUTE" => PrivilegeType::Execute,
+            "USAGE" => PrivilegeType::Usage,
+            "CREATE" => PrivilegeType::Create,
+            "MAINTAIN" => PrivilegeType::Maintain,
+            _ => panic!("Invalid privilege type"),
+        }
+    }
+}
+
+/// A single entry of an object's access control list.
+///
+/// Objects without an explicit ACL are listed with Postgres' default privileges, e.g. `EXECUTE`
+/// for `PUBLIC` on functions. Objects in `pg_catalog` and `information_schema` are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Privilege {
+    pub object_kind: PrivilegeObjectKind,
+
+    /// The Id (`oid`) of the object. For columns, this is the Id of the table.
+    pub object_id: i64,
+
+    /// The name of the schema the object belongs to. For schemas, this is the schema itself.
+    pub schema: String,
+
+    /// The name of the object. For columns, this is the name of the table.
+    pub name: String,
+
+    /// Only set for column privileges.
+    pub column_name: Option<String>,
+
+    /// The role that granted the privilege.
+    pub grantor: String,
+
+    /// The role the privilege was granted to, or `PUBLIC`.
+    pub grantee: String,
+
+    pub privilege_type: PrivilegeType,
+
+    /// Was the privilege granted `WITH GRANT OPTION`?
+    pub is_grantable: bool,
+}
+
+impl SchemaCacheItem for Privilege {
+    type Item = Privilege;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Privilege>, sqlx::Error> {
+        sqlx::query_file_as!(Privilege, "src/queries/privileges.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{
+        SchemaCache,
+        privileges::{PrivilegeObjectKind, PrivilegeType},
+    };
+
+    #[tokio::test]
+    async fn loads_privileges() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            do $$
+            begin
+                if not exists (select 1 from pg_roles where rolname = 'test_anon') then
+                    create role test_anon nologin;
+                end if;
+            end
+            $$;
+
+            create table public.posts (
+                id serial primary key,
+                title text,
+                body text
+            );
+
+            grant select on public.posts to test_anon with grant option;
+            grant update (title) on public.posts to test_anon;
+
+            create function public.publish(post_id int)
+            returns void
+            language sql
+            as $$ select 1; $$;
+
+            revoke execute on function public.publish(int) from public;
+            grant execute on function public.publish(int) to test_anon;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let anon_privileges: Vec<_> = cache
+            .privileges()
+            .iter()
+            .filter(|p| p.grantee == "test_anon")
+            .collect();
+
+        let table_select = anon_privileges
+            .iter()
+            .find(|p| p.object_kind == PrivilegeObjectKind::Table && p.name == "posts")
+            .unwrap();
+        assert_eq!(table_select.schema, "public");
+        assert_eq!(table_select.privilege_type, PrivilegeType::Select);
+        assert!(table_select.is_grantable);
+        assert_eq!(table_select.grantor, "postgres");
+
+        let column_update = anon_privileges
+            .iter()
+            .find(|p| p.object_kind == PrivilegeObjectKind::Column)
+            .unwrap();
+        assert_eq!(column_update.name, "posts");
+        assert_eq!(column_update.column_name, Some("title".into()));
+        assert_eq!(column_update.privilege_type, PrivilegeType::Update);
+        assert!(!column_update.is_grantable);
+
+        let function_execute: Vec<_> = cache
+            .privileges()
+            .iter()
+            .filter(|p| p.object_kind == PrivilegeObjectKind::Function && p.name == "publish")
+            .map(|p| p.grantee.as_str())
+            .collect();
+        assert!(function_execute.contains(&"test_anon"));
+        assert!(!function_execute.contains(&"PUBLIC"));
+
+        // sequences have default privileges for their owner
+        assert!(cache.privileges().iter().any(|p| {
+            p.object_kind == PrivilegeObjectKind::Sequence
+                && p.name == "posts_id_seq"
+                && p.grantee == "postgres"
+                && p.privilege_type == PrivilegeType::Usage
+        }));
+    }
+}
@@ -0,0 +1,13 @@
+select
+  c.oid :: int8 as "id!",
+  c.castsource :: int8 as "source_type_id!",
+  c.casttarget :: int8 as "target_type_id!",
+  -- a function id of 0 means the types are binary coercible or converted via their I/O functions
+  nullif(c.castfunc :: int8, 0) as function_id,
+  case
+    when c.castcontext = 'i' then 'IMPLICIT'
+    when c.castcontext = 'a' then 'ASSIGNMENT'
+    else 'EXPLICIT'
+  end as "context!"
+from
+  pg_catalog.pg_cast c;
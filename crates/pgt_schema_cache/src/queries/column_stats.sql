@@ -0,0 +1,27 @@
+select
+  distinct on (s.schemaname, s.tablename, s.attname)
+  c.oid :: int8 as "table_oid!",
+  s.schemaname :: text as "schema_name!",
+  s.tablename :: text as "table_name!",
+  s.attname :: text as "column_name!",
+  s.inherited as "inherited!",
+  s.null_frac as "null_frac!",
+  s.avg_width as "avg_width!",
+  s.n_distinct as "n_distinct!",
+  s.most_common_vals :: text :: text [] as most_common_values,
+  s.most_common_freqs,
+  s.correlation
+from
+  pg_stats s
+  join pg_namespace n on n.nspname = s.schemaname
+  join pg_class c on c.relnamespace = n.oid
+  and c.relname = s.tablename
+where
+  c.relkind in ('r', 'p', 'm', 'f')
+  and s.schemaname not in ('pg_catalog', 'information_schema')
+order by
+  s.schemaname,
+  s.tablename,
+  s.attname,
+  -- prefer the statistics of the whole inheritance hierarchy
+  s.inherited desc;
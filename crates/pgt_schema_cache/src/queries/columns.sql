@@ -43,6 +43,13 @@ select
   pg_get_expr (def.adbin, def.adrelid) as default_expr,
   coalesce(ix.is_primary, false) as "is_primary_key!",
   coalesce(ix.is_unique, false) as "is_unique!",
+  case
+    when atts.attidentity = 'a' then 'IDENTITY ALWAYS'
+    when atts.attidentity = 'd' then 'IDENTITY BY DEFAULT'
+    when atts.attgenerated = 's' then 'STORED'
+    when atts.attgenerated = 'v' then 'VIRTUAL'
+    else 'NONE'
+  end as "generation!",
   pg_catalog.col_description (ts.table_oid, atts.attnum) as comment
 from
   pg_catalog.pg_attribute atts
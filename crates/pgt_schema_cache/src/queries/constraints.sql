@@ -0,0 +1,85 @@
+select
+  con.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  con.conname as "name!",
+  t.oid :: int8 as "table_id!",
+  t.relname as "table_name!",
+  case
+    when con.contype = 'p' then 'PRIMARY KEY'
+    when con.contype = 'f' then 'FOREIGN KEY'
+    when con.contype = 'c' then 'CHECK'
+    when con.contype = 'u' then 'UNIQUE'
+    when con.contype = 'x' then 'EXCLUDE'
+    when con.contype = 't' then 'TRIGGER'
+    else 'NOT NULL'
+  end as "kind!",
+  coalesce(constraint_columns.names, '{}') as "columns!",
+  nullif(con.confrelid :: int8, 0) as referenced_table_id,
+  rn.nspname as "referenced_schema?",
+  rt.relname as "referenced_table?",
+  coalesce(referenced_columns.names, '{}') as "referenced_columns!",
+  case
+    when con.confupdtype = 'a' then 'NO ACTION'
+    when con.confupdtype = 'r' then 'RESTRICT'
+    when con.confupdtype = 'c' then 'CASCADE'
+    when con.confupdtype = 'n' then 'SET NULL'
+    when con.confupdtype = 'd' then 'SET DEFAULT'
+  end as on_update,
+  case
+    when con.confdeltype = 'a' then 'NO ACTION'
+    when con.confdeltype = 'r' then 'RESTRICT'
+    when con.confdeltype = 'c' then 'CASCADE'
+    when con.confdeltype = 'n' then 'SET NULL'
+    when con.confdeltype = 'd' then 'SET DEFAULT'
+  end as on_delete,
+  con.condeferrable as "is_deferrable!",
+  con.condeferred as "is_initially_deferred!",
+  con.convalidated as "is_validated!",
+  pg_get_constraintdef(con.oid) as "definition!",
+  obj_description(con.oid, 'pg_constraint') as comment
+from
+  pg_catalog.pg_constraint con
+  join pg_catalog.pg_class t on t.oid = con.conrelid
+  join pg_catalog.pg_namespace n on n.oid = con.connamespace
+  left join pg_catalog.pg_class rt on rt.oid = con.confrelid
+  left join pg_catalog.pg_namespace rn on rn.oid = rt.relnamespace
+  left join lateral (
+    select
+      array_agg(
+        a.attname :: text
+        order by
+          k.ord
+      ) as names
+    from
+      unnest(con.conkey) with ordinality as k(attnum, ord)
+      join pg_catalog.pg_attribute a on a.attrelid = con.conrelid
+      and a.attnum = k.attnum
+  ) as constraint_columns on true
+  left join lateral (
+    select
+      array_agg(
+        a.attname :: text
+        order by
+          k.ord
+      ) as names
+    from
+      unnest(con.confkey) with ordinality as k(attnum, ord)
+      join pg_catalog.pg_attribute a on a.attrelid = con.confrelid
+      and a.attnum = k.attnum
+  ) as referenced_columns on true
+where
+  -- domain constraints are not attached to a table
+  con.conrelid <> 0
+  and not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(t.relowner, 'USAGE')
+    or has_table_privilege(
+      t.oid,
+      'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+    )
+    or has_any_column_privilege(t.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+  )
+order by
+  n.nspname,
+  t.relname,
+  con.conname;
\ No newline at end of file
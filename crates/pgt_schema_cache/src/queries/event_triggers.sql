@@ -0,0 +1,16 @@
+select
+  e.oid :: int8 as "id!",
+  e.evtname as "name!",
+  e.evtevent as "event!",
+  p.oid :: int8 as "function_id!",
+  pn.nspname as "function_schema!",
+  p.proname as "function_name!",
+  e.evtenabled :: text as "enabled!",
+  coalesce(e.evttags, '{}') as "tags!",
+  obj_description(e.oid, 'pg_event_trigger') as comment
+from
+  pg_catalog.pg_event_trigger e
+  join pg_catalog.pg_proc p on p.oid = e.evtfoid
+  join pg_catalog.pg_namespace pn on pn.oid = p.pronamespace
+order by
+  e.evtname;
\ No newline at end of file
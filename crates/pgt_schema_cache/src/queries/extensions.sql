@@ -0,0 +1,12 @@
+select
+  ae.name as "name!",
+  n.nspname as "schema?",
+  e.extversion as "installed_version?",
+  ae.default_version,
+  ae.comment
+from
+  pg_catalog.pg_available_extensions ae
+  left join pg_catalog.pg_extension e on e.extname = ae.name
+  left join pg_catalog.pg_namespace n on n.oid = e.extnamespace
+order by
+  ae.name;
\ No newline at end of file
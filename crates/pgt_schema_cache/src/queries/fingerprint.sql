@@ -0,0 +1,117 @@
+-- Every DDL statement inserts, updates or deletes rows in the system catalogs. Combining the number
+-- of rows with the highest transaction id that created one of them is enough to tell whether a
+-- catalog changed, without comparing its contents.
+select
+  'pg_namespace' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_namespace
+union all
+select
+  'pg_class' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_class
+union all
+select
+  'pg_attribute' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_attribute
+union all
+select
+  'pg_attrdef' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_attrdef
+union all
+select
+  'pg_constraint' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_constraint
+union all
+select
+  'pg_index' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_index
+union all
+select
+  'pg_proc' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_proc
+union all
+select
+  'pg_type' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_type
+union all
+select
+  'pg_enum' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_enum
+union all
+select
+  'pg_policy' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_policy
+union all
+select
+  'pg_trigger' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_trigger
+union all
+select
+  'pg_event_trigger' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_event_trigger
+union all
+select
+  'pg_extension' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_extension
+union all
+select
+  'pg_rewrite' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_rewrite
+union all
+select
+  'pg_sequence' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_sequence
+union all
+select
+  'pg_description' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_description
+union all
+select
+  'pg_inherits' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_inherits
+union all
+select
+  'pg_cast' as "catalog!",
+  count(*) :: text || ':' || coalesce(max(xmin :: text :: int8), 0) :: text as "fingerprint!"
+from
+  pg_cast
+union all
+-- pg_authid is not readable by regular users
+select
+  'pg_roles' as "catalog!",
+  md5(coalesce(string_agg(r :: text, ',' order by r.oid), '')) as "fingerprint!"
+from
+  pg_roles r;
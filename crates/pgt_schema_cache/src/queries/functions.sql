@@ -62,10 +62,15 @@ select
     when f.provolatile = 's' then 'STABLE'
     when f.provolatile = 'v' then 'VOLATILE'
   end as behavior,
-  f.prosecdef as security_definer
+  f.prosecdef as security_definer,
+  ext.extname as "extension?"
 from
   functions f
   left join pg_namespace n on f.pronamespace = n.oid
+  left join pg_depend ext_dep on ext_dep.classid = 'pg_proc' :: regclass
+  and ext_dep.objid = f.oid
+  and ext_dep.deptype = 'e'
+  left join pg_extension ext on ext.oid = ext_dep.refobjid
   left join pg_language l on f.prolang = l.oid
   left join pg_type rt on rt.oid = f.prorettype
   left join (
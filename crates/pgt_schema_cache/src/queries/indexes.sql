@@ -0,0 +1,68 @@
+select
+  i.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  i.relname as "name!",
+  t.oid :: int8 as "table_id!",
+  t.relname as "table_name!",
+  am.amname as "method!",
+  coalesce(index_keys.columns, '{}') as "columns!",
+  coalesce(index_keys.expressions, '{}') as "expressions!",
+  ix.indisunique as "is_unique!",
+  ix.indisprimary as "is_primary!",
+  ix.indisvalid as "is_valid!",
+  pg_get_expr(ix.indpred, ix.indrelid) as predicate,
+  pg_get_indexdef(i.oid) as "definition!",
+  pg_relation_size(i.oid) :: int8 as "bytes!",
+  pg_size_pretty(pg_relation_size(i.oid)) as "size!",
+  obj_description(i.oid, 'pg_class') as comment
+from
+  pg_catalog.pg_index ix
+  join pg_catalog.pg_class i on i.oid = ix.indexrelid
+  join pg_catalog.pg_class t on t.oid = ix.indrelid
+  join pg_catalog.pg_namespace n on n.oid = i.relnamespace
+  join pg_catalog.pg_am am on am.oid = i.relam
+  left join lateral (
+    select
+      -- a key with `attnum = 0` is an expression, e.g. `lower(email)`
+      array_agg(
+        a.attname :: text
+        order by
+          k.ord
+      ) filter (
+        where
+          k.attnum <> 0
+      ) as columns,
+      array_agg(
+        pg_get_indexdef(ix.indexrelid, k.ord :: int4, true)
+        order by
+          k.ord
+      ) filter (
+        where
+          k.attnum = 0
+      ) as expressions
+    from
+      unnest(ix.indkey) with ordinality as k(attnum, ord)
+      left join pg_catalog.pg_attribute a on a.attrelid = ix.indrelid
+      and a.attnum = k.attnum
+    where
+      -- `INCLUDE` columns are stored after the key columns
+      k.ord <= ix.indnkeyatts
+  ) as index_keys on true
+where
+  -- r: normal tables
+  -- m: materialized views
+  -- p: partitioned tables
+  t.relkind in ('r', 'm', 'p')
+  and not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(t.relowner, 'USAGE')
+    or has_table_privilege(
+      t.oid,
+      'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+    )
+    or has_any_column_privilege(t.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+  )
+order by
+  n.nspname,
+  t.relname,
+  i.relname;
\ No newline at end of file
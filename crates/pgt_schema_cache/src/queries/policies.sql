@@ -0,0 +1,48 @@
+select
+  pol.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  c.oid :: int8 as "table_id!",
+  c.relname as "table_name!",
+  pol.polname as "name!",
+  case
+    when pol.polcmd = 'r' then 'SELECT'
+    when pol.polcmd = 'a' then 'INSERT'
+    when pol.polcmd = 'w' then 'UPDATE'
+    when pol.polcmd = 'd' then 'DELETE'
+    else 'ALL'
+  end as "command!",
+  pol.polpermissive as "is_permissive!",
+  case
+    -- a role id of 0 stands for `PUBLIC`
+    when pol.polroles = '{0}' then array ['public']
+    else array(
+      select
+        r.rolname :: text
+      from
+        pg_catalog.pg_roles r
+      where
+        r.oid = any (pol.polroles)
+      order by
+        r.rolname
+    )
+  end as "roles!",
+  pg_get_expr(pol.polqual, pol.polrelid) as "using",
+  pg_get_expr(pol.polwithcheck, pol.polrelid) as with_check
+from
+  pg_catalog.pg_policy pol
+  join pg_catalog.pg_class c on c.oid = pol.polrelid
+  join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+where
+  not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(c.relowner, 'USAGE')
+    or has_table_privilege(
+      c.oid,
+      'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+    )
+    or has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+  )
+order by
+  n.nspname,
+  c.relname,
+  pol.polname;
\ No newline at end of file
@@ -0,0 +1,143 @@
+with
+  privileges as (
+    select
+      case
+        when c.relkind = 'S' then 'SEQUENCE'
+        else 'TABLE'
+      end as object_kind,
+      c.oid as object_id,
+      n.nspname as schema,
+      c.relname as name,
+      null :: text as column_name,
+      acl.grantor,
+      acl.grantee,
+      acl.privilege_type,
+      acl.is_grantable
+    from
+      pg_catalog.pg_class c
+      join pg_catalog.pg_namespace n on n.oid = c.relnamespace,
+      -- a null acl means that the default privileges apply
+      lateral aclexplode(
+        coalesce(
+          c.relacl,
+          acldefault(
+            case
+              when c.relkind = 'S' then 's'
+              else 'r'
+            end :: "char",
+            c.relowner
+          )
+        )
+      ) as acl
+    where
+      -- r: normal tables
+      -- p: partitioned tables
+      -- v: views
+      -- m: materialized views
+      -- f: foreign tables
+      -- S: sequences
+      c.relkind in ('r', 'p', 'v', 'm', 'f', 'S')
+      and (
+        pg_has_role(c.relowner, 'USAGE')
+        or case
+          when c.relkind = 'S' then has_sequence_privilege(c.oid, 'SELECT, UPDATE, USAGE')
+          else has_table_privilege(
+            c.oid,
+            'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+          )
+          or has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+        end
+      )
+    union all
+    select
+      'COLUMN' as object_kind,
+      c.oid as object_id,
+      n.nspname as schema,
+      c.relname as name,
+      a.attname :: text as column_name,
+      acl.grantor,
+      acl.grantee,
+      acl.privilege_type,
+      acl.is_grantable
+    from
+      pg_catalog.pg_attribute a
+      join pg_catalog.pg_class c on c.oid = a.attrelid
+      join pg_catalog.pg_namespace n on n.oid = c.relnamespace,
+      -- columns only have privileges that were granted explicitly
+      lateral aclexplode(a.attacl) as acl
+    where
+      a.attacl is not null
+      and a.attnum > 0
+      and not a.attisdropped
+      and (
+        pg_has_role(c.relowner, 'USAGE')
+        or has_column_privilege(
+          c.oid,
+          a.attnum,
+          'SELECT, INSERT, UPDATE, REFERENCES'
+        )
+      )
+    union all
+    select
+      'FUNCTION' as object_kind,
+      p.oid as object_id,
+      n.nspname as schema,
+      p.proname as name,
+      null :: text as column_name,
+      acl.grantor,
+      acl.grantee,
+      acl.privilege_type,
+      acl.is_grantable
+    from
+      pg_catalog.pg_proc p
+      join pg_catalog.pg_namespace n on n.oid = p.pronamespace,
+      lateral aclexplode(coalesce(p.proacl, acldefault('f', p.proowner))) as acl
+    where
+      pg_has_role(p.proowner, 'USAGE')
+      or has_function_privilege(p.oid, 'EXECUTE')
+    union all
+    select
+      'SCHEMA' as object_kind,
+      n.oid as object_id,
+      n.nspname as schema,
+      n.nspname as name,
+      null :: text as column_name,
+      acl.grantor,
+      acl.grantee,
+      acl.privilege_type,
+      acl.is_grantable
+    from
+      pg_catalog.pg_namespace n,
+      lateral aclexplode(coalesce(n.nspacl, acldefault('n', n.nspowner))) as acl
+    where
+      pg_has_role(n.nspowner, 'USAGE')
+      or has_schema_privilege(n.oid, 'CREATE, USAGE')
+  )
+select
+  p.object_kind as "object_kind!",
+  p.object_id :: int8 as "object_id!",
+  p.schema :: text as "schema!",
+  p.name :: text as "name!",
+  p.column_name,
+  pg_get_userbyid(p.grantor) :: text as "grantor!",
+  case
+    -- a grantee of 0 stands for `PUBLIC`
+    when p.grantee = 0 then 'PUBLIC'
+    else pg_get_userbyid(p.grantee) :: text
+  end as "grantee!",
+  p.privilege_type as "privilege_type!",
+  p.is_grantable as "is_grantable!"
+from
+  privileges p
+where
+  -- the built-in objects come with thousands of default privileges that are rarely of interest
+  p.schema not in ('pg_catalog', 'information_schema')
+  and not pg_catalog.starts_with(p.schema, 'pg_toast')
+  and not pg_catalog.starts_with(p.schema, 'pg_temp_')
+order by
+  p.schema,
+  p.name,
+  p.object_kind,
+  p.column_name,
+  p.grantee,
+  p.privilege_type;
\ No newline at end of file
@@ -0,0 +1,25 @@
+select
+  r.oid :: int8 as "id!",
+  r.rolname as "name!",
+  r.rolsuper as "is_super_user!",
+  r.rolcreatedb as "can_create_db!",
+  r.rolcreaterole as "can_create_roles!",
+  r.rolinherit as "can_inherit!",
+  r.rolcanlogin as "can_login!",
+  r.rolbypassrls as "can_bypass_rls!",
+  array(
+    select
+      g.rolname :: text
+    from
+      pg_catalog.pg_auth_members m
+      join pg_catalog.pg_roles g on g.oid = m.roleid
+    where
+      m.member = r.oid
+    order by
+      g.rolname
+  ) as "member_of!",
+  shobj_description(r.oid, 'pg_authid') as comment
+from
+  pg_catalog.pg_roles r
+order by
+  r.rolname;
\ No newline at end of file
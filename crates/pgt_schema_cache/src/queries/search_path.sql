@@ -0,0 +1,4 @@
+-- the effective search path of the connection, including implicitly searched schemas such as
+-- `pg_catalog`. schemas that do not exist are left out.
+select
+  current_schemas(true) :: text[] as "search_path!";
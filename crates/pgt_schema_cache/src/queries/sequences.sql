@@ -0,0 +1,52 @@
+select
+  c.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  c.relname as "name!",
+  format_type(s.seqtypid, null) as "data_type!",
+  s.seqstart as "start_value!",
+  s.seqincrement as "increment!",
+  s.seqmin as "min_value!",
+  s.seqmax as "max_value!",
+  s.seqcache as "cache_size!",
+  s.seqcycle as "is_cycled!",
+  case
+    when has_sequence_privilege(c.oid, 'SELECT, USAGE') then pg_sequence_last_value(c.oid)
+  end as last_value,
+  owner.schema as "owned_by_schema?",
+  owner.table_name as "owned_by_table?",
+  owner.column_name as "owned_by_column?",
+  coalesce(owner.is_identity, false) as "is_identity!",
+  obj_description(c.oid, 'pg_class') as comment
+from
+  pg_catalog.pg_sequence s
+  join pg_catalog.pg_class c on c.oid = s.seqrelid
+  join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+  left join lateral (
+    select
+      tn.nspname :: text as schema,
+      t.relname :: text as table_name,
+      a.attname :: text as column_name,
+      d.deptype = 'i' as is_identity
+    from
+      pg_catalog.pg_depend d
+      join pg_catalog.pg_class t on t.oid = d.refobjid
+      join pg_catalog.pg_namespace tn on tn.oid = t.relnamespace
+      join pg_catalog.pg_attribute a on a.attrelid = d.refobjid
+      and a.attnum = d.refobjsubid
+    where
+      d.classid = 'pg_catalog.pg_class' :: regclass
+      and d.objid = c.oid
+      and d.refclassid = 'pg_catalog.pg_class' :: regclass
+      -- a: `OWNED BY`, e.g. for serial columns
+      -- i: identity columns
+      and d.deptype in ('a', 'i')
+  ) as owner on true
+where
+  not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(c.relowner, 'USAGE')
+    or has_sequence_privilege(c.oid, 'SELECT, UPDATE, USAGE')
+  )
+order by
+  n.nspname,
+  c.relname;
\ No newline at end of file
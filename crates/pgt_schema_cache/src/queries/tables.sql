@@ -16,12 +16,36 @@ select
   ) as "size!",
   pg_stat_get_live_tuples(c.oid) as "live_rows_estimate!",
   pg_stat_get_dead_tuples(c.oid) as "dead_rows_estimate!",
-  obj_description(c.oid) as comment
+  obj_description(c.oid) as comment,
+  c.relkind :: text as "kind!",
+  c.relkind = 'p' as "is_partitioned!",
+  pg_get_partkeydef(c.oid) as partition_key,
+  c.relispartition as is_partition,
+  pg_get_expr(c.relpartbound, c.oid) as partition_bound,
+  coalesce(
+    (
+      select
+        array_agg(
+          i.inhparent :: int8
+          order by
+            i.inhseqno
+        )
+      from
+        pg_inherits i
+      where
+        i.inhrelid = c.oid
+    ),
+    '{}'
+  ) as "parent_ids!"
 from
   pg_namespace nc
   join pg_class c on nc.oid = c.relnamespace
 where
-  c.relkind in ('r', 'p')
+  -- r: normal tables
+  -- p: partitioned tables
+  -- v: views
+  -- m: materialized views
+  c.relkind in ('r', 'p', 'v', 'm')
   and not pg_is_other_temp_schema(nc.oid)
   and (
     pg_has_role(c.relowner, 'USAGE')
@@ -37,4 +61,5 @@ group by
   c.relrowsecurity,
   c.relforcerowsecurity,
   c.relreplident,
+  c.relkind,
   nc.nspname;
\ No newline at end of file
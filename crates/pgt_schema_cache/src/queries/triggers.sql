@@ -0,0 +1,62 @@
+select
+  t.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  c.oid :: int8 as "table_id!",
+  c.relname as "table_name!",
+  t.tgname as "name!",
+  case
+    when t.tgtype & 2 = 2 then 'BEFORE'
+    when t.tgtype & 64 = 64 then 'INSTEAD OF'
+    else 'AFTER'
+  end as "timing!",
+  case
+    when t.tgtype & 1 = 1 then 'ROW'
+    else 'STATEMENT'
+  end as "level!",
+  t.tgtype & 4 = 4 as "on_insert!",
+  t.tgtype & 16 = 16 as "on_update!",
+  t.tgtype & 8 = 8 as "on_delete!",
+  t.tgtype & 32 = 32 as "on_truncate!",
+  array(
+    select
+      a.attname :: text
+    from
+      unnest(t.tgattr) with ordinality as k(attnum, ord)
+      join pg_catalog.pg_attribute a on a.attrelid = t.tgrelid
+      and a.attnum = k.attnum
+    order by
+      k.ord
+  ) as "update_columns!",
+  p.oid :: int8 as "function_id!",
+  pn.nspname as "function_schema!",
+  p.proname as "function_name!",
+  t.tgenabled :: text as "enabled!",
+  substring(
+    pg_get_triggerdef(t.oid)
+    from
+      'WHEN \((.+)\) EXECUTE (?:FUNCTION|PROCEDURE)'
+  ) as when_clause,
+  pg_get_triggerdef(t.oid) as "definition!",
+  obj_description(t.oid, 'pg_trigger') as comment
+from
+  pg_catalog.pg_trigger t
+  join pg_catalog.pg_class c on c.oid = t.tgrelid
+  join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+  join pg_catalog.pg_proc p on p.oid = t.tgfoid
+  join pg_catalog.pg_namespace pn on pn.oid = p.pronamespace
+where
+  -- internal triggers implement foreign keys and the like
+  not t.tgisinternal
+  and not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(c.relowner, 'USAGE')
+    or has_table_privilege(
+      c.oid,
+      'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+    )
+    or has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+  )
+order by
+  n.nspname,
+  c.relname,
+  t.tgname;
\ No newline at end of file
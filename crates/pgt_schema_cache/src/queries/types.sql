@@ -5,10 +5,52 @@ select
   format_type (t.oid, null) as "format!",
   coalesce(t_enums.enums, '[]') as enums,
   coalesce(t_attributes.attributes, '[]') as attributes,
-  obj_description (t.oid, 'pg_type') as comment
+  obj_description (t.oid, 'pg_type') as comment,
+  ext.extname as "extension?",
+  case
+    when t.typtype = 'b' then 'BASE'
+    when t.typtype = 'c' then 'COMPOSITE'
+    when t.typtype = 'd' then 'DOMAIN'
+    when t.typtype = 'e' then 'ENUM'
+    when t.typtype = 'r' then 'RANGE'
+    when t.typtype = 'm' then 'MULTIRANGE'
+    else 'PSEUDO'
+  end as "kind!",
+  nullif(t.typbasetype :: int8, 0) as base_type_id,
+  t.typnotnull as "not_null!",
+  coalesce(t_domain_constraints.constraints, '[]') as domain_constraints,
+  coalesce(rng.rngsubtype, multirng.rngsubtype) :: int8 as range_subtype_id,
+  t.typispreferred as "is_preferred!"
 from
   pg_type t
   left join pg_namespace n on n.oid = t.typnamespace
+  left join pg_depend ext_dep on ext_dep.classid = 'pg_type' :: regclass
+  and ext_dep.objid = t.oid
+  and ext_dep.deptype = 'e'
+  left join pg_extension ext on ext.oid = ext_dep.refobjid
+  left join pg_range rng on rng.rngtypid = t.oid
+  left join pg_range multirng on multirng.rngmultitypid = t.oid
+  left join (
+    select
+      contypid,
+      jsonb_agg(
+        jsonb_build_object(
+          'name',
+          conname,
+          'definition',
+          pg_get_constraintdef(oid)
+        )
+        order by
+          conname
+      ) as constraints
+    from
+      pg_constraint
+    where
+      contypid <> 0
+      and contype = 'c'
+    group by
+      contypid
+  ) as t_domain_constraints on t_domain_constraints.contypid = t.oid
   left join (
     select
       enumtypid,
@@ -0,0 +1,69 @@
+select
+  c.oid :: int8 as "id!",
+  n.nspname as "schema!",
+  c.relname as "name!",
+  c.relkind = 'm' as "is_materialized!",
+  c.relispopulated as "is_populated!",
+  pg_get_viewdef(c.oid) as "definition!",
+  coalesce(column_dependencies.columns, '[]') as "column_dependencies!: Json<Vec<ViewColumnDependency>>",
+  obj_description(c.oid, 'pg_class') as comment
+from
+  pg_catalog.pg_class c
+  join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+  left join lateral (
+    select
+      jsonb_agg(
+        jsonb_build_object(
+          'table_id',
+          deps.table_id,
+          'schema',
+          deps.schema,
+          'table_name',
+          deps.table_name,
+          'column_name',
+          deps.column_name
+        )
+        order by
+          deps.schema,
+          deps.table_name,
+          deps.column_name
+      ) as columns
+    from
+      (
+        -- a view is implemented as a rewrite rule, which depends on every column it references
+        select
+          distinct rc.oid :: int8 as table_id,
+          rn.nspname :: text as schema,
+          rc.relname :: text as table_name,
+          a.attname :: text as column_name
+        from
+          pg_catalog.pg_rewrite r
+          join pg_catalog.pg_depend d on d.classid = 'pg_catalog.pg_rewrite' :: regclass
+          and d.objid = r.oid
+          and d.refclassid = 'pg_catalog.pg_class' :: regclass
+          and d.refobjsubid > 0
+          join pg_catalog.pg_class rc on rc.oid = d.refobjid
+          join pg_catalog.pg_namespace rn on rn.oid = rc.relnamespace
+          join pg_catalog.pg_attribute a on a.attrelid = d.refobjid
+          and a.attnum = d.refobjsubid
+        where
+          r.ev_class = c.oid
+          and d.refobjid <> c.oid
+      ) as deps
+  ) as column_dependencies on true
+where
+  -- v: views
+  -- m: materialized views
+  c.relkind in ('v', 'm')
+  and not pg_is_other_temp_schema(n.oid)
+  and (
+    pg_has_role(c.relowner, 'USAGE')
+    or has_table_privilege(
+      c.oid,
+      'SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER'
+    )
+    or has_any_column_privilege(c.oid, 'SELECT, INSERT, UPDATE, REFERENCES')
+  )
+order by
+  n.nspname,
+  c.relname;
\ No newline at end of file
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Role {
+    /// The Id (`oid`) of the role.
+    pub id: i64,
+
+    pub name: String,
+
+    pub is_super_user: bool,
+
+    pub can_create_db: bool,
+
+    pub can_create_roles: bool,
+
+    /// Does the role automatically inherit the privileges of the roles it is a member of?
+    pub can_inherit: bool,
+
+    pub can_login: bool,
+
+    /// Does the role bypass row-level security policies?
+    pub can_bypass_rls: bool,
+
+    /// The names of the roles this role is a direct member of.
+    pub member_of: Vec<String>,
+
+    /// Comment inserted via `COMMENT ON ROLE my_role IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for Role {
+    type Item = Role;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Role>, sqlx::Error> {
+        sqlx::query_file_as!(Role, "src/queries/roles.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::SchemaCache;
+
+    #[tokio::test]
+    async fn loads_roles() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            do $$
+            begin
+                if not exists (select 1 from pg_roles where rolname = 'test_authenticated') then
+                    create role test_authenticated nologin;
+                end if;
+
+                if not exists (select 1 from pg_roles where rolname = 'test_api') then
+                    create role test_api login bypassrls in role test_authenticated;
+                end if;
+            end
+            $$;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let authenticated = cache
+            .roles()
+            .iter()
+            .find(|r| r.name == "test_authenticated")
+            .unwrap();
+        assert!(!authenticated.can_login);
+        assert!(!authenticated.is_super_user);
+        assert!(authenticated.member_of.is_empty());
+
+        let api = cache.roles().iter().find(|r| r.name == "test_api").unwrap();
+        assert!(api.can_login);
+        assert!(api.can_bypass_rls);
+        assert!(api.can_inherit);
+        assert_eq!(api.member_of, vec!["test_authenticated".to_string()]);
+
+        let postgres = cache.roles().iter().find(|r| r.name == "postgres").unwrap();
+        assert!(postgres.is_super_user);
+    }
+}
@@ -1,31 +1,237 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
 use sqlx::postgres::PgPool;
 
+use crate::casts::Cast;
+use crate::column_stats::ColumnStats;
 use crate::columns::Column;
+use crate::constraints::Constraint;
+use crate::extensions::Extension;
 use crate::functions::Function;
+use crate::indexes::Index;
+use crate::policies::Policy;
+use crate::privileges::Privilege;
+use crate::roles::Role;
 use crate::schemas::Schema;
+use crate::sequences::Sequence;
 use crate::tables::Table;
+use crate::triggers::{EventTrigger, Trigger};
 use crate::types::PostgresType;
 use crate::versions::Version;
+use crate::views::View;
 
-#[derive(Debug, Clone, Default)]
+/// The search path used if none was loaded from the database, e.g. for caches created via
+/// [SchemaCache::builtin].
+const DEFAULT_SEARCH_PATH: [&str; 2] = ["pg_catalog", "public"];
+
+/// Lookups via the `find_*` methods use an index of the positions of the items that is built on
+/// first use. The items can only be modified via the `*_mut` methods, which drop the index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SchemaCache {
-    pub schemas: Vec<Schema>,
-    pub tables: Vec<Table>,
-    pub functions: Vec<Function>,
-    pub types: Vec<PostgresType>,
-    pub versions: Vec<Version>,
-    pub columns: Vec<Column>,
+    schemas: Vec<Schema>,
+    tables: Vec<Table>,
+    functions: Vec<Function>,
+    types: Vec<PostgresType>,
+    versions: Vec<Version>,
+    columns: Vec<Column>,
+    indexes: Vec<Index>,
+    constraints: Vec<Constraint>,
+    policies: Vec<Policy>,
+    triggers: Vec<Trigger>,
+    event_triggers: Vec<EventTrigger>,
+    views: Vec<View>,
+    extensions: Vec<Extension>,
+    roles: Vec<Role>,
+    privileges: Vec<Privilege>,
+    sequences: Vec<Sequence>,
+    casts: Vec<Cast>,
+
+    /// Only loaded on demand via [SchemaCache::load_column_stats], since reading `pg_stats` can
+    /// be slow on large databases.
+    column_stats: Option<Vec<ColumnStats>>,
+
+    /// The schemas unqualified names are resolved against, in order, as returned by
+    /// `current_schemas(true)`.
+    search_path: Vec<String>,
+
+    #[serde(skip)]
+    index: OnceLock<SchemaCacheIndex>,
+}
+
+/// Positions of the items within the [SchemaCache], keyed by `(schema, name)` and by `oid`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SchemaCacheIndex {
+    tables_by_name: HashMap<(String, String), usize>,
+    tables_by_id: HashMap<i64, usize>,
+    tables_by_parent: HashMap<i64, Vec<usize>>,
+    types_by_name: HashMap<(String, String), usize>,
+    types_by_id: HashMap<i64, usize>,
+    functions_by_name: HashMap<(String, String), Vec<usize>>,
+    functions_by_id: HashMap<i64, usize>,
+    columns_by_table: HashMap<(String, String), Vec<usize>>,
+    /// Tables, views, materialized views and foreign tables, to resolve the schema of a relation
+    relations_by_name: HashSet<(String, String)>,
+    casts_by_types: HashMap<(i64, i64), usize>,
+    column_stats_by_column: HashMap<(i64, String), usize>,
+}
+
+impl SchemaCacheIndex {
+    fn new(cache: &SchemaCache) -> Self {
+        let mut index = SchemaCacheIndex::default();
+
+        for (idx, table) in cache.tables.iter().enumerate() {
+            index
+                .tables_by_name
+                .entry((table.schema.clone(), table.name.clone()))
+                .or_insert(idx);
+            index.tables_by_id.entry(table.id).or_insert(idx);
+            for parent_id in &table.parent_ids {
+                index
+                    .tables_by_parent
+                    .entry(*parent_id)
+                    .or_default()
+                    .push(idx);
+            }
+        }
+
+        for (idx, t) in cache.types.iter().enumerate() {
+            index
+                .types_by_name
+                .entry((t.schema.clone(), t.name.clone()))
+                .or_insert(idx);
+            index.types_by_id.entry(t.id).or_insert(idx);
+        }
+
+        for (idx, function) in cache.functions.iter().enumerate() {
+            index
+                .functions_by_name
+                .entry((function.schema.clone(), function.name.clone()))
+                .or_default()
+                .push(idx);
+            index.functions_by_id.entry(function.id).or_insert(idx);
+        }
+
+        for (idx, column) in cache.columns.iter().enumerate() {
+            index
+                .columns_by_table
+                .entry((column.schema_name.clone(), column.table_name.clone()))
+                .or_default()
+                .push(idx);
+        }
+
+        index.relations_by_name = cache
+            .tables
+            .iter()
+            .map(|table| (table.schema.clone(), table.name.clone()))
+            .chain(
+                cache
+                    .views
+                    .iter()
+                    .map(|view| (view.schema.clone(), view.name.clone())),
+            )
+            .chain(index.columns_by_table.keys().cloned())
+            .collect();
+
+        for (idx, cast) in cache.casts.iter().enumerate() {
+            index
+                .casts_by_types
+                .entry((cast.source_type_id, cast.target_type_id))
+                .or_insert(idx);
+        }
+
+        for (idx, stats) in cache.column_stats.iter().flatten().enumerate() {
+            index
+                .column_stats_by_column
+                .entry((stats.table_oid, stats.column_name.clone()))
+                .or_insert(idx);
+        }
+
+        index
+    }
+}
+
+/// Generates read-only accessors for the items of the cache, and mutable accessors that drop the
+/// index, so that it never points at the wrong item.
+macro_rules! items {
+    ($($field:ident, $field_mut:ident: $item:ty;)*) => {
+        impl SchemaCache {
+            $(
+                pub fn $field(&self) -> &[$item] {
+                    &self.$field
+                }
+
+                pub fn $field_mut(&mut self) -> &mut Vec<$item> {
+                    self.reindex();
+                    &mut self.$field
+                }
+            )*
+        }
+    };
+}
+
+items! {
+    schemas, schemas_mut: Schema;
+    tables, tables_mut: Table;
+    functions, functions_mut: Function;
+    types, types_mut: PostgresType;
+    versions, versions_mut: Version;
+    columns, columns_mut: Column;
+    indexes, indexes_mut: Index;
+    constraints, constraints_mut: Constraint;
+    policies, policies_mut: Policy;
+    triggers, triggers_mut: Trigger;
+    event_triggers, event_triggers_mut: EventTrigger;
+    views, views_mut: View;
+    extensions, extensions_mut: Extension;
+    roles, roles_mut: Role;
+    privileges, privileges_mut: Privilege;
+    sequences, sequences_mut: Sequence;
+    casts, casts_mut: Cast;
+    search_path, search_path_mut: String;
 }
 
 impl SchemaCache {
     pub async fn load(pool: &PgPool) -> Result<SchemaCache, sqlx::Error> {
-        let (schemas, tables, functions, types, versions, columns) = futures_util::try_join!(
+        let (
+            schemas,
+            tables,
+            functions,
+            types,
+            versions,
+            columns,
+            indexes,
+            constraints,
+            policies,
+            triggers,
+            event_triggers,
+            views,
+            extensions,
+            roles,
+            privileges,
+            sequences,
+            casts,
+            search_path,
+        ) = futures_util::try_join!(
             Schema::load(pool),
             Table::load(pool),
             Function::load(pool),
             PostgresType::load(pool),
             Version::load(pool),
-            Column::load(pool)
+            Column::load(pool),
+            Index::load(pool),
+            Constraint::load(pool),
+            Policy::load(pool),
+            Trigger::load(pool),
+            EventTrigger::load(pool),
+            View::load(pool),
+            Extension::load(pool),
+            Role::load(pool),
+            Privilege::load(pool),
+            Sequence::load(pool),
+            Cast::load(pool),
+            load_search_path(pool)
         )?;
 
         Ok(SchemaCache {
@@ -35,43 +241,342 @@ impl SchemaCache {
             types,
             versions,
             columns,
+            indexes,
+            constraints,
+            policies,
+            triggers,
+            event_triggers,
+            views,
+            extensions,
+            roles,
+            privileges,
+            sequences,
+            casts,
+            column_stats: None,
+            search_path,
+            index: OnceLock::new(),
         })
     }
 
-    /// Applies an AST node to the repository
-    ///
-    /// For example, alter table add column will add the column to the table if it does not exist
-    /// yet
-    pub fn mutate(&mut self) {
-        unimplemented!();
+    /// Returns the statistics of all columns, if they were loaded via
+    /// [SchemaCache::load_column_stats].
+    pub fn column_stats(&self) -> Option<&[ColumnStats]> {
+        self.column_stats.as_deref()
+    }
+
+    /// Loads the statistics of all columns from `pg_stats`. Statistics only exist for tables that
+    /// were analyzed, and are not updated by [SchemaCache::refresh] after another `ANALYZE`.
+    pub async fn load_column_stats(&mut self, pool: &PgPool) -> Result<(), sqlx::Error> {
+        self.column_stats = Some(ColumnStats::load(pool).await?);
+        self.reindex();
+
+        Ok(())
+    }
+
+    /// Reloads the items that are read from any of the `changed_catalogs`, e.g. `pg_class`, as
+    /// returned by [crate::SchemaFingerprint::changed_catalogs]. The search path and the versions
+    /// are reloaded every time, since they are not stored in a catalog.
+    pub async fn refresh(
+        &mut self,
+        pool: &PgPool,
+        changed_catalogs: &[String],
+    ) -> Result<(), sqlx::Error> {
+        (self.versions, self.search_path) =
+            futures_util::try_join!(Version::load(pool), load_search_path(pool))?;
+
+        let changed = |catalogs: &[&str]| {
+            changed_catalogs
+                .iter()
+                .any(|changed| catalogs.contains(&changed.as_str()))
+        };
+
+        if changed(&["pg_namespace", "pg_roles"]) {
+            self.schemas = Schema::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_class", "pg_inherits", "pg_description"]) {
+            self.tables = Table::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_proc", "pg_type", "pg_extension"]) {
+            self.functions = Function::load(pool).await?;
+        }
+        if changed(&[
+            "pg_namespace",
+            "pg_type",
+            "pg_enum",
+            "pg_class",
+            "pg_attribute",
+            "pg_constraint",
+            "pg_extension",
+            "pg_description",
+        ]) {
+            self.types = PostgresType::load(pool).await?;
+        }
+        if changed(&[
+            "pg_namespace",
+            "pg_class",
+            "pg_attribute",
+            "pg_attrdef",
+            "pg_index",
+            "pg_constraint",
+            "pg_description",
+        ]) {
+            self.columns = Column::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_class", "pg_index", "pg_description"]) {
+            self.indexes = Index::load(pool).await?;
+        }
+        if changed(&[
+            "pg_namespace",
+            "pg_class",
+            "pg_constraint",
+            "pg_description",
+        ]) {
+            self.constraints = Constraint::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_class", "pg_policy", "pg_roles"]) {
+            self.policies = Policy::load(pool).await?;
+        }
+        if changed(&[
+            "pg_namespace",
+            "pg_class",
+            "pg_trigger",
+            "pg_proc",
+            "pg_description",
+        ]) {
+            self.triggers = Trigger::load(pool).await?;
+        }
+        if changed(&["pg_event_trigger", "pg_proc"]) {
+            self.event_triggers = EventTrigger::load(pool).await?;
+        }
+        if changed(&[
+            "pg_namespace",
+            "pg_class",
+            "pg_attribute",
+            "pg_rewrite",
+            "pg_description",
+        ]) {
+            self.views = View::load(pool).await?;
+        }
+        if changed(&["pg_extension", "pg_description"]) {
+            self.extensions = Extension::load(pool).await?;
+        }
+        if changed(&["pg_roles", "pg_description"]) {
+            self.roles = Role::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_class", "pg_proc", "pg_roles"]) {
+            self.privileges = Privilege::load(pool).await?;
+        }
+        if changed(&["pg_namespace", "pg_class", "pg_sequence", "pg_attribute"]) {
+            self.sequences = Sequence::load(pool).await?;
+        }
+        if changed(&["pg_cast"]) {
+            self.casts = Cast::load(pool).await?;
+        }
+        if self.column_stats.is_some() && changed(&["pg_namespace", "pg_class", "pg_attribute"]) {
+            self.column_stats = Some(ColumnStats::load(pool).await?);
+        }
+
+        self.reindex();
+
+        Ok(())
+    }
+
+    /// Drops the index used by the `find_*` methods, so it is rebuilt on the next lookup.
+    pub(crate) fn reindex(&mut self) {
+        self.index = OnceLock::new();
+    }
+
+    fn index(&self) -> &SchemaCacheIndex {
+        self.index.get_or_init(|| SchemaCacheIndex::new(self))
+    }
+
+    /// The schemas an unqualified name is looked up in, in order. If `schema` is set, only that
+    /// schema is searched.
+    pub(crate) fn schemas_to_search<'a>(&'a self, schema: Option<&'a str>) -> Vec<&'a str> {
+        match schema {
+            Some(schema) => vec![schema],
+            None if self.search_path.is_empty() => DEFAULT_SEARCH_PATH.to_vec(),
+            None => self.search_path.iter().map(|s| s.as_str()).collect(),
+        }
     }
 
+    /// Finds a table by name. Unqualified names are resolved via the search path.
     pub fn find_table(&self, name: &str, schema: Option<&str>) -> Option<&Table> {
-        self.tables
-            .iter()
-            .find(|t| t.name == name && schema.is_none() || Some(t.schema.as_str()) == schema)
+        let index = self.index();
+        self.schemas_to_search(schema)
+            .into_iter()
+            .find_map(|schema| {
+                index
+                    .tables_by_name
+                    .get(&(schema.to_string(), name.to_string()))
+            })
+            .and_then(|idx| self.tables.get(*idx))
+    }
+
+    pub fn find_table_by_id(&self, id: i64) -> Option<&Table> {
+        self.index()
+            .tables_by_id
+            .get(&id)
+            .and_then(|idx| self.tables.get(*idx))
+    }
+
+    /// Finds the tables that inherit from the table, including its partitions.
+    pub fn find_child_tables(&self, table_id: i64) -> Vec<&Table> {
+        self.index()
+            .tables_by_parent
+            .get(&table_id)
+            .map(|idxs| {
+                idxs.iter()
+                    .filter_map(|idx| self.tables.get(*idx))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Finds the tables the table inherits from. For a partition, this is the partitioned table.
+    pub fn find_parent_tables(&self, table_id: i64) -> Vec<&Table> {
+        self.find_table_by_id(table_id)
+            .map(|table| {
+                table
+                    .parent_ids
+                    .iter()
+                    .filter_map(|id| self.find_table_by_id(*id))
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
+    /// Finds the partitioned table at the top of the partition hierarchy of a partition. Returns
+    /// the table itself if it is no partition.
+    pub fn find_partition_root(&self, table_id: i64) -> Option<&Table> {
+        let mut current = self.find_table_by_id(table_id)?;
+
+        while current.is_partition {
+            let Some(parent) = current
+                .parent_ids
+                .first()
+                .and_then(|id| self.find_table_by_id(*id))
+            else {
+                break;
+            };
+            current = parent;
+        }
+
+        Some(current)
+    }
+
+    /// Finds a type by name. Unqualified names are resolved via the search path.
     pub fn find_type(&self, name: &str, schema: Option<&str>) -> Option<&PostgresType> {
-        self.types
-            .iter()
-            .find(|t| t.name == name && schema.is_none() || Some(t.schema.as_str()) == schema)
+        self.find_types(name, schema).into_iter().next()
     }
 
+    pub fn find_type_by_id(&self, id: i64) -> Option<&PostgresType> {
+        self.index()
+            .types_by_id
+            .get(&id)
+            .and_then(|idx| self.types.get(*idx))
+    }
+
+    /// Finds the type a value of the given type is stored as, following domains down to their
+    /// base type. Returns the type itself if it is no domain.
+    pub fn find_base_type(&self, type_id: i64) -> Option<&PostgresType> {
+        let mut current = self.find_type_by_id(type_id)?;
+
+        // domains can be based on other domains, but never on themselves
+        while let Some(base_type_id) = current.base_type_id {
+            current = self.find_type_by_id(base_type_id)?;
+        }
+
+        Some(current)
+    }
+
+    /// Finds a column of a table, view, materialized view or foreign table. An unqualified
+    /// relation name is resolved via the search path.
     pub fn find_col(&self, name: &str, table: &str, schema: Option<&str>) -> Option<&Column> {
-        self.columns.iter().find(|c| {
-            c.name.as_str() == name
-                && c.table_name.as_str() == table
-                && schema.is_none_or(|s| s == c.schema_name.as_str())
-        })
+        let index = self.index();
+        let relation = self
+            .schemas_to_search(schema)
+            .into_iter()
+            .map(|schema| (schema.to_string(), table.to_string()))
+            .find(|relation| index.relations_by_name.contains(relation))?;
+
+        index
+            .columns_by_table
+            .get(&relation)?
+            .iter()
+            .filter_map(|idx| self.columns.get(*idx))
+            .find(|c| c.name == name)
     }
 
+    /// Finds all types with the given name, in search path order. If `schema` is set, only that
+    /// schema is searched.
     pub fn find_types(&self, name: &str, schema: Option<&str>) -> Vec<&PostgresType> {
-        self.types
-            .iter()
-            .filter(|t| t.name == name && schema.is_none() || Some(t.schema.as_str()) == schema)
+        let index = self.index();
+        self.schemas_to_search(schema)
+            .into_iter()
+            .filter_map(|schema| {
+                index
+                    .types_by_name
+                    .get(&(schema.to_string(), name.to_string()))
+            })
+            .filter_map(|idx| self.types.get(*idx))
+            .collect()
+    }
+
+    /// Finds all overloads of a function, in search path order. If `schema` is set, only that
+    /// schema is searched.
+    pub fn find_functions(&self, name: &str, schema: Option<&str>) -> Vec<&Function> {
+        let index = self.index();
+        self.schemas_to_search(schema)
+            .into_iter()
+            .filter_map(|schema| {
+                index
+                    .functions_by_name
+                    .get(&(schema.to_string(), name.to_string()))
+            })
+            .flatten()
+            .filter_map(|idx| self.functions.get(*idx))
             .collect()
     }
+
+    pub fn find_function_by_id(&self, id: i64) -> Option<&Function> {
+        self.index()
+            .functions_by_id
+            .get(&id)
+            .and_then(|idx| self.functions.get(*idx))
+    }
+
+    /// Finds the cast from one type to another, if there is one.
+    pub fn find_cast(&self, source_type_id: i64, target_type_id: i64) -> Option<&Cast> {
+        self.index()
+            .casts_by_types
+            .get(&(source_type_id, target_type_id))
+            .and_then(|idx| self.casts.get(*idx))
+    }
+
+    /// Finds the statistics of a column of a table, materialized view or foreign table. Returns
+    /// `None` if the statistics were not loaded via [SchemaCache::load_column_stats] or the
+    /// relation was never analyzed.
+    pub fn find_column_stats(
+        &self,
+        column: &str,
+        table: &str,
+        schema: Option<&str>,
+    ) -> Option<&ColumnStats> {
+        let column = self.find_col(column, table, schema)?;
+        let column_stats = self.column_stats.as_ref()?;
+
+        self.index()
+            .column_stats_by_column
+            .get(&(column.table_oid, column.name.clone()))
+            .and_then(|idx| column_stats.get(*idx))
+    }
+}
+
+async fn load_search_path(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_file_scalar!("src/queries/search_path.sql")
+        .fetch_one(pool)
+        .await
 }
 
 pub trait SchemaCacheItem {
@@ -83,6 +588,7 @@ pub trait SchemaCacheItem {
 #[cfg(test)]
 mod tests {
     use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
 
     use crate::SchemaCache;
 
@@ -94,4 +600,138 @@ mod tests {
             .await
             .expect("Couldnt' load Schema Cache");
     }
+
+    #[tokio::test]
+    async fn resolves_names_via_search_path() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create schema other;
+
+            create table public.users (id serial primary key);
+            create table other.users (id serial primary key, email text);
+            create table other.posts (id serial primary key);
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        assert_eq!(cache.search_path, vec!["pg_catalog", "public"]);
+
+        assert_eq!(cache.find_table("users", None).unwrap().schema, "public");
+        assert_eq!(
+            cache.find_table("users", Some("other")).unwrap().schema,
+            "other"
+        );
+
+        // `other` is not on the search path
+        assert!(cache.find_table("posts", None).is_none());
+        assert!(cache.find_table("posts", Some("public")).is_none());
+
+        assert!(cache.find_col("email", "users", None).is_none());
+        assert!(cache.find_col("email", "users", Some("other")).is_some());
+
+        let int4 = cache.find_type("int4", None).unwrap();
+        assert_eq!(cache.find_type_by_id(int4.id).unwrap().name, "int4");
+        assert!(cache.find_type("int4", Some("public")).is_none());
+
+        assert!(!cache.find_functions("lower", None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn finds_columns_of_views() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create schema other;
+
+            create table public.users (id serial primary key, email text);
+            create view public.user_emails as select email from public.users;
+            create materialized view other.user_count as select count(*) as total from public.users;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let email = cache.find_col("email", "user_emails", None).unwrap();
+        assert_eq!(email.table_name, "user_emails");
+        assert!(cache.find_col("id", "user_emails", None).is_none());
+
+        assert!(cache.find_col("total", "user_count", None).is_none());
+        assert!(
+            cache
+                .find_col("total", "user_count", Some("other"))
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_partitions_and_inheritance() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.events (id int, created_at date) partition by range (created_at);
+            create table public.events_2024 partition of public.events
+                for values from ('2024-01-01') to ('2025-01-01');
+            create table public.events_default partition of public.events default;
+
+            create table public.cities (name text);
+            create table public.capitals (country text) inherits (public.cities);
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let events = cache.find_table("events", None).unwrap();
+        assert!(events.is_partitioned);
+        assert_eq!(events.partition_key.as_deref(), Some("RANGE (created_at)"));
+
+        let mut partitions: Vec<_> = cache
+            .find_child_tables(events.id)
+            .into_iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        partitions.sort();
+        assert_eq!(partitions, vec!["events_2024", "events_default"]);
+
+        let events_2024 = cache.find_table("events_2024", None).unwrap();
+        assert!(events_2024.is_partition);
+        assert_eq!(
+            events_2024.partition_bound.as_deref(),
+            Some("FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')")
+        );
+        assert_eq!(
+            cache.find_partition_root(events_2024.id).unwrap().id,
+            events.id
+        );
+
+        let capitals = cache.find_table("capitals", None).unwrap();
+        assert!(!capitals.is_partition);
+        assert_eq!(
+            cache
+                .find_parent_tables(capitals.id)
+                .into_iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["cities"]
+        );
+    }
 }
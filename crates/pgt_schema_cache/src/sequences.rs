@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sequence {
+    /// The Id (`oid`) of the sequence.
+    pub id: i64,
+
+    /// The name of the schema the sequence belongs to.
+    pub schema: String,
+
+    pub name: String,
+
+    /// The type of the sequence's values: `smallint`, `integer` or `bigint`.
+    pub data_type: String,
+
+    pub start_value: i64,
+
+    pub increment: i64,
+
+    pub min_value: i64,
+
+    pub max_value: i64,
+
+    /// How many values are preallocated per session.
+    pub cache_size: i64,
+
+    /// Does the sequence wrap around once it reaches its `max_value`?
+    pub is_cycled: bool,
+
+    /// The last value returned by `nextval`. `None` if the sequence was never used or the current
+    /// role is not allowed to read it.
+    pub last_value: Option<i64>,
+
+    /// The schema of the table that owns the sequence, e.g. via a `serial` or identity column.
+    pub owned_by_schema: Option<String>,
+
+    pub owned_by_table: Option<String>,
+
+    pub owned_by_column: Option<String>,
+
+    /// Does the sequence back an identity column?
+    pub is_identity: bool,
+
+    /// Comment inserted via `COMMENT ON SEQUENCE my_sequence IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for Sequence {
+    type Item = Sequence;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Sequence>, sqlx::Error> {
+        sqlx::query_file_as!(Sequence, "src/queries/sequences.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::SchemaCache;
+
+    #[tokio::test]
+    async fn loads_sequences() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                name text
+            );
+
+            create table public.orders (
+                id bigint generated always as identity (start with 100 increment by 10) primary key
+            );
+
+            create sequence public.invoice_numbers as smallint minvalue 1 maxvalue 999 cycle;
+
+            select nextval('public.invoice_numbers');
+            select nextval('public.invoice_numbers');
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let serial_seq = cache
+            .sequences()
+            .iter()
+            .find(|s| s.name == "users_id_seq")
+            .unwrap();
+        assert_eq!(serial_seq.schema, "public");
+        assert_eq!(serial_seq.data_type, "integer");
+        assert_eq!(serial_seq.owned_by_table, Some("users".into()));
+        assert_eq!(serial_seq.owned_by_column, Some("id".into()));
+        assert!(!serial_seq.is_identity);
+        assert_eq!(serial_seq.last_value, None);
+
+        let identity_seq = cache
+            .sequences()
+            .iter()
+            .find(|s| s.owned_by_table.as_deref() == Some("orders"))
+            .unwrap();
+        assert_eq!(identity_seq.data_type, "bigint");
+        assert_eq!(identity_seq.start_value, 100);
+        assert_eq!(identity_seq.increment, 10);
+        assert!(identity_seq.is_identity);
+
+        let standalone_seq = cache
+            .sequences()
+            .iter()
+            .find(|s| s.name == "invoice_numbers")
+            .unwrap();
+        assert_eq!(standalone_seq.data_type, "smallint");
+        assert_eq!(standalone_seq.min_value, 1);
+        assert_eq!(standalone_seq.max_value, 999);
+        assert!(standalone_seq.is_cycled);
+        assert_eq!(standalone_seq.last_value, Some(2));
+        assert_eq!(standalone_seq.owned_by_table, None);
+    }
+}
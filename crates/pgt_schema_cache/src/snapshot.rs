@@ -0,0 +1,140 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema_cache::SchemaCache;
+
+/// The version of the snapshot format.
+///
+/// Must be bumped whenever a change to the [SchemaCache] or its items would make older snapshots
+/// deserialize incorrectly, so they are rejected instead.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 8;
+
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    format_version: u32,
+    cache: &'a SchemaCache,
+}
+
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    format_version: u32,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    cache: SchemaCache,
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The snapshot was written with a different [SNAPSHOT_FORMAT_VERSION].
+    UnsupportedVersion { found: u32, expected: u32 },
+    /// The snapshot is not valid JSON, or does not match the shape of the [SchemaCache].
+    Json(serde_json::Error),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "Unsupported snapshot format version {found}, expected {expected}. Please recreate the snapshot."
+            ),
+            SnapshotError::Json(err) => write!(f, "Invalid snapshot: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(err: serde_json::Error) -> Self {
+        SnapshotError::Json(err)
+    }
+}
+
+impl SchemaCache {
+    /// Serializes the cache into a snapshot that can be loaded via [SchemaCache::from_snapshot]
+    /// without connecting to a database.
+    pub fn to_snapshot(&self) -> Result<String, SnapshotError> {
+        let snapshot = SnapshotRef {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            cache: self,
+        };
+
+        Ok(serde_json::to_string(&snapshot)?)
+    }
+
+    pub fn from_snapshot(snapshot: &str) -> Result<SchemaCache, SnapshotError> {
+        // check the version first, so that outdated snapshots produce a helpful error instead of a
+        // deserialization error somewhere in the cache
+        let header: SnapshotHeader = serde_json::from_str(snapshot)?;
+        if header.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: header.format_version,
+                expected: SNAPSHOT_FORMAT_VERSION,
+            });
+        }
+
+        let snapshot: Snapshot = serde_json::from_str(snapshot)?;
+        Ok(snapshot.cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{
+        SchemaCache,
+        snapshot::{SNAPSHOT_FORMAT_VERSION, SnapshotError},
+    };
+
+    #[tokio::test]
+    async fn roundtrips_snapshots() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                name text
+            );
+
+            create view public.user_names as select name from public.users;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let snapshot = cache.to_snapshot().unwrap();
+        let loaded = SchemaCache::from_snapshot(&snapshot).unwrap();
+
+        assert_eq!(loaded.tables(), cache.tables());
+        assert_eq!(loaded.columns(), cache.columns());
+        assert_eq!(loaded.views(), cache.views());
+        assert_eq!(loaded.functions().len(), cache.functions().len());
+        assert_eq!(loaded.types().len(), cache.types().len());
+        assert!(loaded.find_col("name", "users", Some("public")).is_some());
+    }
+
+    #[test]
+    fn rejects_other_format_versions() {
+        let snapshot = format!(
+            r#"{{ "format_version": {}, "cache": {{}} }}"#,
+            SNAPSHOT_FORMAT_VERSION + 1
+        );
+
+        assert!(matches!(
+            SchemaCache::from_snapshot(&snapshot),
+            Err(SnapshotError::UnsupportedVersion { .. })
+        ));
+    }
+}
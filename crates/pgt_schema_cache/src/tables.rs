@@ -1,8 +1,9 @@
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 
 use crate::schema_cache::SchemaCacheItem;
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ReplicaIdentity {
     #[default]
     Default,
@@ -23,7 +24,32 @@ impl From<String> for ReplicaIdentity {
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The kind of a relation, see `relkind` in `pg_class`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TableKind {
+    #[default]
+    Ordinary,
+    /// A table whose rows are stored in partitions, see [Table::partition_key].
+    Partitioned,
+    /// A view. Its definition and dependencies are in [crate::View].
+    View,
+    /// A materialized view. Its definition and dependencies are in [crate::View].
+    MaterializedView,
+}
+
+impl From<String> for TableKind {
+    fn from(relkind: String) -> Self {
+        match relkind.as_str() {
+            "p" => TableKind::Partitioned,
+            "v" => TableKind::View,
+            "m" => TableKind::MaterializedView,
+            // the query only loads tables, partitioned tables, views and materialized views
+            _ => TableKind::Ordinary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Table {
     pub id: i64,
     pub schema: String,
@@ -36,6 +62,31 @@ pub struct Table {
     pub live_rows_estimate: i64,
     pub dead_rows_estimate: i64,
     pub comment: Option<String>,
+
+    pub kind: TableKind,
+
+    /// Whether the rows of the table are stored in partitions, see [Table::partition_key].
+    pub is_partitioned: bool,
+
+    /// The partition key of a partitioned table, e.g. `RANGE (created_at)`.
+    pub partition_key: Option<String>,
+
+    /// Whether the table is a partition of the table in [Table::parent_ids].
+    pub is_partition: bool,
+
+    /// The bound of a partition, e.g. `FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')`.
+    pub partition_bound: Option<String>,
+
+    /// The ids of the tables this table inherits from, in the order they were declared. A
+    /// partition has exactly one parent.
+    pub parent_ids: Vec<i64>,
+}
+
+impl Table {
+    /// Whether the relation is a view or a materialized view rather than a table.
+    pub fn is_view(&self) -> bool {
+        matches!(self.kind, TableKind::View | TableKind::MaterializedView)
+    }
 }
 
 impl SchemaCacheItem for Table {
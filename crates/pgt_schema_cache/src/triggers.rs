@@ -0,0 +1,311 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+impl From<String> for TriggerTiming {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "BEFORE" => TriggerTiming::Before,
+            "AFTER" => TriggerTiming::After,
+            "INSTEAD OF" => TriggerTiming::InsteadOf,
+            _ => panic!("Invalid trigger timing"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerLevel {
+    /// `FOR EACH ROW`
+    Row,
+    /// `FOR EACH STATEMENT`
+    Statement,
+}
+
+impl From<String> for TriggerLevel {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "ROW" => TriggerLevel::Row,
+            "STATEMENT" => TriggerLevel::Statement,
+            _ => panic!("Invalid trigger level"),
+        }
+    }
+}
+
+/// Controls in which `session_replication_role` modes a trigger fires.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TriggerEnabled {
+    /// Fires in "origin" and "local" modes. This is the default for new triggers.
+    #[default]
+    Origin,
+    /// Fires only in "replica" mode.
+    Replica,
+    /// Fires in all modes.
+    Always,
+    Disabled,
+}
+
+impl From<String> for TriggerEnabled {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "O" => TriggerEnabled::Origin,
+            "R" => TriggerEnabled::Replica,
+            "A" => TriggerEnabled::Always,
+            "D" => TriggerEnabled::Disabled,
+            _ => panic!("Invalid trigger enabled state"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trigger {
+    /// The Id (`oid`) of the trigger.
+    pub id: i64,
+
+    /// The name of the schema the table of the trigger belongs to.
+    pub schema: String,
+
+    /// The Id (`oid`) of the table (or view) the trigger is defined on.
+    pub table_id: i64,
+
+    pub table_name: String,
+
+    pub name: String,
+
+    pub timing: TriggerTiming,
+
+    pub level: TriggerLevel,
+
+    pub on_insert: bool,
+
+    pub on_update: bool,
+
+    pub on_delete: bool,
+
+    pub on_truncate: bool,
+
+    /// The columns listed in `UPDATE OF col1, col2`. Empty if the trigger fires on updates of any
+    /// column.
+    pub update_columns: Vec<String>,
+
+    /// The Id (`oid`) of the trigger function.
+    pub function_id: i64,
+
+    pub function_schema: String,
+
+    pub function_name: String,
+
+    pub enabled: TriggerEnabled,
+
+    /// The condition of the `WHEN (...)` clause, if present.
+    pub when_clause: Option<String>,
+
+    /// The full `CREATE TRIGGER ...` statement.
+    pub definition: String,
+
+    /// Comment inserted via `COMMENT ON TRIGGER my_trigger ON my_table IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for Trigger {
+    type Item = Trigger;
+
+    async fn load(pool: &PgPool) -> Result<Vec<Trigger>, sqlx::Error> {
+        sqlx::query_file_as!(Trigger, "src/queries/triggers.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventTrigger {
+    /// The Id (`oid`) of the event trigger.
+    pub id: i64,
+
+    pub name: String,
+
+    /// The event the trigger fires on, e.g. `ddl_command_start` or `sql_drop`.
+    pub event: String,
+
+    /// The Id (`oid`) of the trigger function.
+    pub function_id: i64,
+
+    pub function_schema: String,
+
+    pub function_name: String,
+
+    pub enabled: TriggerEnabled,
+
+    /// The command tags listed in `WHEN TAG IN (...)`. Empty if the trigger fires for all commands.
+    pub tags: Vec<String>,
+
+    /// Comment inserted via `COMMENT ON EVENT TRIGGER my_trigger IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl SchemaCacheItem for EventTrigger {
+    type Item = EventTrigger;
+
+    async fn load(pool: &PgPool) -> Result<Vec<EventTrigger>, sqlx::Error> {
+        sqlx::query_file_as!(EventTrigger, "src/queries/event_triggers.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{
+        SchemaCache,
+        triggers::{TriggerEnabled, TriggerLevel, TriggerTiming},
+    };
+
+    #[tokio::test]
+    async fn loads_triggers() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                name text,
+                updated_at timestamptz
+            );
+
+            create or replace function public.set_updated_at()
+            returns trigger
+            language plpgsql
+            as $$
+            begin
+                new.updated_at = now();
+                return new;
+            end;
+            $$;
+
+            create or replace function public.audit()
+            returns trigger
+            language plpgsql
+            as $$
+            begin
+                return null;
+            end;
+            $$;
+
+            create trigger users_set_updated_at
+                before update of name on public.users
+                for each row
+                when (old.name is distinct from new.name)
+                execute function public.set_updated_at();
+
+            create trigger users_audit
+                after insert or delete on public.users
+                for each statement
+                execute function public.audit();
+
+            alter table public.users disable trigger users_audit;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let user_triggers: Vec<_> = cache
+            .triggers()
+            .iter()
+            .filter(|t| t.table_name == "users")
+            .collect();
+
+        assert_eq!(user_triggers.len(), 2);
+
+        let updated_at_trigger = user_triggers
+            .iter()
+            .find(|t| t.name == "users_set_updated_at")
+            .unwrap();
+        assert_eq!(updated_at_trigger.schema, "public");
+        assert_eq!(updated_at_trigger.timing, TriggerTiming::Before);
+        assert_eq!(updated_at_trigger.level, TriggerLevel::Row);
+        assert!(updated_at_trigger.on_update);
+        assert!(!updated_at_trigger.on_insert);
+        assert!(!updated_at_trigger.on_delete);
+        assert!(!updated_at_trigger.on_truncate);
+        assert_eq!(updated_at_trigger.update_columns, vec!["name".to_string()]);
+        assert_eq!(updated_at_trigger.function_schema, "public");
+        assert_eq!(updated_at_trigger.function_name, "set_updated_at");
+        assert_eq!(updated_at_trigger.enabled, TriggerEnabled::Origin);
+        assert_eq!(
+            updated_at_trigger.when_clause,
+            Some("(old.name IS DISTINCT FROM new.name)".into())
+        );
+
+        let audit_trigger = user_triggers
+            .iter()
+            .find(|t| t.name == "users_audit")
+            .unwrap();
+        assert_eq!(audit_trigger.timing, TriggerTiming::After);
+        assert_eq!(audit_trigger.level, TriggerLevel::Statement);
+        assert!(audit_trigger.on_insert);
+        assert!(audit_trigger.on_delete);
+        assert!(!audit_trigger.on_update);
+        assert!(audit_trigger.update_columns.is_empty());
+        assert_eq!(audit_trigger.enabled, TriggerEnabled::Disabled);
+        assert_eq!(audit_trigger.when_clause, None);
+    }
+
+    #[tokio::test]
+    async fn loads_event_triggers() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create or replace function public.log_ddl()
+            returns event_trigger
+            language plpgsql
+            as $$
+            begin
+                raise notice 'ddl';
+            end;
+            $$;
+
+            create event trigger log_table_ddl
+                on ddl_command_end
+                when tag in ('CREATE TABLE', 'ALTER TABLE')
+                execute function public.log_ddl();
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let event_trigger = cache
+            .event_triggers()
+            .iter()
+            .find(|t| t.name == "log_table_ddl")
+            .unwrap();
+
+        assert_eq!(event_trigger.event, "ddl_command_end");
+        assert_eq!(event_trigger.function_name, "log_ddl");
+        assert_eq!(event_trigger.enabled, TriggerEnabled::Origin);
+        assert_eq!(
+            event_trigger.tags,
+            vec!["CREATE TABLE".to_string(), "ALTER TABLE".to_string()]
+        );
+    }
+}
@@ -1,18 +1,18 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use sqlx::types::JsonValue;
 
 use crate::schema_cache::SchemaCacheItem;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TypeAttributes {
-    attrs: Vec<PostgresTypeAttribute>,
+    pub attrs: Vec<PostgresTypeAttribute>,
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct PostgresTypeAttribute {
-    name: String,
-    type_id: i64,
+    pub name: String,
+    pub type_id: i64,
 }
 
 impl From<Option<JsonValue>> for TypeAttributes {
@@ -23,7 +23,7 @@ impl From<Option<JsonValue>> for TypeAttributes {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Enums {
     pub values: Vec<String>,
 }
@@ -36,7 +36,61 @@ impl From<Option<JsonValue>> for Enums {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+/// The kind of a type, see `typtype` in `pg_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TypeKind {
+    #[default]
+    Base,
+    /// A row type with [PostgresType::attributes], e.g. created via `create type .. as (..)`.
+    Composite,
+    /// A [PostgresType::base_type_id] with optional constraints.
+    Domain,
+    Enum,
+    /// A range over [PostgresType::range_subtype_id], e.g. `int4range`.
+    Range,
+    /// A set of non-overlapping ranges over [PostgresType::range_subtype_id].
+    Multirange,
+    /// A pseudo-type such as `anyelement`, `record` or `trigger`.
+    Pseudo,
+}
+
+impl From<String> for TypeKind {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "BASE" => TypeKind::Base,
+            "COMPOSITE" => TypeKind::Composite,
+            "DOMAIN" => TypeKind::Domain,
+            "ENUM" => TypeKind::Enum,
+            "RANGE" => TypeKind::Range,
+            "MULTIRANGE" => TypeKind::Multirange,
+            "PSEUDO" => TypeKind::Pseudo,
+            _ => panic!("Invalid type kind"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainConstraint {
+    pub name: String,
+
+    /// The constraint as it would appear in `create domain`, e.g. `CHECK ((VALUE > 0))`.
+    pub definition: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DomainConstraints {
+    pub constraints: Vec<DomainConstraint>,
+}
+
+impl From<Option<JsonValue>> for DomainConstraints {
+    fn from(s: Option<JsonValue>) -> Self {
+        let constraints: Vec<DomainConstraint> =
+            serde_json::from_value(s.unwrap_or(JsonValue::Array(vec![]))).unwrap();
+        DomainConstraints { constraints }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PostgresType {
     pub id: i64,
     pub name: String,
@@ -45,6 +99,27 @@ pub struct PostgresType {
     pub enums: Enums,
     pub attributes: TypeAttributes,
     pub comment: Option<String>,
+    /// The name of the extension that installed the type, if any.
+    pub extension: Option<String>,
+
+    pub kind: TypeKind,
+
+    /// The type a domain is based on. `None` for other kinds of types.
+    pub base_type_id: Option<i64>,
+
+    /// Whether a domain rejects `null` values.
+    pub not_null: bool,
+
+    /// The `CHECK` constraints of a domain.
+    pub domain_constraints: DomainConstraints,
+
+    /// The type of the values of a range or multirange, e.g. `int4` for `int4range`. `None` for
+    /// other kinds of types.
+    pub range_subtype_id: Option<i64>,
+
+    /// Whether the type is preferred within its type category, e.g. `text` for strings, when
+    /// resolving overloaded functions and operators.
+    pub is_preferred: bool,
 }
 
 impl SchemaCacheItem for PostgresType {
@@ -56,3 +131,77 @@ impl SchemaCacheItem for PostgresType {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{SchemaCache, types::TypeKind};
+
+    #[tokio::test]
+    async fn loads_domains_ranges_and_composite_types() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create domain public.positive as int not null check (value > 0);
+
+            create domain public.small_positive as public.positive
+                constraint below_hundred check (value < 100);
+
+            create type public.address as (street text, zip public.positive);
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let int4 = cache.find_type("int4", Some("pg_catalog")).unwrap();
+        let positive = cache.find_type("positive", Some("public")).unwrap();
+        assert_eq!(positive.kind, TypeKind::Domain);
+        assert_eq!(positive.base_type_id, Some(int4.id));
+        assert!(positive.not_null);
+        assert_eq!(
+            positive.domain_constraints.constraints[0].definition,
+            "CHECK ((VALUE > 0))"
+        );
+
+        let small_positive = cache.find_type("small_positive", Some("public")).unwrap();
+        assert_eq!(small_positive.base_type_id, Some(positive.id));
+        assert_eq!(
+            small_positive.domain_constraints.constraints[0].name,
+            "below_hundred"
+        );
+        assert_eq!(
+            cache.find_base_type(small_positive.id).map(|t| t.id),
+            Some(int4.id)
+        );
+
+        let address = cache.find_type("address", Some("public")).unwrap();
+        assert_eq!(address.kind, TypeKind::Composite);
+        assert_eq!(address.attributes.attrs[1].name, "zip");
+        assert_eq!(address.attributes.attrs[1].type_id, positive.id);
+
+        let int4range = cache.find_type("int4range", Some("pg_catalog")).unwrap();
+        assert_eq!(int4range.kind, TypeKind::Range);
+        assert_eq!(int4range.range_subtype_id, Some(int4.id));
+
+        let int4multirange = cache
+            .find_type("int4multirange", Some("pg_catalog"))
+            .unwrap();
+        assert_eq!(int4multirange.kind, TypeKind::Multirange);
+        assert_eq!(int4multirange.range_subtype_id, Some(int4.id));
+
+        let record = cache.find_type("record", Some("pg_catalog")).unwrap();
+        assert_eq!(record.kind, TypeKind::Pseudo);
+
+        let text = cache.find_type("text", Some("pg_catalog")).unwrap();
+        assert!(text.is_preferred);
+        assert!(!int4.is_preferred);
+    }
+}
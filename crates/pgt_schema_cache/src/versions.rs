@@ -1,8 +1,9 @@
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 
 use crate::schema_cache::SchemaCacheItem;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Version {
     pub version: Option<String>,
     pub version_num: Option<i64>,
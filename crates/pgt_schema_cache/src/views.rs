@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+use crate::schema_cache::SchemaCacheItem;
+
+/// A column of another relation that a view references.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ViewColumnDependency {
+    /// The Id (`oid`) of the referenced table or view.
+    pub table_id: i64,
+    pub schema: String,
+    pub table_name: String,
+    pub column_name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewColumnDependencies {
+    pub columns: Vec<ViewColumnDependency>,
+}
+
+/// The dependencies are loaded as JSON, which is decoded by `sqlx`, so that a malformed value
+/// fails the query instead of panicking.
+impl From<Json<Vec<ViewColumnDependency>>> for ViewColumnDependencies {
+    fn from(Json(columns): Json<Vec<ViewColumnDependency>>) -> Self {
+        ViewColumnDependencies { columns }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct View {
+    /// The Id (`oid`) of the view.
+    pub id: i64,
+
+    /// The name of the schema the view belongs to.
+    pub schema: String,
+
+    pub name: String,
+
+    pub is_materialized: bool,
+
+    /// `false` for materialized views created `WITH NO DATA` that were never refreshed.
+    /// Always `true` for regular views.
+    pub is_populated: bool,
+
+    /// The `SELECT` statement of the view, as reconstructed by Postgres.
+    pub definition: String,
+
+    /// The columns of other tables and views that the view references.
+    pub column_dependencies: ViewColumnDependencies,
+
+    /// Comment inserted via `COMMENT ON VIEW my_view IS '...'`, if present.
+    pub comment: Option<String>,
+}
+
+impl View {
+    /// Whether the view references the given column.
+    pub fn depends_on_column(&self, schema: &str, table: &str, column: &str) -> bool {
+        self.column_dependencies
+            .columns
+            .iter()
+            .any(|c| c.schema == schema && c.table_name == table && c.column_name == column)
+    }
+}
+
+impl SchemaCacheItem for View {
+    type Item = View;
+
+    async fn load(pool: &PgPool) -> Result<Vec<View>, sqlx::Error> {
+        sqlx::query_file_as!(View, "src/queries/views.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_test_utils::test_database::get_new_test_db;
+    use sqlx::Executor;
+
+    use crate::{SchemaCache, TableKind};
+
+    #[tokio::test]
+    async fn loads_views() {
+        let test_db = get_new_test_db().await;
+
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                name text,
+                email text,
+                is_admin bool not null default false
+            );
+
+            create view public.admins as
+                select id, name from public.users where is_admin;
+
+            create materialized view public.user_emails as
+                select email from public.users
+                with no data;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let admins = cache
+            .views()
+            .iter()
+            .find(|v| v.name == "admins" && v.schema == "public")
+            .unwrap();
+        assert!(!admins.is_materialized);
+        assert!(admins.is_populated);
+        assert!(admins.definition.contains("FROM users"));
+        assert_eq!(
+            admins
+                .column_dependencies
+                .columns
+                .iter()
+                .map(|c| c.column_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "is_admin", "name"]
+        );
+        assert!(admins.depends_on_column("public", "users", "is_admin"));
+        assert!(!admins.depends_on_column("public", "users", "email"));
+
+        let user_emails = cache
+            .views()
+            .iter()
+            .find(|v| v.name == "user_emails")
+            .unwrap();
+        assert!(user_emails.is_materialized);
+        assert!(!user_emails.is_populated);
+        assert!(user_emails.depends_on_column("public", "users", "email"));
+        assert!(!user_emails.depends_on_column("public", "users", "id"));
+
+        // views are relations of their own kind, so they can be looked up like tables
+        let users = cache.find_table("users", None).unwrap();
+        assert_eq!(users.kind, TableKind::Ordinary);
+        assert!(!users.is_view());
+
+        let admins = cache.find_table("admins", None).unwrap();
+        assert_eq!(admins.kind, TableKind::View);
+        assert!(admins.is_view());
+
+        let user_emails = cache.find_table("user_emails", None).unwrap();
+        assert_eq!(user_emails.kind, TableKind::MaterializedView);
+    }
+}
@@ -1,33 +1,110 @@
-use pgt_schema_cache::{Function, SchemaCache};
+use pgt_query_ext::NodeEnum;
+use pgt_schema_cache::{Function, FunctionArg, SchemaCache};
 
 use crate::{
-    types::{PossibleType, resolve_type},
+    types::{
+        PossibleType, base_type_id, can_coerce_implicitly, element_type_id, is_preferred,
+        resolve_type,
+    },
     util::get_string_from_node,
 };
 
+/// The outcome of resolving a function call against the functions in the schema cache.
+#[derive(Debug)]
+pub enum FuncCallResolution<'a> {
+    /// Exactly one function matches the call best.
+    Resolved(&'a Function),
+    /// Several functions match the call equally well, so Postgres rejects it as ambiguous.
+    Ambiguous(Vec<&'a Function>),
+    /// No function with this name accepts the arguments, so Postgres reports that the function
+    /// does not exist.
+    NoMatch,
+}
+
+impl<'a> FuncCallResolution<'a> {
+    /// The function the call resolves to, if it is neither ambiguous nor without a match.
+    pub fn function(&self) -> Option<&'a Function> {
+        match self {
+            FuncCallResolution::Resolved(function) => Some(function),
+            _ => None,
+        }
+    }
+}
+
+struct CallArg {
+    /// Set if the argument is passed in named notation, e.g. `make_interval(days => 1)`.
+    name: Option<String>,
+    input: PossibleType,
+}
+
+/// A function that accepts the call, along with the declared type each argument is passed as.
+struct Candidate<'a> {
+    function: &'a Function,
+    param_types: Vec<i64>,
+}
+
+/// Resolves a function call the way Postgres does: the overloads that accept the number and names
+/// of the arguments are narrowed down to those the arguments can be implicitly cast to, and the
+/// one with the most exact and preferred type matches is chosen.
+///
+/// See https://www.postgresql.org/docs/current/typeconv-func.html
 pub fn resolve_func_call<'b>(
     node: &pgt_query_ext::protobuf::FuncCall,
     schema_cache: &'b SchemaCache,
-) -> Option<&'b Function> {
+) -> FuncCallResolution<'b> {
     let (schema, name) = resolve_func_identifier(node);
 
-    let fns = schema_cache
-        .functions
+    let args: Vec<CallArg> = node
+        .args
         .iter()
-        .filter(|f| {
-            function_matches(
-                f,
-                schema.as_deref(),
-                name.as_str(),
-                node.args
-                    .iter()
-                    .map(|a| resolve_type(a.node.as_ref().unwrap(), schema_cache))
-                    .collect(),
-            )
+        .filter_map(|a| a.node.as_ref())
+        .map(|n| match n {
+            NodeEnum::NamedArgExpr(named) => CallArg {
+                name: Some(named.name.clone()),
+                input: named
+                    .arg
+                    .as_ref()
+                    .and_then(|a| a.node.as_ref())
+                    .map(|n| resolve_type(n, schema_cache))
+                    .unwrap_or(PossibleType::Unresolved),
+            },
+            n => CallArg {
+                name: None,
+                input: resolve_type(n, schema_cache),
+            },
         })
-        .collect::<Vec<&Function>>();
+        .map(|arg| match arg.input {
+            // domains are treated as their base type when resolving the call
+            PossibleType::Known(type_id) => CallArg {
+                input: PossibleType::Known(base_type_id(type_id, schema_cache)),
+                ..arg
+            },
+            _ => arg,
+        })
+        .collect();
+
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for function in schema_cache.find_functions(name.as_str(), schema.as_deref()) {
+        let Some(param_types) = match_params(function, &args, node.func_variadic, schema_cache)
+        else {
+            continue;
+        };
+
+        // a function with the same signature later on the search path is hidden by the earlier one
+        if candidates
+            .iter()
+            .any(|c| input_type_ids(c.function) == input_type_ids(function))
+        {
+            continue;
+        }
+
+        candidates.push(Candidate {
+            function,
+            param_types,
+        });
+    }
 
-    if fns.len() == 1 { Some(fns[0]) } else { None }
+    select_candidate(candidates, &args, schema_cache)
 }
 
 fn resolve_func_identifier(node: &pgt_query_ext::protobuf::FuncCall) -> (Option<String>, String) {
@@ -41,48 +118,286 @@ fn resolve_func_identifier(node: &pgt_query_ext::protobuf::FuncCall) -> (Option<
     }
 }
 
-fn function_matches(
+fn input_params(func: &Function) -> Vec<&FunctionArg> {
+    func.args
+        .args
+        .iter()
+        .filter(|a| matches!(a.mode.as_str(), "in" | "inout" | "variadic"))
+        .collect()
+}
+
+fn input_type_ids(func: &Function) -> Vec<i64> {
+    input_params(func).iter().map(|a| a.type_id).collect()
+}
+
+/// Maps the arguments of the call to the parameters of the function. Returns the declared type of
+/// each argument, or `None` if the function does not accept the call.
+fn match_params(
     func: &Function,
-    schema: Option<&str>,
-    name: &str,
-    arg_types: Vec<PossibleType>,
-) -> bool {
-    if func.name != name {
-        return false;
-    }
+    args: &[CallArg],
+    explicit_variadic: bool,
+    schema_cache: &SchemaCache,
+) -> Option<Vec<i64>> {
+    let params = input_params(func);
 
-    if schema.is_some() && Some(func.schema.as_str()) != schema {
-        return false;
+    // named arguments must come after all positional ones
+    let positional = args.iter().take_while(|a| a.name.is_none()).count();
+    if args[positional..].iter().any(|a| a.name.is_none()) {
+        return None;
     }
 
-    let arg_count = arg_types.len();
-    let args_with_default = func
-        .args
-        .args
-        .iter()
-        .filter(|a| a.has_default.is_some())
-        .count();
-    let total_args = func.args.args.len();
+    // unless called with `VARIADIC`, the variadic parameter accepts any number of elements
+    let variadic = params
+        .last()
+        .filter(|p| p.mode == "variadic" && !explicit_variadic && positional == args.len());
 
-    if total_args < arg_count || total_args - args_with_default > arg_count {
-        return false;
-    }
+    let mut param_types = Vec::with_capacity(args.len());
+    let mut filled = vec![false; params.len()];
 
-    for (i, (func_arg, possible_type)) in func.args.args.iter().zip(arg_types.iter()).enumerate() {
-        match possible_type {
-            PossibleType::Null => {
-                // can be any type
+    for idx in 0..positional {
+        match (params.get(idx), variadic) {
+            (Some(param), Some(_)) if idx == params.len() - 1 => {
+                param_types.push(element_type_id(param.type_id, schema_cache));
             }
-            PossibleType::AnyOf(types) => {
-                if types.iter().all(|type_id| *type_id != func_arg.type_id) {
-                    return false;
-                }
+            (Some(param), _) => param_types.push(param.type_id),
+            (None, Some(variadic)) => {
+                param_types.push(element_type_id(variadic.type_id, schema_cache));
             }
+            (None, None) => return None,
         }
 
-        if i >= arg_count && !func_arg.has_default.unwrap_or(false) {
-            return false;
+        if idx < filled.len() {
+            filled[idx] = true;
         }
     }
-    true
+
+    for arg in &args[positional..] {
+        let name = arg.name.as_deref()?;
+        let idx = params.iter().position(|p| p.name == name)?;
+        if filled[idx] {
+            return None;
+        }
+
+        filled[idx] = true;
+        param_types.push(params[idx].type_id);
+    }
+
+    let all_required_filled = params
+        .iter()
+        .zip(filled)
+        .all(|(param, filled)| filled || param.has_default == Some(true));
+
+    all_required_filled.then_some(param_types)
+}
+
+fn select_candidate<'a>(
+    mut candidates: Vec<Candidate<'a>>,
+    args: &[CallArg],
+    schema_cache: &SchemaCache,
+) -> FuncCallResolution<'a> {
+    let is_exact = |arg: &CallArg, param_type: &i64| arg.input == PossibleType::Known(*param_type);
+
+    if let Some(exact) = candidates
+        .iter()
+        .find(|c| args.iter().zip(&c.param_types).all(|(a, p)| is_exact(a, p)))
+    {
+        return FuncCallResolution::Resolved(exact.function);
+    }
+
+    candidates.retain(|c| {
+        args.iter()
+            .zip(&c.param_types)
+            .all(|(a, p)| can_coerce_implicitly(&a.input, *p, schema_cache))
+    });
+
+    // prefer the candidates that need the fewest conversions
+    retain_best(&mut candidates, |c| {
+        args.iter()
+            .zip(&c.param_types)
+            .filter(|(a, p)| is_exact(a, p))
+            .count()
+    });
+
+    // then the ones that accept preferred types where a conversion is needed, e.g. `text` for
+    // string literals
+    retain_best(&mut candidates, |c| {
+        args.iter()
+            .zip(&c.param_types)
+            .filter(|(a, p)| {
+                matches!(a.input, PossibleType::Unknown | PossibleType::Known(_))
+                    && !is_exact(a, p)
+                    && is_preferred(**p, schema_cache)
+            })
+            .count()
+    });
+
+    match candidates.len() {
+        0 => FuncCallResolution::NoMatch,
+        1 => FuncCallResolution::Resolved(candidates[0].function),
+        _ => FuncCallResolution::Ambiguous(candidates.into_iter().map(|c| c.function).collect()),
+    }
+}
+
+fn retain_best(candidates: &mut Vec<Candidate>, score: impl Fn(&Candidate) -> usize) {
+    let best = candidates.iter().map(&score).max().unwrap_or_default();
+    candidates.retain(|c| score(c) == best);
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_query_ext::NodeEnum;
+    use pgt_schema_cache::SchemaCache;
+
+    use super::{FuncCallResolution, resolve_func_call};
+
+    fn resolve<'a>(schema_cache: &'a SchemaCache, expr: &str) -> FuncCallResolution<'a> {
+        let root = pgt_query_ext::parse(&format!("select {};", expr)).unwrap();
+        let NodeEnum::SelectStmt(select) = root else {
+            panic!("expected a select statement");
+        };
+        let Some(NodeEnum::ResTarget(target)) = &select.target_list[0].node else {
+            panic!("expected a target");
+        };
+        let Some(NodeEnum::FuncCall(call)) = target.val.as_ref().and_then(|v| v.node.as_ref())
+        else {
+            panic!("expected a function call");
+        };
+
+        resolve_func_call(call, schema_cache)
+    }
+
+    fn cache_with(sql: &str) -> SchemaCache {
+        let mut cache = SchemaCache::builtin();
+        for stmt in sql.split(';').filter(|s| !s.trim().is_empty()) {
+            cache.mutate(&pgt_query_ext::parse(stmt).unwrap());
+        }
+        cache
+    }
+
+    #[test]
+    fn prefers_exact_and_preferred_types() {
+        let cache = SchemaCache::builtin();
+
+        let abs = resolve(&cache, "abs(1)").function().unwrap();
+        assert_eq!(abs.identity_argument_types.as_deref(), Some("integer"));
+
+        // `integer` can be cast to both `double precision` and `numeric`, but the former is
+        // the preferred numeric type
+        let round = resolve(&cache, "round(1)").function().unwrap();
+        assert_eq!(round.return_type, "double precision");
+
+        let round = resolve(&cache, "round(1.5)").function().unwrap();
+        assert_eq!(round.return_type, "numeric");
+
+        // integers are `bigint` as long as they fit, and `numeric` otherwise
+        let round = resolve(&cache, "round(9223372036854775807)")
+            .function()
+            .unwrap();
+        assert_eq!(round.return_type, "double precision");
+
+        let round = resolve(&cache, "round(9223372036854775808)")
+            .function()
+            .unwrap();
+        assert_eq!(round.return_type, "numeric");
+
+        // string literals are `unknown`, which prefers `text`
+        let length = resolve(&cache, "length('abc')").function().unwrap();
+        assert_eq!(length.identity_argument_types.as_deref(), Some("text"));
+
+        let length = resolve(&cache, "length('abc'::bytea)").function().unwrap();
+        assert_eq!(length.identity_argument_types.as_deref(), Some("bytea"));
+    }
+
+    #[test]
+    fn expands_variadic_arguments() {
+        let cache = SchemaCache::builtin();
+
+        let format = resolve(&cache, "format('%s')").function().unwrap();
+        assert_eq!(format.args.args.len(), 1);
+
+        let format = resolve(&cache, "format('%s, %s', 1, true)")
+            .function()
+            .unwrap();
+        assert_eq!(format.args.args.len(), 2);
+
+        assert!(
+            resolve(&cache, "concat_ws(',', 'a', 2, now())")
+                .function()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn matches_defaults_and_named_arguments() {
+        let cache = cache_with(
+            "create function public.greet(name text, greeting text default 'hello') returns text language sql as $$ select greeting || name $$",
+        );
+
+        assert!(resolve(&cache, "greet('world')").function().is_some());
+        assert!(resolve(&cache, "greet('world', 'hi')").function().is_some());
+        assert!(
+            resolve(&cache, "greet(greeting => 'hi', name => 'world')")
+                .function()
+                .is_some()
+        );
+
+        assert!(matches!(
+            resolve(&cache, "greet()"),
+            FuncCallResolution::NoMatch
+        ));
+        assert!(matches!(
+            resolve(&cache, "greet(nme => 'world')"),
+            FuncCallResolution::NoMatch
+        ));
+        assert!(matches!(
+            resolve(&cache, "greet('world', 'hi', 'again')"),
+            FuncCallResolution::NoMatch
+        ));
+    }
+
+    #[test]
+    fn only_polymorphic_pseudo_types_accept_any_type() {
+        let cache = cache_with(
+            "create function public.first(a anyelement) returns int language sql as $$ select 1 $$;
+            create function public.to_rows(a record) returns int language sql as $$ select 1 $$",
+        );
+
+        assert!(resolve(&cache, "first(1)").function().is_some());
+        assert!(matches!(
+            resolve(&cache, "to_rows(1)"),
+            FuncCallResolution::NoMatch
+        ));
+    }
+
+    #[test]
+    fn passes_domains_as_their_base_type() {
+        let cache = cache_with("create domain public.positive as int check (value > 0)");
+
+        let abs = resolve(&cache, "abs(1::positive)").function().unwrap();
+        assert_eq!(abs.identity_argument_types.as_deref(), Some("integer"));
+    }
+
+    #[test]
+    fn reports_ambiguous_and_missing_functions() {
+        let cache = cache_with(
+            "create function public.f(a bigint) returns int language sql as $$ select 1 $$;
+            create function public.f(a numeric) returns int language sql as $$ select 2 $$",
+        );
+
+        let FuncCallResolution::Ambiguous(functions) = resolve(&cache, "f(1)") else {
+            panic!("expected an ambiguous call");
+        };
+        assert_eq!(functions.len(), 2);
+
+        assert!(resolve(&cache, "f(1::bigint)").function().is_some());
+
+        assert!(matches!(
+            resolve(&cache, "abs(true)"),
+            FuncCallResolution::NoMatch
+        ));
+        assert!(matches!(
+            resolve(&cache, "does_not_exist(1)"),
+            FuncCallResolution::NoMatch
+        ));
+    }
 }
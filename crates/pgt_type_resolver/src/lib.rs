@@ -2,4 +2,4 @@ mod functions;
 mod types;
 mod util;
 
-pub use functions::resolve_func_call;
+pub use functions::{FuncCallResolution, resolve_func_call};
@@ -1,79 +1,158 @@
-use pgt_schema_cache::SchemaCache;
+use pgt_query_ext::{NodeEnum, protobuf::a_const::Val};
+use pgt_schema_cache::{CastContext, SchemaCache, TypeKind};
 
+use crate::{functions::resolve_func_call, util::get_string_from_node};
+
+/// The polymorphic pseudo-types, which accept values of any type, or of any array, enum or range
+/// type. Other pseudo-types such as `record`, `cstring` or `trigger` only accept specific values.
+const POLYMORPHIC_TYPES: [&str; 12] = [
+    "any",
+    "anyelement",
+    "anyarray",
+    "anynonarray",
+    "anyenum",
+    "anyrange",
+    "anymultirange",
+    "anycompatible",
+    "anycompatiblearray",
+    "anycompatiblenonarray",
+    "anycompatiblerange",
+    "anycompatiblemultirange",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum PossibleType {
-    Null,
-    AnyOf(Vec<i64>),
+    /// `null` or a string literal. Postgres types them as `unknown` and coerces them to whatever
+    /// type is required.
+    Unknown,
+    /// An expression whose type cannot be determined without the database, e.g. a column
+    /// reference. Matches any type.
+    Unresolved,
+    /// The type with the given id.
+    Known(i64),
 }
 
-pub fn resolve_type(node: &pgt_query_ext::NodeEnum, schema_cache: &SchemaCache) -> PossibleType {
+pub(crate) fn resolve_type(node: &NodeEnum, schema_cache: &SchemaCache) -> PossibleType {
     match node {
-        pgt_query_ext::NodeEnum::AConst(n) => {
+        NodeEnum::AConst(n) => {
             if n.isnull {
-                PossibleType::Null
-            } else {
-                match n
-                    .val
-                    .as_ref()
-                    .expect("expected non-nullable AConst to have a value")
-                {
-                    pgt_query_ext::protobuf::a_const::Val::Ival(_) => {
-                        let types: Vec<String> = ["int2", "int4", "int8"]
-                            .iter()
-                            .map(|s| s.to_string())
-                            .collect();
-
-                        PossibleType::AnyOf(
-                            schema_cache
-                                .types
-                                .iter()
-                                .filter(|t| {
-                                    types.iter().any(|i| i == &t.name) && t.schema == "pg_catalog"
-                                })
-                                .map(|t| t.id)
-                                .collect(),
-                        )
-                    }
-                    pgt_query_ext::protobuf::a_const::Val::Fval(_) => {
-                        let types: Vec<String> =
-                            ["float4", "float8"].iter().map(|s| s.to_string()).collect();
-
-                        PossibleType::AnyOf(
-                            schema_cache
-                                .types
-                                .iter()
-                                .filter(|t| types.contains(&t.name) && t.schema == "pg_catalog")
-                                .map(|t| t.id)
-                                .collect(),
-                        )
-                    }
-                    pgt_query_ext::protobuf::a_const::Val::Boolval(_) => PossibleType::AnyOf(
-                        schema_cache
-                            .types
-                            .iter()
-                            .filter(|t| t.name == "bool" && t.schema == "pg_catalog")
-                            .map(|t| t.id)
-                            .collect(),
-                    ),
-                    pgt_query_ext::protobuf::a_const::Val::Sval(v) => {
-                        let types: Vec<String> =
-                            ["text", "varchar"].iter().map(|s| s.to_string()).collect();
-
-                        PossibleType::AnyOf(
-                            schema_cache
-                                .types
-                                .iter()
-                                .filter(|t| {
-                                    (types.iter().any(|i| i == &t.name) && t.schema == "pg_catalog")
-                                        || t.enums.values.contains(&v.sval)
-                                })
-                                .map(|t| t.id)
-                                .collect(),
-                        )
-                    }
-                    pgt_query_ext::protobuf::a_const::Val::Bsval(_) => todo!(),
-                }
+                return PossibleType::Unknown;
             }
+
+            let Some(val) = n.val.as_ref() else {
+                return PossibleType::Unresolved;
+            };
+
+            let type_name = match val {
+                Val::Sval(_) => return PossibleType::Unknown,
+                Val::Ival(_) => "int4",
+                // integers that don't fit into an `int4` are stored as floats by the parser, and
+                // are `numeric` if they don't fit into an `int8` either
+                Val::Fval(v) if v.fval.parse::<i64>().is_ok() => "int8",
+                Val::Fval(_) => "numeric",
+                Val::Boolval(_) => "bool",
+                Val::Bsval(_) => "bit",
+            };
+
+            builtin_type(schema_cache, type_name)
         }
-        _ => todo!(),
+        NodeEnum::TypeCast(n) => n
+            .type_name
+            .as_ref()
+            .and_then(|type_name| resolve_type_name(type_name, schema_cache))
+            .map(PossibleType::Known)
+            .unwrap_or(PossibleType::Unresolved),
+        NodeEnum::FuncCall(n) => resolve_func_call(n, schema_cache)
+            .function()
+            .map(|f| PossibleType::Known(f.return_type_id))
+            .unwrap_or(PossibleType::Unresolved),
+        _ => PossibleType::Unresolved,
     }
 }
+
+/// Resolves a type name such as `int[]` or `public.my_type` to the id of the type in the cache.
+pub(crate) fn resolve_type_name(
+    type_name: &pgt_query_ext::protobuf::TypeName,
+    schema_cache: &SchemaCache,
+) -> Option<i64> {
+    let mut names: Vec<String> = type_name.names.iter().map(get_string_from_node).collect();
+    let name = names.pop()?;
+    let schema = names.pop();
+
+    // array types are prefixed with an underscore in `pg_type`
+    let name = if type_name.array_bounds.is_empty() {
+        name
+    } else {
+        format!("_{}", name)
+    };
+
+    schema_cache
+        .find_type(&name, schema.as_deref())
+        .map(|t| t.id)
+}
+
+/// Whether a value of type `input` can be passed where a value of type `target_type_id` is
+/// expected, without an explicit cast.
+pub(crate) fn can_coerce_implicitly(
+    input: &PossibleType,
+    target_type_id: i64,
+    schema_cache: &SchemaCache,
+) -> bool {
+    match input {
+        PossibleType::Unknown | PossibleType::Unresolved => true,
+        PossibleType::Known(type_id) => {
+            *type_id == target_type_id
+                || is_polymorphic(target_type_id, schema_cache)
+                || base_type_id(target_type_id, schema_cache) == *type_id
+                || schema_cache
+                    .find_cast(*type_id, target_type_id)
+                    .is_some_and(|cast| cast.context == CastContext::Implicit)
+        }
+    }
+}
+
+/// Returns the type a domain is based on, or the type itself if it is no domain.
+pub(crate) fn base_type_id(type_id: i64, schema_cache: &SchemaCache) -> i64 {
+    schema_cache
+        .find_base_type(type_id)
+        .map(|t| t.id)
+        .unwrap_or(type_id)
+}
+
+/// Whether the type is a polymorphic pseudo-type such as `anyelement` or `"any"`, which accepts
+/// values of any type.
+pub(crate) fn is_polymorphic(type_id: i64, schema_cache: &SchemaCache) -> bool {
+    schema_cache.find_type_by_id(type_id).is_some_and(|t| {
+        t.kind == TypeKind::Pseudo
+            && t.schema == "pg_catalog"
+            && POLYMORPHIC_TYPES.contains(&t.name.as_str())
+    })
+}
+
+/// Whether Postgres prefers the type within its type category when resolving an overloaded
+/// function, see `typispreferred` in `pg_type`.
+pub(crate) fn is_preferred(type_id: i64, schema_cache: &SchemaCache) -> bool {
+    schema_cache
+        .find_type_by_id(type_id)
+        .is_some_and(|t| t.is_preferred)
+}
+
+/// Returns the element type of an array type, e.g. `text` for `text[]`. Polymorphic types are
+/// returned as they are.
+pub(crate) fn element_type_id(array_type_id: i64, schema_cache: &SchemaCache) -> i64 {
+    schema_cache
+        .find_type_by_id(array_type_id)
+        .and_then(|t| {
+            let element_name = t.name.strip_prefix('_')?;
+            schema_cache.find_type(element_name, Some(t.schema.as_str()))
+        })
+        .map(|t| t.id)
+        .unwrap_or(array_type_id)
+}
+
+fn builtin_type(schema_cache: &SchemaCache, name: &str) -> PossibleType {
+    schema_cache
+        .find_type(name, Some("pg_catalog"))
+        .map(|t| PossibleType::Known(t.id))
+        .unwrap_or(PossibleType::Unresolved)
+}
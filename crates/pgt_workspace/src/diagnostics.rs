@@ -5,6 +5,8 @@ use pgt_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticTags, LogCategory, Severity, Visit, category,
 };
 use pgt_fs::FileSystemDiagnostic;
+
+use crate::connection::ConnectionSources;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
@@ -19,6 +21,10 @@ pub enum WorkspaceError {
     Configuration(ConfigurationDiagnostic),
     /// Error when trying to access the database
     DatabaseConnectionError(DatabaseConnectionError),
+    /// Error when reading or writing a schema snapshot
+    SchemaSnapshot(SchemaSnapshotError),
+    /// A connection profile that is not configured was selected
+    UnknownProfile(UnknownProfile),
     /// Diagnostics emitted when querying the file system
     FileSystem(FileSystemDiagnostic),
     /// Thrown when we can't read a generic directory
@@ -61,6 +67,50 @@ impl WorkspaceError {
         Self::Vcs(VcsDiagnostic::DisabledVcs(DisabledVcs {}))
     }
 
+    pub fn not_connected() -> Self {
+        Self::DatabaseConnectionError(DatabaseConnectionError {
+            message: "Not connected to database.".into(),
+            code: None,
+            sources: ConnectionSourcesAdvice::default(),
+        })
+    }
+
+    pub fn invalid_connection(message: impl Into<String>) -> Self {
+        Self::DatabaseConnectionError(DatabaseConnectionError {
+            message: message.into(),
+            code: None,
+            sources: ConnectionSourcesAdvice::default(),
+        })
+    }
+
+    /// Adds where the connection settings were taken from to errors that occurred while
+    /// connecting. Errors reported by the database itself are left untouched.
+    pub fn with_connection_sources(self, sources: &ConnectionSources) -> Self {
+        match self {
+            Self::DatabaseConnectionError(err) if err.code.is_none() => {
+                Self::DatabaseConnectionError(DatabaseConnectionError {
+                    sources: ConnectionSourcesAdvice(Some(sources.to_string())),
+                    ..err
+                })
+            }
+            err => err,
+        }
+    }
+
+    pub fn schema_snapshot(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::SchemaSnapshot(SchemaSnapshotError {
+            path: path.into(),
+            message: message.into(),
+        })
+    }
+
+    pub fn unknown_profile(profile: impl Into<String>, available: Vec<String>) -> Self {
+        Self::UnknownProfile(UnknownProfile {
+            profile: profile.into(),
+            available: available.join(", "),
+        })
+    }
+
     pub fn runtime(msg: &str) -> Self {
         Self::RuntimeError(RuntimeError {
             message: msg.into(),
@@ -218,14 +268,34 @@ impl From<VcsDiagnostic> for WorkspaceError {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[derive(Clone, Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "database/connection",
-    message = "Database error: {message}"
+    message(
+        message("Database error: "{self.message}),
+        description = "Database error: {message}"
+    )
 )]
 pub struct DatabaseConnectionError {
     message: String,
     code: Option<String>,
+    #[advice]
+    sources: ConnectionSourcesAdvice,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConnectionSourcesAdvice(Option<String>);
+
+impl Advices for ConnectionSourcesAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        match &self.0 {
+            Some(sources) => visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The connection settings were resolved as follows: "{sources} },
+            ),
+            None => Ok(()),
+        }
+    }
 }
 
 impl From<sqlx::Error> for WorkspaceError {
@@ -235,16 +305,45 @@ impl From<sqlx::Error> for WorkspaceError {
             Self::DatabaseConnectionError(DatabaseConnectionError {
                 message: db_err.message().to_string(),
                 code: db_err.code().map(|c| c.to_string()),
+                sources: ConnectionSourcesAdvice::default(),
             })
         } else {
             Self::DatabaseConnectionError(DatabaseConnectionError {
                 message: err.to_string(),
                 code: None,
+                sources: ConnectionSourcesAdvice::default(),
             })
         }
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "database/schemaSnapshot",
+    message(
+        message("Couldn't use the schema snapshot "<Emphasis>{self.path}</Emphasis>": "{self.message}),
+        description = "Couldn't use the schema snapshot {path}: {message}"
+    )
+)]
+pub struct SchemaSnapshotError {
+    #[location(resource)]
+    path: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "database/profile",
+    message(
+        message("The connection profile "<Emphasis>{self.profile}</Emphasis>" is not configured. Available profiles: "{self.available}),
+        description = "The connection profile {profile} is not configured. Available profiles: {available}"
+    )
+)]
+pub struct UnknownProfile {
+    profile: String,
+    available: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
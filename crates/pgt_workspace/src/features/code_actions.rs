@@ -1,7 +1,7 @@
 use crate::workspace::StatementId;
 use pgt_configuration::RuleSelector;
 use pgt_fs::PgTPath;
-use pgt_text_size::TextSize;
+use pgt_text_size::{TextRange, TextSize};
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -36,7 +36,27 @@ pub enum CodeActionKind {
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-pub struct EditAction {}
+pub struct EditAction {
+    pub category: EditActionCategory,
+    pub edits: Vec<DocumentEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EditActionCategory {
+    /// Applies a fix offered by a rule
+    QuickFix,
+    /// Suppresses a rule for a statement
+    SuppressRule,
+}
+
+/// Replaces a range of the document with new text
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -0,0 +1,33 @@
+use pgt_configuration::RuleSelector;
+use pgt_fs::PgTPath;
+
+/// Which fixes to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FixFileMode {
+    /// Applies only the fixes that do not change the semantics of the statement
+    SafeFixes,
+    /// Also applies the fixes that may change the semantics of the statement or that have to be
+    /// completed by hand
+    SafeAndUnsafeFixes,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixFileParams {
+    pub path: PgTPath,
+    pub fix_file_mode: FixFileMode,
+    pub only: Vec<RuleSelector>,
+    pub skip: Vec<RuleSelector>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FixFileResult {
+    /// The content of the file with the fixes applied
+    pub code: String,
+    /// The number of fixes that were applied
+    pub fixes_applied: u32,
+    /// The number of fixes that were skipped because they are unsafe
+    pub skipped_suggested_fixes: u32,
+}
@@ -4,6 +4,7 @@ use pgt_console::Console;
 use pgt_fs::{FileSystem, OsFileSystem};
 
 pub mod configuration;
+pub mod connection;
 pub mod diagnostics;
 pub mod dome;
 pub mod features;
@@ -3,6 +3,7 @@ use globset::Glob;
 use pgt_diagnostics::Category;
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     num::NonZeroU64,
     path::{Path, PathBuf},
     sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
@@ -12,14 +13,19 @@ use std::{
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use pgt_configuration::{
     ConfigurationDiagnostic, LinterConfiguration, PartialConfiguration,
-    database::PartialDatabaseConfiguration,
+    database::{PartialDatabaseConfiguration, SslMode},
     diagnostics::InvalidIgnorePattern,
     files::FilesConfiguration,
     migrations::{MigrationsConfiguration, PartialMigrationsConfiguration},
 };
 use pgt_fs::FileSystem;
 
-use crate::{DynRef, WorkspaceError, matcher::Matcher};
+use crate::{
+    DynRef, WorkspaceError,
+    connection::{self, ConnectionEnv, ConnectionSources, ResolvedConnection},
+    diagnostics::DatabaseConnectionError,
+    matcher::Matcher,
+};
 
 /// Global settings for the entire workspace
 #[derive(Debug, Default)]
@@ -85,6 +91,25 @@ impl Settings {
         working_directory: Option<PathBuf>,
         vcs_path: Option<PathBuf>,
         gitignore_matches: &[String],
+    ) -> Result<(), WorkspaceError> {
+        self.merge_with_configuration_in(
+            configuration,
+            working_directory,
+            vcs_path,
+            gitignore_matches,
+            &ConnectionEnv::from_process(),
+        )
+    }
+
+    /// Like [Settings::merge_with_configuration], but resolves the connection settings in the
+    /// given environment instead of the one of the process.
+    pub(crate) fn merge_with_configuration_in(
+        &mut self,
+        configuration: PartialConfiguration,
+        working_directory: Option<PathBuf>,
+        vcs_path: Option<PathBuf>,
+        gitignore_matches: &[String],
+        env: &ConnectionEnv,
     ) -> Result<(), WorkspaceError> {
         // Filesystem settings
         if let Some(files) = to_file_settings(
@@ -98,7 +123,38 @@ impl Settings {
 
         // db settings
         if let Some(db) = configuration.db {
-            self.db = db.into()
+            let mut db = DatabaseSettings::resolve(db, env)?;
+
+            if let Some(profile) = &db.profile
+                && !db.profiles.contains_key(profile)
+            {
+                return Err(WorkspaceError::unknown_profile(
+                    profile,
+                    db.profiles.keys().cloned().collect(),
+                ));
+            }
+
+            // the connection settings of the other profiles are reported once they are selected
+            if let Some(err) = db
+                .with_profile(db.profile.as_deref())
+                .and_then(DatabaseSettings::connection_error)
+            {
+                tracing::warn!("Invalid connection settings: {}", err);
+            }
+
+            // relative snapshot paths are resolved against the workspace
+            if let Some(working_directory) = &working_directory {
+                let snapshots = std::iter::once(&mut db.schema_snapshot).chain(
+                    db.profiles
+                        .values_mut()
+                        .map(|profile| &mut profile.schema_snapshot),
+                );
+                for snapshot in snapshots.flatten() {
+                    *snapshot = working_directory.join(&snapshot);
+                }
+            }
+
+            self.db = db;
         }
 
         // linter part
@@ -266,15 +322,49 @@ impl Default for LinterSettings {
 }
 
 /// Database settings for the entire workspace
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DatabaseSettings {
     pub host: String,
     pub port: u16,
     pub username: String,
     pub password: String,
     pub database: String,
+    pub ssl_mode: SslMode,
+    pub ssl_root_cert: Option<PathBuf>,
+    pub ssl_cert: Option<PathBuf>,
+    pub ssl_key: Option<PathBuf>,
+    /// Where the connection settings were taken from, e.g. an environment variable.
+    pub sources: ConnectionSources,
     pub conn_timeout_secs: Duration,
     pub allow_statement_executions: bool,
+    /// If set, the schema cache is loaded from this snapshot instead of the database.
+    pub schema_snapshot: Option<PathBuf>,
+    /// Whether column statistics are loaded into the schema cache.
+    pub load_column_stats: bool,
+    /// The settings of each connection profile by name.
+    pub profiles: BTreeMap<String, DatabaseSettings>,
+    /// The profile to connect with, unless another one is selected for a workspace folder.
+    pub profile: Option<String>,
+    /// Why the connection settings could not be resolved, e.g. because of a malformed connection
+    /// string. It is reported once a connection is attempted.
+    connection_error: Option<DatabaseConnectionError>,
+}
+
+impl DatabaseSettings {
+    /// Returns the settings of the profile, or these settings if no profile is given.
+    pub fn with_profile(&self, profile: Option<&str>) -> Option<&DatabaseSettings> {
+        match profile {
+            Some(profile) => self.profiles.get(profile),
+            None => Some(self),
+        }
+    }
+
+    /// Returns the error the connection settings could not be resolved with, if any.
+    pub fn connection_error(&self) -> Option<WorkspaceError> {
+        self.connection_error
+            .clone()
+            .map(WorkspaceError::DatabaseConnectionError)
+    }
 }
 
 impl Default for DatabaseSettings {
@@ -285,18 +375,59 @@ impl Default for DatabaseSettings {
             username: "postgres".to_string(),
             password: "postgres".to_string(),
             database: "postgres".to_string(),
+            ssl_mode: SslMode::default(),
+            ssl_root_cert: None,
+            ssl_cert: None,
+            ssl_key: None,
+            sources: ConnectionSources::default(),
             conn_timeout_secs: Duration::from_secs(10),
             allow_statement_executions: true,
+            schema_snapshot: None,
+            load_column_stats: false,
+            profiles: BTreeMap::new(),
+            profile: None,
+            connection_error: None,
         }
     }
 }
 
-impl From<PartialDatabaseConfiguration> for DatabaseSettings {
-    fn from(value: PartialDatabaseConfiguration) -> Self {
+impl DatabaseSettings {
+    /// Resolves the settings from the configuration. Connection settings it does not set are taken
+    /// from the connection string, the service file, the `PG*` environment variables and the
+    /// password file.
+    pub(crate) fn resolve(
+        value: PartialDatabaseConfiguration,
+        env: &ConnectionEnv,
+    ) -> Result<Self, WorkspaceError> {
         let d = DatabaseSettings::default();
 
-        let database = value.database.unwrap_or(d.database);
-        let host = value.host.unwrap_or(d.host);
+        // the settings of each profile are resolved on their own, so that statement executions
+        // are allowed per profile. Invalid connection settings of a profile are only reported once
+        // it is selected.
+        let profiles = value
+            .profiles
+            .iter()
+            .flat_map(|profiles| profiles.0.iter())
+            .map(|(name, profile)| {
+                Ok((
+                    name.clone(),
+                    DatabaseSettings::resolve(value.with_profile(profile), env)?,
+                ))
+            })
+            .collect::<Result<_, WorkspaceError>>()?;
+
+        // the other settings stay usable if the connection settings are invalid, e.g. to lint
+        // without a database
+        let (connection, connection_error) = match connection::resolve(&value, env) {
+            Ok(connection) => (connection, None),
+            Err(WorkspaceError::DatabaseConnectionError(err)) => {
+                (ResolvedConnection::default(), Some(err))
+            }
+            Err(err) => return Err(err),
+        };
+
+        let database = connection.database;
+        let host = connection.host;
 
         let allow_statement_executions = value
             .allow_statement_executions_against
@@ -311,20 +442,39 @@ impl From<PartialDatabaseConfiguration> for DatabaseSettings {
             })
             .unwrap_or(false);
 
-        Self {
-            port: value.port.unwrap_or(d.port),
-            username: value.username.unwrap_or(d.username),
-            password: value.password.unwrap_or(d.password),
+        Ok(Self {
+            port: connection.port,
+            username: connection.username,
+            password: connection.password,
             database,
             host,
 
+            ssl_mode: connection.ssl_mode,
+            ssl_root_cert: connection.ssl_root_cert,
+            ssl_cert: connection.ssl_cert,
+            ssl_key: connection.ssl_key,
+            sources: connection.sources,
+
             conn_timeout_secs: value
                 .conn_timeout_secs
                 .map(|s| Duration::from_secs(s.into()))
                 .unwrap_or(d.conn_timeout_secs),
 
             allow_statement_executions,
-        }
+
+            schema_snapshot: value
+                .schema_snapshot
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from),
+
+            load_column_stats: value.load_column_stats.unwrap_or(d.load_column_stats),
+
+            profiles,
+
+            profile: value.profile.filter(|profile| !profile.is_empty()),
+
+            connection_error,
+        })
     }
 }
 
@@ -349,6 +499,8 @@ pub struct FilesSettings {
 pub struct MigrationSettings {
     pub path: Option<PathBuf>,
     pub after: Option<u64>,
+    /// Whether the schema cache is built from the migrations instead of the database
+    pub schema_from_migrations: bool,
 }
 
 impl From<PartialMigrationsConfiguration> for MigrationSettings {
@@ -356,6 +508,7 @@ impl From<PartialMigrationsConfiguration> for MigrationSettings {
         Self {
             path: value.migrations_dir.map(PathBuf::from),
             after: value.after,
+            schema_from_migrations: value.schema_from_migrations.unwrap_or_default(),
         }
     }
 }
@@ -367,6 +520,7 @@ fn to_migration_settings(
     working_directory.map(|working_directory| MigrationSettings {
         path: Some(working_directory.join(conf.migrations_dir)),
         after: Some(conf.after),
+        schema_from_migrations: conf.schema_from_migrations,
     })
 }
 
@@ -441,10 +595,15 @@ impl PartialConfigurationExt for PartialConfiguration {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use biome_deserialize::StringSet;
-    use pgt_configuration::database::PartialDatabaseConfiguration;
+    use pgt_configuration::{
+        PartialConfiguration,
+        database::{DatabaseProfile, DatabaseProfiles, PartialDatabaseConfiguration},
+    };
 
-    use super::DatabaseSettings;
+    use super::{ConnectionEnv, DatabaseSettings, Settings};
 
     #[test]
     fn should_identify_allowed_statement_executions() {
@@ -457,7 +616,7 @@ mod tests {
             ..Default::default()
         };
 
-        let config = DatabaseSettings::from(partial_config);
+        let config = DatabaseSettings::resolve(partial_config, &ConnectionEnv::default()).unwrap();
 
         assert!(config.allow_statement_executions)
     }
@@ -473,8 +632,161 @@ mod tests {
             ..Default::default()
         };
 
-        let config = DatabaseSettings::from(partial_config);
+        let config = DatabaseSettings::resolve(partial_config, &ConnectionEnv::default()).unwrap();
 
         assert!(!config.allow_statement_executions)
     }
+
+    #[test]
+    fn should_resolve_schema_snapshot_against_workspace() {
+        let mut settings = Settings::default();
+
+        settings
+            .merge_with_configuration_in(
+                PartialConfiguration {
+                    db: Some(PartialDatabaseConfiguration {
+                        schema_snapshot: Some("snapshots/schema.json".into()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Some(PathBuf::from("/workspace")),
+                None,
+                &[],
+                &ConnectionEnv::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            settings.db.schema_snapshot,
+            Some(PathBuf::from("/workspace/snapshots/schema.json"))
+        );
+    }
+
+    #[test]
+    fn should_keep_settings_with_invalid_connection() {
+        for (connection_string, service) in [
+            (Some("mysql://localhost/app"), None),
+            (None, Some("unknown")),
+        ] {
+            let mut settings = Settings::default();
+
+            settings
+                .merge_with_configuration_in(
+                    PartialConfiguration {
+                        db: Some(PartialDatabaseConfiguration {
+                            connection_string: connection_string.map(String::from),
+                            service: service.map(String::from),
+                            schema_snapshot: Some("schema.json".into()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    None,
+                    None,
+                    &[],
+                    &ConnectionEnv::default(),
+                )
+                .unwrap();
+
+            assert!(settings.db.connection_error().is_some());
+            assert_eq!(
+                settings.db.schema_snapshot,
+                Some(PathBuf::from("schema.json"))
+            );
+        }
+    }
+
+    #[test]
+    fn should_ignore_empty_schema_snapshot() {
+        let partial_config = PartialDatabaseConfiguration {
+            schema_snapshot: Some("".into()),
+            ..Default::default()
+        };
+
+        let config = DatabaseSettings::resolve(partial_config, &ConnectionEnv::default()).unwrap();
+
+        assert_eq!(config.schema_snapshot, None)
+    }
+
+    #[test]
+    fn should_resolve_profiles() {
+        let partial_config = PartialDatabaseConfiguration {
+            host: Some("localhost".into()),
+            database: Some("app".into()),
+            allow_statement_executions_against: Some(StringSet::from_iter(vec![String::from(
+                "localhost/*",
+            )])),
+            profiles: Some(DatabaseProfiles::from_iter([(
+                "prod".to_string(),
+                DatabaseProfile {
+                    host: Some("prod-replica".into()),
+                    username: Some("readonly".into()),
+                    ..Default::default()
+                },
+            )])),
+            profile: Some("prod".into()),
+            ..Default::default()
+        };
+
+        let config = DatabaseSettings::resolve(partial_config, &ConnectionEnv::default()).unwrap();
+
+        assert!(config.allow_statement_executions);
+        assert_eq!(config.profile.as_deref(), Some("prod"));
+
+        let prod = config.with_profile(Some("prod")).unwrap();
+        assert_eq!(prod.host, "prod-replica");
+        assert_eq!(prod.username, "readonly");
+        assert_eq!(prod.database, "app");
+        assert!(!prod.allow_statement_executions);
+        assert!(prod.profiles.is_empty());
+
+        assert!(config.with_profile(Some("staging")).is_none());
+    }
+
+    #[test]
+    fn should_keep_profiles_with_invalid_connection() {
+        let partial_config = PartialDatabaseConfiguration {
+            profiles: Some(DatabaseProfiles::from_iter([(
+                "prod".to_string(),
+                DatabaseProfile {
+                    service: Some("ci".into()),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        };
+
+        let config = DatabaseSettings::resolve(partial_config, &ConnectionEnv::default()).unwrap();
+
+        assert!(config.connection_error().is_none());
+        assert!(
+            config
+                .with_profile(Some("prod"))
+                .unwrap()
+                .connection_error()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_profile() {
+        let mut settings = Settings::default();
+
+        let result = settings.merge_with_configuration_in(
+            PartialConfiguration {
+                db: Some(PartialDatabaseConfiguration {
+                    profile: Some("staging".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            None,
+            None,
+            &[],
+            &ConnectionEnv::default(),
+        );
+
+        assert!(result.is_err());
+    }
 }
@@ -15,6 +15,7 @@ use crate::{
         },
         completions::{CompletionsResult, GetCompletionsParams},
         diagnostics::{PullDiagnosticsParams, PullDiagnosticsResult},
+        fix_file::{FixFileMode, FixFileParams, FixFileResult},
     },
 };
 
@@ -75,6 +76,17 @@ pub struct UpdateSettingsParams {
     pub skip_db: bool,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SelectProfileParams {
+    /// The profile to connect with. If `None`, the connection settings outside of the profiles
+    /// are used.
+    pub profile: Option<String>,
+    /// The workspace folder to select the profile for. If `None`, it is selected for all folders
+    /// that do not select their own.
+    pub workspace_folder: Option<PathBuf>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetFileContentParams {
@@ -99,6 +111,10 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError>;
 
+    /// Applies the fixes offered by the lint rules to a file and returns its new content. The
+    /// file itself is not changed.
+    fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
+
     /// Retrieves a list of available code_actions for a file/cursor_position
     fn pull_code_actions(
         &self,
@@ -136,10 +152,23 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, then `true` is returned, and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
+    /// Selects the database connection profile used for the files in a workspace folder,
+    /// overriding `db.profile`.
+    fn select_profile(&self, params: SelectProfileParams) -> Result<(), WorkspaceError>;
+
     fn execute_statement(
         &self,
         params: ExecuteStatementParams,
     ) -> Result<ExecuteStatementResult, WorkspaceError>;
+
+    /// Loads the schema of the connected database and serializes it into a snapshot, which can
+    /// be used via `db.schemaSnapshot` when no database is available.
+    fn dump_schema_snapshot(&self) -> Result<String, WorkspaceError>;
+
+    /// Checks the connected database for schema changes and reloads the affected parts of the
+    /// schema cache. Returns whether the cache changed, in which case diagnostics should be
+    /// pulled again.
+    fn refresh_schema_cache(&self) -> Result<bool, WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -208,6 +237,20 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             skip,
         })
     }
+
+    pub fn fix_file(
+        &self,
+        fix_file_mode: FixFileMode,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
+    ) -> Result<FixFileResult, WorkspaceError> {
+        self.workspace.fix_file(FixFileParams {
+            path: self.path.clone(),
+            fix_file_mode,
+            only,
+            skip,
+        })
+    }
 }
 
 impl<W: Workspace + ?Sized> Drop for FileGuard<'_, W> {
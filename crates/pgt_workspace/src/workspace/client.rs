@@ -7,7 +7,10 @@ use std::{
     sync::atomic::{AtomicU64, Ordering},
 };
 
-use super::{CloseFileParams, GetFileContentParams, IsPathIgnoredParams, OpenFileParams};
+use super::{
+    CloseFileParams, FixFileParams, FixFileResult, GetFileContentParams, IsPathIgnoredParams,
+    OpenFileParams, SelectProfileParams,
+};
 
 pub struct WorkspaceClient<T> {
     transport: T,
@@ -103,6 +106,14 @@ where
         self.request("pgt/execute_statement", params)
     }
 
+    fn dump_schema_snapshot(&self) -> Result<String, WorkspaceError> {
+        self.request("pgt/dump_schema_snapshot", ())
+    }
+
+    fn refresh_schema_cache(&self) -> Result<bool, WorkspaceError> {
+        self.request("pgt/refresh_schema_cache", ())
+    }
+
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
         self.request("pgt/open_file", params)
     }
@@ -123,6 +134,10 @@ where
         self.request("pgt/is_path_ignored", params)
     }
 
+    fn select_profile(&self, params: SelectProfileParams) -> Result<(), WorkspaceError> {
+        self.request("pgt/select_profile", params)
+    }
+
     fn server_info(&self) -> Option<&ServerInfo> {
         self.server_info.as_ref()
     }
@@ -138,6 +153,10 @@ where
         self.request("pgt/pull_diagnostics", params)
     }
 
+    fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError> {
+        self.request("pgt/fix_file", params)
+    }
+
     fn get_completions(
         &self,
         params: super::GetCompletionsParams,
@@ -1,4 +1,10 @@
-use std::{fs, panic::RefUnwindSafe, path::Path, sync::RwLock};
+use std::{
+    collections::HashMap,
+    fs,
+    panic::RefUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
 
 use analyser::AnalyserVisitorBuilder;
 use async_helper::run_async;
@@ -9,12 +15,21 @@ pub(crate) use document::StatementId;
 use document::{Document, Statement};
 use futures::{StreamExt, stream};
 use pg_query::PgQueryStore;
-use pgt_analyse::{AnalyserOptions, AnalysisFilter};
-use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext};
-use pgt_diagnostics::{Diagnostic, DiagnosticExt, Severity, serde::Diagnostic as SDiagnostic};
+use pgt_analyse::{
+    AnalyserOptions, AnalysisFilter, RuleCategories, RuleCategoriesBuilder, RuleCategory,
+    RuleDiagnostic,
+};
+use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext, FileAnalyserContext, Suppressions};
+use pgt_configuration::RuleSelector;
+use pgt_diagnostics::{
+    Applicability, Category, Diagnostic, DiagnosticExt, Severity, display::markup_to_string,
+    serde::Diagnostic as SDiagnostic,
+};
 use pgt_fs::{ConfigName, PgTPath};
+use pgt_schema_cache::{SchemaCache, Version};
+use pgt_text_size::{TextRange, TextSize};
 use pgt_typecheck::TypecheckParams;
-use schema_cache_manager::SchemaCacheManager;
+use schema_cache_manager::{MutatedSchemaCache, SchemaCacheHandle, SchemaCacheManager};
 use sqlx::Executor;
 use tracing::info;
 use tree_sitter::TreeSitterStore;
@@ -25,17 +40,19 @@ use crate::{
     features::{
         code_actions::{
             self, CodeAction, CodeActionKind, CodeActionsResult, CommandAction,
-            CommandActionCategory, ExecuteStatementParams, ExecuteStatementResult,
+            CommandActionCategory, DocumentEdit, EditAction, EditActionCategory,
+            ExecuteStatementParams, ExecuteStatementResult,
         },
         completions::{CompletionsResult, GetCompletionsParams},
         diagnostics::{PullDiagnosticsParams, PullDiagnosticsResult},
+        fix_file::{FixFileMode, FixFileParams, FixFileResult},
     },
-    settings::{Settings, SettingsHandle, SettingsHandleMut},
+    settings::{DatabaseSettings, Settings, SettingsHandle, SettingsHandleMut},
 };
 
 use super::{
-    GetFileContentParams, IsPathIgnoredParams, OpenFileParams, ServerInfo, UpdateSettingsParams,
-    Workspace,
+    GetFileContentParams, IsPathIgnoredParams, OpenFileParams, SelectProfileParams, ServerInfo,
+    UpdateSettingsParams, Workspace,
 };
 
 mod analyser;
@@ -52,16 +69,25 @@ pub(super) struct WorkspaceServer {
     /// global settings object for this workspace
     settings: RwLock<Settings>,
 
-    /// Stores the schema cache for this workspace
-    schema_cache: SchemaCacheManager,
+    /// Stores the schema cache of each connection profile, `None` being the default connection
+    schema_caches: RwLock<HashMap<Option<String>, Arc<SchemaCacheManager>>>,
 
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<PgTPath, Document>,
 
+    /// The schema cache each document was last completed with, if DDL statements of the document
+    /// had to be applied to it
+    mutated_schema_caches: DashMap<PgTPath, MutatedSchemaCache>,
+
     tree_sitter: TreeSitterStore,
     pg_query: PgQueryStore,
 
-    connection: RwLock<DbConnection>,
+    /// Stores the connection of each profile, `None` being the default connection
+    connections: RwLock<HashMap<Option<String>, DbConnection>>,
+
+    /// The profile selected for a workspace folder, or for the whole workspace if the folder is
+    /// `None`. Overrides `db.profile` from the configuration.
+    selected_profiles: RwLock<HashMap<Option<PathBuf>, Option<String>>>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -82,10 +108,12 @@ impl WorkspaceServer {
         Self {
             settings: RwLock::default(),
             documents: DashMap::default(),
+            mutated_schema_caches: DashMap::default(),
             tree_sitter: TreeSitterStore::new(),
             pg_query: PgQueryStore::new(),
-            schema_cache: SchemaCacheManager::default(),
-            connection: RwLock::default(),
+            schema_caches: RwLock::default(),
+            connections: RwLock::default(),
+            selected_profiles: RwLock::default(),
         }
     }
 
@@ -98,6 +126,208 @@ impl WorkspaceServer {
         SettingsHandleMut::new(&self.settings)
     }
 
+    /// Creates an analyser for the rules enabled in the settings, narrowed down by `only` and
+    /// `skip`, and passes it to `f`
+    fn with_analyser<T>(
+        &self,
+        settings: &Settings,
+        only: &[RuleSelector],
+        skip: &[RuleSelector],
+        categories: RuleCategories,
+        f: impl FnOnce(&Analyser) -> T,
+    ) -> T {
+        // first, collect enabled and disabled rules from the workspace settings
+        let (enabled_rules, disabled_rules) = AnalyserVisitorBuilder::new(settings)
+            .with_linter_rules(only, skip)
+            .finish();
+        // then, build a map that contains all options
+        let options = AnalyserOptions {
+            rules: to_analyser_rules(settings),
+        };
+        // next, build the analysis filter which will be used to match rules
+        let filter = AnalysisFilter {
+            categories,
+            enabled_rules: Some(enabled_rules.as_slice()),
+            disabled_rules: &disabled_rules,
+        };
+        // finally, create the analyser that will be used during this run
+        let analyser = Analyser::new(AnalyserConfig {
+            options: &options,
+            filter,
+        });
+
+        f(&analyser)
+    }
+
+    /// Runs the lint rules on the statements of `doc` that `include` selects, as well as the rules
+    /// that look at the whole file, and returns the diagnostics that are not suppressed together
+    /// with the range of the statement they belong to. Spans and fixes of the diagnostics are
+    /// relative to that statement.
+    fn lint_statements(
+        &self,
+        analyser: &Analyser,
+        doc: &Document,
+        suppressions: &mut Suppressions,
+        schema_cache: Option<&SchemaCache>,
+        version: Option<&Version>,
+        include: impl Fn(&TextRange) -> bool,
+    ) -> Vec<(TextRange, RuleDiagnostic)> {
+        let statements: Vec<_> = doc
+            .iter_statements_with_range()
+            .filter_map(|(stmt, range)| Some((self.pg_query.get_ast(&stmt)?, *range)))
+            .collect();
+
+        // the diagnostics with their statement and their span in the file
+        let mut diagnostics = vec![];
+        for (ast, range) in statements.iter().filter(|(_, range)| include(range)) {
+            let results = analyser.run(AnalyserContext {
+                root: ast,
+                text: &doc.content[*range],
+                schema_cache,
+                version,
+            });
+            diagnostics.extend(
+                results
+                    .into_iter()
+                    .map(|d| (*range, file_span(&d, *range), d)),
+            );
+        }
+
+        // file rules see every statement, and point at the one they are reported for
+        let file_diagnostics = analyser.run_file(FileAnalyserContext {
+            statements: statements
+                .iter()
+                .map(|(ast, range)| (ast.as_ref(), *range))
+                .collect(),
+            text: &doc.content,
+            schema_cache,
+            version,
+        });
+        diagnostics.extend(file_diagnostics.into_iter().filter_map(|d| {
+            let span = d.location().span?;
+            let (_, range) = statements
+                .iter()
+                .find(|(_, range)| range.contains_range(span))?;
+            include(range).then_some((*range, span, d))
+        }));
+
+        diagnostics
+            .into_iter()
+            .filter(|(_, span, d)| {
+                d.category()
+                    .is_some_and(|category| !suppressions.is_suppressed(category, *span))
+            })
+            .map(|(range, _, d)| (range, d))
+            .collect()
+    }
+
+    /// Returns the fixes offered by the lint rules for the statements under the cursor, and a quick
+    /// fix for every lint diagnostic that inserts a suppression comment above the statement
+    fn lint_actions(
+        &self,
+        params: &code_actions::CodeActionsParams,
+        doc: &Document,
+    ) -> Vec<CodeAction> {
+        let schema_cache = self
+            .load_lint_schema_cache(&self.profile_for(Some(params.path.as_path())))
+            .unwrap_or_else(|err| {
+                tracing::warn!("Linting without schema information: {}", err);
+                None
+            });
+        let schema_cache = schema_cache.as_ref().map(|cache| cache.as_ref());
+        let version = schema_cache.and_then(|cache| cache.versions().first());
+
+        let settings = self.settings();
+
+        self.with_analyser(
+            settings.as_ref(),
+            &params.only,
+            &params.skip,
+            RuleCategoriesBuilder::default().with_lint().build(),
+            |analyser| {
+                let statement_ranges: Vec<TextRange> =
+                    doc.iter_statements_with_range().map(|(_, r)| *r).collect();
+                let mut suppressions = analyser.suppressions(&doc.content, &statement_ranges);
+
+                let diagnostics = self.lint_statements(
+                    analyser,
+                    doc,
+                    &mut suppressions,
+                    schema_cache,
+                    version,
+                    |range| range.contains(params.cursor_position),
+                );
+
+                let mut fix_actions = vec![];
+                let mut suppression_actions = vec![];
+
+                for range in statement_ranges
+                    .iter()
+                    .filter(|range| range.contains(params.cursor_position))
+                {
+                    let diagnostics: Vec<_> = diagnostics
+                        .iter()
+                        .filter(|(r, _)| r == range)
+                        .map(|(_, d)| d)
+                        .collect();
+
+                    let text = &doc.content[*range];
+                    fix_actions.extend(diagnostics.iter().flat_map(|d| d.fixes()).map(|fix| {
+                        CodeAction {
+                            title: markup_to_string(&fix.msg),
+                            kind: CodeActionKind::Edit(EditAction {
+                                category: EditActionCategory::QuickFix,
+                                edits: vec![DocumentEdit {
+                                    range: *range,
+                                    new_text: fix.suggestion.new_string(text),
+                                }],
+                            }),
+                            disabled_reason: None,
+                        }
+                    }));
+
+                    let mut categories: Vec<&Category> =
+                        diagnostics.iter().filter_map(|d| d.category()).collect();
+                    categories.sort_by_key(|category| category.name());
+                    categories.dedup_by_key(|category| category.name());
+
+                    // the comment goes on its own line, indented like the statement
+                    let line_start = doc.content[..usize::from(range.start())]
+                        .rfind('\n')
+                        .map_or(0, |idx| idx + 1);
+                    let indentation = &doc.content[line_start..usize::from(range.start())];
+                    let (offset, prefix) = if indentation.trim().is_empty() {
+                        (line_start, indentation)
+                    } else {
+                        // another statement precedes this one on the same line
+                        (usize::from(range.start()), "\n")
+                    };
+                    let offset = TextSize::try_from(offset).unwrap();
+
+                    suppression_actions.extend(categories.into_iter().map(|category| CodeAction {
+                        title: format!("Suppress rule {} for this statement", category.name()),
+                        kind: CodeActionKind::Edit(EditAction {
+                            category: EditActionCategory::SuppressRule,
+                            edits: vec![DocumentEdit {
+                                range: TextRange::empty(offset),
+                                new_text: format!(
+                                    "{}-- pgt-ignore {}: <explanation>\n",
+                                    prefix,
+                                    category.name()
+                                ),
+                            }],
+                        }),
+                        disabled_reason: None,
+                    }));
+                }
+
+                // fixes come first, so that editors offer them before the suppressions
+                fix_actions.extend(suppression_actions);
+                fix_actions
+            },
+        )
+    }
+
     fn is_ignored_by_migration_config(&self, path: &Path) -> bool {
         let set = self.settings();
         set.as_ref()
@@ -113,6 +343,146 @@ impl WorkspaceServer {
             .unwrap_or(false)
     }
 
+    /// Returns the profile to connect with for the file at `path`: the one selected for the
+    /// closest workspace folder, then the one selected for the whole workspace, and finally the one
+    /// from the configuration.
+    fn profile_for(&self, path: Option<&Path>) -> Option<String> {
+        let selected = self.selected_profiles.read().unwrap();
+
+        let by_folder = path.and_then(|path| {
+            selected
+                .iter()
+                .filter_map(|(folder, profile)| Some((folder.as_ref()?, profile)))
+                .filter(|(folder, _)| path.starts_with(folder))
+                .max_by_key(|(folder, _)| folder.components().count())
+                .map(|(_, profile)| profile.clone())
+        });
+
+        by_folder
+            .or_else(|| selected.get(&None).cloned())
+            .unwrap_or_else(|| self.settings().as_ref().db.profile.clone())
+    }
+
+    /// Returns the settings to connect with for the given profile.
+    fn db_settings(&self, profile: Option<&str>) -> Result<DatabaseSettings, WorkspaceError> {
+        let settings = self.settings();
+        let db = &settings.as_ref().db;
+        db.with_profile(profile).cloned().ok_or_else(|| {
+            WorkspaceError::unknown_profile(
+                profile.unwrap_or_default(),
+                db.profiles.keys().cloned().collect(),
+            )
+        })
+    }
+
+    fn get_pool(&self, profile: &Option<String>) -> Option<sqlx::PgPool> {
+        self.connections
+            .read()
+            .expect("DbConnection RwLock panicked")
+            .get(profile)
+            .and_then(|conn| conn.get_pool())
+    }
+
+    fn schema_cache_manager(&self, profile: &Option<String>) -> Arc<SchemaCacheManager> {
+        if let Some(manager) = self.schema_caches.read().unwrap().get(profile) {
+            return manager.clone();
+        }
+
+        self.schema_caches
+            .write()
+            .unwrap()
+            .entry(profile.clone())
+            .or_default()
+            .clone()
+    }
+
+    /// Loads the schema cache of the given profile from the configured snapshot, by replaying the
+    /// migrations if configured, or from the database. Returns `None` if none of them is
+    /// available.
+    fn load_schema_cache(
+        &self,
+        profile: &Option<String>,
+    ) -> Result<Option<SchemaCacheHandle>, WorkspaceError> {
+        let db = self.db_settings(profile.as_deref())?;
+        let schema_cache = self.schema_cache_manager(profile);
+
+        if let Some(path) = &db.schema_snapshot {
+            return schema_cache.load_snapshot(path).map(Some);
+        }
+
+        let migrations_dir = self
+            .settings()
+            .as_ref()
+            .migrations
+            .as_ref()
+            .filter(|migrations| migrations.schema_from_migrations)
+            .and_then(|migrations| migrations.path.clone());
+        if let Some(migrations_dir) = migrations_dir {
+            return schema_cache.load_migrations(&migrations_dir).map(Some);
+        }
+
+        match self.get_pool(profile) {
+            Some(pool) => schema_cache
+                .load(pool)
+                .map(Some)
+                .map_err(|err| err.with_connection_sources(&db.sources)),
+            None => db.connection_error().map_or(Ok(None), Err),
+        }
+    }
+
+    /// Loads the schema cache the lint rules run with. If `db.loadColumnStats` is set, the column
+    /// statistics are loaded the first time, since reading `pg_stats` can be slow.
+    fn load_lint_schema_cache(
+        &self,
+        profile: &Option<String>,
+    ) -> Result<Option<SchemaCacheHandle>, WorkspaceError> {
+        let Some(schema_cache) = self.load_schema_cache(profile)? else {
+            return Ok(None);
+        };
+
+        let db = self.db_settings(profile.as_deref())?;
+        if !db.load_column_stats || schema_cache.as_ref().column_stats().is_some() {
+            return Ok(Some(schema_cache));
+        }
+
+        match self.get_pool(profile) {
+            Some(pool) => self
+                .schema_cache_manager(profile)
+                .load_column_stats(pool)
+                .map(Some)
+                .map_err(|err| err.with_connection_sources(&db.sources)),
+            None => Ok(Some(schema_cache)),
+        }
+    }
+
+    /// Returns the schema cache with the DDL statements applied, e.g. the tables a migration file
+    /// creates before the statement that is being completed. Completions are requested on every
+    /// keystroke, so the result is kept until the statements or the schema cache change.
+    fn mutated_schema_cache(
+        &self,
+        path: &PgTPath,
+        base: SchemaCacheHandle,
+        ddl: Vec<(Statement, Arc<pgt_query_ext::NodeEnum>)>,
+    ) -> SchemaCacheHandle {
+        let statements: Vec<Statement> = ddl.iter().map(|(stmt, _)| stmt.clone()).collect();
+
+        if let Some(mutated) = self.mutated_schema_caches.get(path)
+            && mutated.matches(&base, &statements)
+        {
+            return mutated.handle();
+        }
+
+        let mut cache = base.as_ref().clone();
+        for (_, ast) in &ddl {
+            cache.mutate(ast);
+        }
+
+        let mutated = MutatedSchemaCache::new(&base, statements, cache);
+        let handle = mutated.handle();
+        self.mutated_schema_caches.insert(path.clone(), mutated);
+        handle
+    }
+
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored(&self, path: &Path) -> bool {
         let file_name = path.file_name().and_then(|s| s.to_str());
@@ -167,12 +537,37 @@ impl Workspace for WorkspaceServer {
         tracing::info!("Updated settings in workspace");
 
         if !params.skip_db {
-            self.connection
-                .write()
-                .unwrap()
-                .set_conn_settings(&self.settings().as_ref().db);
+            let settings = self.settings();
+            let db = &settings.as_ref().db;
+
+            let mut connections = self.connections.write().unwrap();
+            connections.clear();
+            for (profile, db) in std::iter::once((None, db)).chain(
+                db.profiles
+                    .iter()
+                    .map(|(name, profile)| (Some(name.clone()), profile)),
+            ) {
+                // the error is reported once the connection is used
+                if db.connection_error().is_some() {
+                    continue;
+                }
+
+                let mut conn = DbConnection::default();
+                conn.set_conn_settings(db);
+                connections.insert(profile, conn);
+            }
         }
 
+        // forget selections of profiles that no longer exist
+        let settings = self.settings();
+        self.selected_profiles
+            .write()
+            .unwrap()
+            .retain(|_, profile| match profile {
+                Some(profile) => settings.as_ref().db.profiles.contains_key(profile),
+                None => true,
+            });
+
         tracing::info!("Updated Db connection settings");
 
         Ok(())
@@ -199,6 +594,7 @@ impl Workspace for WorkspaceServer {
             .documents
             .remove(&params.path)
             .ok_or_else(WorkspaceError::not_found)?;
+        self.mutated_schema_caches.remove(&params.path);
 
         for stmt in doc.iter_statements() {
             self.tree_sitter.remove_statement(&stmt);
@@ -281,6 +677,29 @@ impl Workspace for WorkspaceServer {
         Ok(self.is_ignored(params.pgt_path.as_path()))
     }
 
+    fn select_profile(&self, params: SelectProfileParams) -> Result<(), WorkspaceError> {
+        // fail early on a typo or on invalid connection settings instead of on the next request
+        let db = self.db_settings(params.profile.as_deref())?;
+        if db.schema_snapshot.is_none()
+            && let Some(err) = db.connection_error()
+        {
+            return Err(err);
+        }
+
+        tracing::info!(
+            "Selected profile {:?} for {:?}",
+            params.profile,
+            params.workspace_folder
+        );
+
+        self.selected_profiles
+            .write()
+            .unwrap()
+            .insert(params.workspace_folder, params.profile);
+
+        Ok(())
+    }
+
     fn pull_code_actions(
         &self,
         params: code_actions::CodeActionsParams,
@@ -296,12 +715,9 @@ impl Workspace for WorkspaceServer {
 
         let mut actions: Vec<code_actions::CodeAction> = vec![];
 
-        let settings = self
-            .settings
-            .read()
-            .expect("Unable to read settings for Code Actions");
+        let db = self.db_settings(self.profile_for(Some(params.path.as_path())).as_deref())?;
 
-        let disabled_reason: Option<String> = if settings.db.allow_statement_executions {
+        let disabled_reason: Option<String> = if db.allow_statement_executions {
             None
         } else {
             Some("Statement execution not allowed against database.".into())
@@ -322,13 +738,98 @@ impl Workspace for WorkspaceServer {
             });
         }
 
+        actions.extend(self.lint_actions(&params, &doc));
+
         Ok(CodeActionsResult { actions })
     }
 
+    fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError> {
+        let doc = self
+            .documents
+            .get(&params.path)
+            .ok_or(WorkspaceError::not_found())?;
+
+        let schema_cache = self
+            .load_lint_schema_cache(&self.profile_for(Some(params.path.as_path())))
+            .unwrap_or_else(|err| {
+                tracing::warn!("Linting without schema information: {}", err);
+                None
+            });
+        let schema_cache = schema_cache.as_ref().map(|cache| cache.as_ref());
+        let version = schema_cache.and_then(|cache| cache.versions().first());
+
+        let settings = self.settings();
+
+        let (edits, skipped_suggested_fixes) = self.with_analyser(
+            settings.as_ref(),
+            &params.only,
+            &params.skip,
+            RuleCategoriesBuilder::default().with_lint().build(),
+            |analyser| {
+                let statement_ranges: Vec<TextRange> =
+                    doc.iter_statements_with_range().map(|(_, r)| *r).collect();
+                let mut suppressions = analyser.suppressions(&doc.content, &statement_ranges);
+
+                let diagnostics = self.lint_statements(
+                    analyser,
+                    &doc,
+                    &mut suppressions,
+                    schema_cache,
+                    version,
+                    |_| true,
+                );
+
+                let mut edits = vec![];
+                let mut skipped_suggested_fixes = 0;
+
+                for range in &statement_ranges {
+                    // the edits of a fix span the whole statement, so only one fix can be
+                    // applied per statement and run
+                    let mut fix = None;
+                    for (_, d) in diagnostics.iter().filter(|(r, _)| r == range) {
+                        let allowed = d.fixes().iter().find(|fix| {
+                            fix.applicability == Applicability::Always
+                                || params.fix_file_mode == FixFileMode::SafeAndUnsafeFixes
+                        });
+                        match allowed {
+                            Some(allowed) => {
+                                fix.get_or_insert(allowed);
+                            }
+                            None if !d.fixes().is_empty() => skipped_suggested_fixes += 1,
+                            None => {}
+                        }
+                    }
+
+                    if let Some(fix) = fix {
+                        edits.push((*range, fix.suggestion.new_string(&doc.content[*range])));
+                    }
+                }
+
+                (edits, skipped_suggested_fixes)
+            },
+        );
+
+        let mut code = doc.content.clone();
+        for (range, new_text) in edits.iter().rev() {
+            code.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                new_text,
+            );
+        }
+
+        Ok(FixFileResult {
+            code,
+            fixes_applied: edits.len() as u32,
+            skipped_suggested_fixes,
+        })
+    }
+
     fn execute_statement(
         &self,
         params: ExecuteStatementParams,
     ) -> Result<ExecuteStatementResult, WorkspaceError> {
+        let profile = self.profile_for(Some(params.path.as_path()));
+
         let doc = self
             .documents
             .get(&params.path)
@@ -356,13 +857,17 @@ impl Workspace for WorkspaceServer {
             }
         };
 
-        let conn = self.connection.read().unwrap();
-        let pool = match conn.get_pool() {
+        let pool = match self.get_pool(&profile) {
             Some(p) => p,
             None => {
-                return Ok(ExecuteStatementResult {
-                    message: "Not connected to database.".into(),
-                });
+                let message = self
+                    .db_settings(profile.as_deref())?
+                    .connection_error()
+                    .map_or_else(
+                        || "Not connected to database.".into(),
+                        |err| err.to_string(),
+                    );
+                return Ok(ExecuteStatementResult { message });
             }
         };
 
@@ -376,6 +881,46 @@ impl Workspace for WorkspaceServer {
         })
     }
 
+    fn dump_schema_snapshot(&self) -> Result<String, WorkspaceError> {
+        let profile = self.profile_for(None);
+        let db = self.db_settings(profile.as_deref())?;
+        let pool = self.get_pool(&profile).ok_or_else(|| {
+            db.connection_error()
+                .unwrap_or_else(WorkspaceError::not_connected)
+        })?;
+
+        let schema_cache = run_async(async move { SchemaCache::load(&pool).await })?
+            .map_err(|err| WorkspaceError::from(err).with_connection_sources(&db.sources))?;
+
+        schema_cache
+            .to_snapshot()
+            .map_err(|err| WorkspaceError::runtime(&err.to_string()))
+    }
+
+    fn refresh_schema_cache(&self) -> Result<bool, WorkspaceError> {
+        // caches loaded from a snapshot or from migrations are not refreshed, since they were
+        // not loaded from this connection
+        let managers: Vec<_> = self
+            .schema_caches
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(profile, manager)| (profile.clone(), manager.clone()))
+            .collect();
+
+        let mut changed = false;
+        for (profile, manager) in managers {
+            if let Some(pool) = self.get_pool(&profile) {
+                let sources = self.db_settings(profile.as_deref())?.sources;
+                changed |= manager
+                    .refresh(pool)
+                    .map_err(|err| err.with_connection_sources(&sources))?;
+            }
+        }
+
+        Ok(changed)
+    }
+
     fn pull_diagnostics(
         &self,
         params: PullDiagnosticsParams,
@@ -388,36 +933,22 @@ impl Workspace for WorkspaceServer {
             .ok_or(WorkspaceError::not_found())?;
 
         let settings = self.settings();
-
-        // create analyser for this run
-        // first, collect enabled and disabled rules from the workspace settings
-        let (enabled_rules, disabled_rules) = AnalyserVisitorBuilder::new(settings.as_ref())
-            .with_linter_rules(&params.only, &params.skip)
-            .finish();
-        // then, build a map that contains all options
-        let options = AnalyserOptions {
-            rules: to_analyser_rules(settings.as_ref()),
-        };
-        // next, build the analysis filter which will be used to match rules
-        let filter = AnalysisFilter {
-            categories: params.categories,
-            enabled_rules: Some(enabled_rules.as_slice()),
-            disabled_rules: &disabled_rules,
-        };
-        // finally, create the analyser that will be used during this run
-        let analyser = Analyser::new(AnalyserConfig {
-            options: &options,
-            filter,
-        });
+        let profile = self.profile_for(Some(params.path.as_path()));
 
         let mut diagnostics: Vec<SDiagnostic> = doc.diagnostics().to_vec();
 
-        if let Some(pool) = self
-            .connection
-            .read()
-            .expect("DbConnection RwLock panicked")
-            .get_pool()
+        // with a schema snapshot, there is no database to typecheck against
+        let pool = if self
+            .db_settings(profile.as_deref())?
+            .schema_snapshot
+            .is_some()
         {
+            None
+        } else {
+            self.get_pool(&profile)
+        };
+
+        if let Some(pool) = pool {
             let typecheck_params: Vec<_> = doc
                 .iter_statements_with_text_and_range()
                 .map(|(stmt, range, text)| {
@@ -464,45 +995,115 @@ impl Workspace for WorkspaceServer {
             }
         }
 
-        diagnostics.extend(doc.iter_statements_with_range().flat_map(|(stmt, r)| {
-            let mut stmt_diagnostics = self.pg_query.get_diagnostics(&stmt);
+        // rules only use the schema to refine their diagnostics, so they run without it if the
+        // database is not available
+        let schema_cache = self.load_lint_schema_cache(&profile).unwrap_or_else(|err| {
+            tracing::warn!("Linting without schema information: {}", err);
+            None
+        });
+        let schema_cache = schema_cache.as_ref().map(|cache| cache.as_ref());
+        let version = schema_cache.and_then(|cache| cache.versions().first());
+
+        let suppressions = self.with_analyser(
+            settings.as_ref(),
+            &params.only,
+            &params.skip,
+            params.categories,
+            |analyser| {
+                let statement_ranges: Vec<TextRange> =
+                    doc.iter_statements_with_range().map(|(_, r)| *r).collect();
+                let mut suppressions = analyser.suppressions(&doc.content, &statement_ranges);
+
+                // the diagnostics of the statements, with their span in the file
+                let mut stmt_diagnostics: Vec<(SDiagnostic, TextRange)> = vec![];
+                let mut statements = vec![];
+
+                for (stmt, r) in doc.iter_statements_with_range() {
+                    let mut results = self.pg_query.get_diagnostics(&stmt);
+
+                    if let Some(ast) = self.pg_query.get_ast(&stmt) {
+                        results.extend(
+                            analyser
+                                .run(AnalyserContext {
+                                    root: &ast,
+                                    text: &doc.content[*r],
+                                    schema_cache,
+                                    version,
+                                })
+                                .into_iter()
+                                .map(SDiagnostic::new),
+                        );
+                        statements.push((ast, *r));
+                    }
+
+                    stmt_diagnostics.extend(results.into_iter().map(|d| {
+                        let span = file_span(&d, *r);
+                        (d, span)
+                    }));
+                }
 
-            let ast = self.pg_query.get_ast(&stmt);
+                // rules that look at the whole file point at a node within the file
+                let file_diagnostics = analyser.run_file(FileAnalyserContext {
+                    statements: statements
+                        .iter()
+                        .map(|(ast, r)| (ast.as_ref(), *r))
+                        .collect(),
+                    text: &doc.content,
+                    schema_cache,
+                    version,
+                });
+                stmt_diagnostics.extend(file_diagnostics.into_iter().map(|d| {
+                    let span = d.location().span.unwrap_or_default();
+                    (SDiagnostic::new(d), span)
+                }));
 
-            if let Some(ast) = ast {
-                stmt_diagnostics.extend(
-                    analyser
-                        .run(AnalyserContext { root: &ast })
+                diagnostics.extend(
+                    stmt_diagnostics
                         .into_iter()
-                        .map(SDiagnostic::new)
-                        .collect::<Vec<_>>(),
+                        .filter(|(d, span)| {
+                            !d.category().is_some_and(|category| {
+                                category.name().starts_with("lint/")
+                                    && suppressions.is_suppressed(category, *span)
+                            })
+                        })
+                        .map(|(d, span)| {
+                            let severity = d
+                                .category()
+                                .filter(|category| category.name().starts_with("lint/"))
+                                .map_or_else(
+                                    || d.severity(),
+                                    |category| {
+                                        settings
+                                            .as_ref()
+                                            .get_severity_from_rule_code(category)
+                                            .unwrap_or(Severity::Warning)
+                                    },
+                                );
+
+                            SDiagnostic::new(
+                                d.with_file_path(params.path.as_path().display().to_string())
+                                    .with_file_span(span)
+                                    .with_severity(severity),
+                            )
+                        }),
                 );
-            }
 
-            stmt_diagnostics
+                suppressions
+            },
+        );
+
+        // a suppression may be unused only because its rule was skipped in this run
+        let report_unused = params.only.is_empty()
+            && params.skip.is_empty()
+            && params.categories.contains(RuleCategory::Lint);
+        diagnostics.extend(
+            suppressions
+                .into_diagnostics(report_unused)
                 .into_iter()
                 .map(|d| {
-                    let severity = d
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || d.severity(),
-                            |category| {
-                                settings
-                                    .as_ref()
-                                    .get_severity_from_rule_code(category)
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    SDiagnostic::new(
-                        d.with_file_path(params.path.as_path().display().to_string())
-                            .with_file_span(r)
-                            .with_severity(severity),
-                    )
-                })
-                .collect::<Vec<_>>()
-        }));
+                    SDiagnostic::new(d.with_file_path(params.path.as_path().display().to_string()))
+                }),
+        );
 
         let errors = diagnostics
             .iter()
@@ -525,8 +1126,9 @@ impl Workspace for WorkspaceServer {
         &self,
         params: GetCompletionsParams,
     ) -> Result<CompletionsResult, WorkspaceError> {
-        let pool = match self.connection.read().unwrap().get_pool() {
-            Some(pool) => pool,
+        let profile = self.profile_for(Some(params.path.as_path()));
+        let schema_cache = match self.load_schema_cache(&profile)? {
+            Some(schema_cache) => schema_cache,
             None => return Ok(CompletionsResult::default()),
         };
 
@@ -557,7 +1159,22 @@ impl Workspace for WorkspaceServer {
             text
         );
 
-        let schema_cache = self.schema_cache.load(pool)?;
+        // statements above the cursor might create tables or columns that do not exist in the
+        // database yet, e.g. in a migration file
+        let preceding_ddl: Vec<_> = doc
+            .iter_statements_with_range()
+            .take_while(|(_, r)| r.end() <= stmt_range.start())
+            .filter_map(|(stmt, _)| {
+                let ast = self.pg_query.get_ast(&stmt)?;
+                SchemaCache::is_mutation(&ast).then_some((stmt, ast))
+            })
+            .collect();
+
+        let schema_cache = if preceding_ddl.is_empty() {
+            schema_cache
+        } else {
+            self.mutated_schema_cache(&params.path, schema_cache, preceding_ddl)
+        };
 
         let items = pgt_completions::complete(pgt_completions::CompletionParams {
             position,
@@ -575,3 +1192,12 @@ impl Workspace for WorkspaceServer {
 fn is_dir(path: &Path) -> bool {
     path.is_dir() || (path.is_symlink() && fs::read_link(path).is_ok_and(|path| path.is_dir()))
 }
+
+/// Returns the range of a diagnostic of a statement within the file. Diagnostics point at a node
+/// relative to the statement if they can, and at the whole statement otherwise.
+fn file_span(diagnostic: &impl Diagnostic, statement_range: TextRange) -> TextRange {
+    diagnostic
+        .location()
+        .span
+        .map_or(statement_range, |span| span + statement_range.start())
+}
@@ -1,6 +1,11 @@
 use std::time::Duration;
 
-use sqlx::{PgPool, Postgres, pool::PoolOptions, postgres::PgConnectOptions};
+use pgt_configuration::database::SslMode;
+use sqlx::{
+    PgPool, Postgres,
+    pool::PoolOptions,
+    postgres::{PgConnectOptions, PgSslMode},
+};
 
 use crate::settings::DatabaseSettings;
 
@@ -16,12 +21,38 @@ impl DbConnection {
     }
 
     pub(crate) fn set_conn_settings(&mut self, settings: &DatabaseSettings) {
-        let config = PgConnectOptions::new()
+        let mut config = PgConnectOptions::new()
             .host(&settings.host)
             .port(settings.port)
             .username(&settings.username)
             .password(&settings.password)
-            .database(&settings.database);
+            .database(&settings.database)
+            .ssl_mode(match settings.ssl_mode {
+                SslMode::Disable => PgSslMode::Disable,
+                SslMode::Allow => PgSslMode::Allow,
+                SslMode::Prefer => PgSslMode::Prefer,
+                SslMode::Require => PgSslMode::Require,
+                SslMode::VerifyCa => PgSslMode::VerifyCa,
+                SslMode::VerifyFull => PgSslMode::VerifyFull,
+            });
+
+        if let Some(root_cert) = &settings.ssl_root_cert {
+            config = config.ssl_root_cert(root_cert);
+        }
+        if let Some(cert) = &settings.ssl_cert {
+            config = config.ssl_client_cert(cert);
+        }
+        if let Some(key) = &settings.ssl_key {
+            config = config.ssl_client_key(key);
+        }
+
+        tracing::info!(
+            "Connecting to {}:{}/{} ({})",
+            settings.host,
+            settings.port,
+            settings.database,
+            settings.sources
+        );
 
         let timeout = settings.conn_timeout_secs;
 
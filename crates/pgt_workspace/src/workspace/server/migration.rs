@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub(crate) struct Migration {
@@ -47,6 +47,47 @@ pub(crate) fn get_migration(path: &Path, migrations_dir: &Path) -> Option<Migrat
         .and_then(parse_migration_name)
 }
 
+/// Get all migration files within the migrations directory, in the order they have to be applied.
+///
+/// Down migrations are skipped, since they are not part of the resulting schema.
+pub(crate) fn get_migration_files(migrations_dir: &Path) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    let Ok(entries) = std::fs::read_dir(migrations_dir) else {
+        return candidates;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            // subdirectory pattern
+            if let Ok(sub_entries) = std::fs::read_dir(&path) {
+                candidates.extend(sub_entries.flatten().map(|e| e.path()));
+            }
+        } else {
+            candidates.push(path);
+        }
+    }
+
+    let mut migrations: Vec<(u64, PathBuf)> = candidates
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "sql"))
+        .filter(|path| !is_down_migration(path))
+        .filter_map(|path| get_migration(&path, migrations_dir).map(|m| (m.sequence_number, path)))
+        .collect();
+
+    // sort by path as well to get a stable order for multiple files within one migration
+    migrations.sort();
+
+    migrations.into_iter().map(|(_, path)| path).collect()
+}
+
+fn is_down_migration(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|os_str| os_str.to_str())
+        .is_some_and(|name| name == "down.sql" || name.ends_with(".down.sql"))
+}
+
 fn parse_migration_name(name: &str) -> Option<Migration> {
     let mut parts = name.splitn(2, '_');
     // remove leading zeros to support numeric
@@ -120,6 +161,31 @@ mod tests {
         assert_eq!(migration.name, "a_migration");
     }
 
+    #[test]
+    fn test_get_migration_files_in_order() {
+        let temp_dir = setup();
+        let migrations_dir = temp_dir.path().to_path_buf();
+        fs::write(migrations_dir.join("20_add_posts.sql"), "").unwrap();
+        fs::write(migrations_dir.join("3_create_users.sql"), "").unwrap();
+        fs::write(migrations_dir.join("3_create_users.down.sql"), "").unwrap();
+        fs::write(migrations_dir.join("README.md"), "").unwrap();
+        let subdir = migrations_dir.join("100_add_comments");
+        fs::create_dir(&subdir).unwrap();
+        fs::write(subdir.join("up.sql"), "").unwrap();
+        fs::write(subdir.join("down.sql"), "").unwrap();
+
+        let files = get_migration_files(&migrations_dir);
+
+        assert_eq!(
+            files,
+            vec![
+                migrations_dir.join("3_create_users.sql"),
+                migrations_dir.join("20_add_posts.sql"),
+                subdir.join("up.sql"),
+            ]
+        );
+    }
+
     #[test]
     fn test_get_migration_not_timestamp_in_filename() {
         let migrations_dir = PathBuf::from("/tmp/migrations");
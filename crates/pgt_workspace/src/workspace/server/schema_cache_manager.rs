@@ -1,38 +1,73 @@
-use std::sync::{RwLock, RwLockReadGuard};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
 
-use pgt_schema_cache::SchemaCache;
+use pgt_schema_cache::{SchemaCache, SchemaFingerprint};
 use sqlx::PgPool;
 
 use crate::WorkspaceError;
 
 use super::async_helper::run_async;
+use super::document::Statement;
+use super::migration::get_migration_files;
 
-pub(crate) struct SchemaCacheHandle<'a> {
-    inner: RwLockReadGuard<'a, SchemaCacheManagerInner>,
+/// A reference to the schema cache as it was when it was requested. Later refreshes do not affect
+/// it.
+pub(crate) struct SchemaCacheHandle {
+    cache: Arc<SchemaCache>,
 }
 
-impl<'a> SchemaCacheHandle<'a> {
-    pub(crate) fn new(cache: &'a RwLock<SchemaCacheManagerInner>) -> Self {
+impl SchemaCacheHandle {
+    pub(crate) fn new(cache: Arc<SchemaCache>) -> Self {
+        Self { cache }
+    }
+}
+
+impl AsRef<SchemaCache> for SchemaCacheHandle {
+    fn as_ref(&self) -> &SchemaCache {
+        &self.cache
+    }
+}
+
+/// A schema cache with DDL statements of a document applied to it, e.g. the tables that a
+/// migration file creates before the statement that is being completed.
+pub(crate) struct MutatedSchemaCache {
+    base: Arc<SchemaCache>,
+    statements: Vec<Statement>,
+    cache: Arc<SchemaCache>,
+}
+
+impl MutatedSchemaCache {
+    pub(crate) fn new(
+        base: &SchemaCacheHandle,
+        statements: Vec<Statement>,
+        cache: SchemaCache,
+    ) -> Self {
         Self {
-            inner: cache.read().unwrap(),
+            base: base.cache.clone(),
+            statements,
+            cache: Arc::new(cache),
         }
     }
 
-    pub(crate) fn wrap(inner: RwLockReadGuard<'a, SchemaCacheManagerInner>) -> Self {
-        Self { inner }
+    /// Whether the cache was built by applying `statements` to `base`. Statements get a new id
+    /// when they change, so comparing the statements is enough.
+    pub(crate) fn matches(&self, base: &SchemaCacheHandle, statements: &[Statement]) -> bool {
+        Arc::ptr_eq(&self.base, &base.cache) && self.statements == statements
     }
-}
 
-impl AsRef<SchemaCache> for SchemaCacheHandle<'_> {
-    fn as_ref(&self) -> &SchemaCache {
-        &self.inner.cache
+    pub(crate) fn handle(&self) -> SchemaCacheHandle {
+        SchemaCacheHandle::new(self.cache.clone())
     }
 }
 
 #[derive(Default)]
 pub(crate) struct SchemaCacheManagerInner {
-    cache: SchemaCache,
-    conn_str: String,
+    cache: Arc<SchemaCache>,
+    /// The connection string, snapshot path or migrations the cache was loaded from
+    source: String,
+    /// The state of the database the cache was loaded from, used to detect schema changes
+    fingerprint: Option<SchemaFingerprint>,
 }
 
 #[derive(Default)]
@@ -47,31 +82,248 @@ impl SchemaCacheManager {
         {
             // return early if the connection string is the same
             let inner = self.inner.read().unwrap();
-            if new_conn_str == inner.conn_str {
+            if new_conn_str == inner.source {
                 tracing::info!("Same connection string, no updates.");
-                return Ok(SchemaCacheHandle::wrap(inner));
+                return Ok(SchemaCacheHandle::new(inner.cache.clone()));
             }
         }
 
-        let maybe_refreshed = run_async(async move { SchemaCache::load(&pool).await })?;
-        let refreshed = maybe_refreshed?;
+        let maybe_refreshed = run_async(async move {
+            // load the fingerprint first, so changes made while the cache loads are picked up by
+            // the next refresh
+            let fingerprint = SchemaFingerprint::load(&pool).await?;
+            let cache = SchemaCache::load(&pool).await?;
+            Ok::<_, sqlx::Error>((cache, fingerprint))
+        })?;
+        let (refreshed, fingerprint) = maybe_refreshed?;
 
         {
             // write lock must be dropped before we return the reference below, hence the block
             let mut inner = self.inner.write().unwrap();
 
             // Double-check that we still need to refresh (another thread might have done it)
-            if new_conn_str != inner.conn_str {
-                inner.cache = refreshed;
-                inner.conn_str = new_conn_str;
+            if new_conn_str != inner.source {
+                inner.cache = Arc::new(refreshed);
+                inner.source = new_conn_str;
+                inner.fingerprint = Some(fingerprint);
                 tracing::info!("Refreshed connection.");
             }
         }
 
-        Ok(SchemaCacheHandle::new(&self.inner))
+        Ok(SchemaCacheHandle::new(
+            self.inner.read().unwrap().cache.clone(),
+        ))
+    }
+
+    /// Loads the column statistics into the schema cache, unless they were loaded before. They
+    /// are kept up to date by [SchemaCacheManager::refresh] from then on.
+    ///
+    /// Does nothing if the cache was not loaded from this database.
+    pub fn load_column_stats(&self, pool: PgPool) -> Result<SchemaCacheHandle, WorkspaceError> {
+        let conn_str = pool_to_conn_str(&pool);
+
+        let base = {
+            let inner = self.inner.read().unwrap();
+            if inner.source != conn_str || inner.cache.column_stats().is_some() {
+                return Ok(SchemaCacheHandle::new(inner.cache.clone()));
+            }
+            inner.cache.clone()
+        };
+
+        let mut cache = base.as_ref().clone();
+        let maybe_loaded = run_async(async move {
+            cache.load_column_stats(&pool).await?;
+            Ok::<_, sqlx::Error>(cache)
+        })?;
+        let loaded = maybe_loaded?;
+
+        let mut inner = self.inner.write().unwrap();
+
+        // the cache might have been reloaded or refreshed in the meantime
+        if Arc::ptr_eq(&inner.cache, &base) {
+            inner.cache = Arc::new(loaded);
+            tracing::info!("Loaded column statistics.");
+        }
+
+        Ok(SchemaCacheHandle::new(inner.cache.clone()))
+    }
+
+    /// Checks whether the schema of the database changed since the cache was loaded from it and,
+    /// if so, reloads the affected items. Returns whether the cache changed.
+    ///
+    /// Does nothing if the cache was not loaded from this database yet.
+    pub fn refresh(&self, pool: PgPool) -> Result<bool, WorkspaceError> {
+        let conn_str = pool_to_conn_str(&pool);
+
+        let (mut cache, previous) = {
+            let inner = self.inner.read().unwrap();
+            match &inner.fingerprint {
+                Some(fingerprint) if inner.source == conn_str => {
+                    (inner.cache.as_ref().clone(), fingerprint.clone())
+                }
+                _ => return Ok(false),
+            }
+        };
+
+        let maybe_refreshed = run_async(async move {
+            let fingerprint = SchemaFingerprint::load(&pool).await?;
+            let changed_catalogs = fingerprint.changed_catalogs(&previous);
+
+            if !changed_catalogs.is_empty() {
+                tracing::info!("Schema changed in {}.", changed_catalogs.join(", "));
+            }
+
+            // the search path and the server version are not part of the fingerprint
+            let search_path = cache.search_path().to_vec();
+            let version = cache.versions().first().map(|v| v.version_num);
+
+            cache.refresh(&pool, &changed_catalogs).await?;
+
+            if changed_catalogs.is_empty()
+                && cache.search_path() == search_path
+                && cache.versions().first().map(|v| v.version_num) == version
+            {
+                return Ok(None);
+            }
+
+            Ok::<_, sqlx::Error>(Some((cache, fingerprint)))
+        })?;
+
+        let Some((refreshed, fingerprint)) = maybe_refreshed? else {
+            return Ok(false);
+        };
+
+        let mut inner = self.inner.write().unwrap();
+
+        // the connection might have changed in the meantime
+        if inner.source != conn_str {
+            return Ok(false);
+        }
+
+        inner.cache = Arc::new(refreshed);
+        inner.fingerprint = Some(fingerprint);
+
+        Ok(true)
+    }
+
+    pub fn load_snapshot(&self, path: &Path) -> Result<SchemaCacheHandle, WorkspaceError> {
+        let new_source = snapshot_to_source(path)
+            .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+
+        {
+            // return early if the snapshot did not change since it was loaded
+            let inner = self.inner.read().unwrap();
+            if new_source == inner.source {
+                return Ok(SchemaCacheHandle::new(inner.cache.clone()));
+            }
+        }
+
+        let snapshot = std::fs::read_to_string(path)
+            .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+        let loaded = SchemaCache::from_snapshot(&snapshot).map_err(|err| {
+            WorkspaceError::schema_snapshot(path.display().to_string(), err.to_string())
+        })?;
+
+        {
+            let mut inner = self.inner.write().unwrap();
+            inner.cache = Arc::new(loaded);
+            inner.source = new_source;
+            inner.fingerprint = None;
+            tracing::info!("Loaded schema snapshot.");
+        }
+
+        Ok(SchemaCacheHandle::new(
+            self.inner.read().unwrap().cache.clone(),
+        ))
+    }
+
+    pub fn load_migrations(
+        &self,
+        migrations_dir: &Path,
+    ) -> Result<SchemaCacheHandle, WorkspaceError> {
+        let files = get_migration_files(migrations_dir);
+        let new_source = migrations_to_source(migrations_dir, &files);
+
+        {
+            // return early if no migration changed since the last replay
+            let inner = self.inner.read().unwrap();
+            if new_source == inner.source {
+                return Ok(SchemaCacheHandle::new(inner.cache.clone()));
+            }
+        }
+
+        let mut cache = SchemaCache::builtin();
+        for file in &files {
+            let sql = std::fs::read_to_string(file)
+                .map_err(|_| WorkspaceError::cant_read_file(file.display().to_string()))?;
+            replay_migration(&mut cache, &sql);
+        }
+
+        {
+            let mut inner = self.inner.write().unwrap();
+            inner.cache = Arc::new(cache);
+            inner.source = new_source;
+            inner.fingerprint = None;
+            tracing::info!("Replayed {} migrations.", files.len());
+        }
+
+        Ok(SchemaCacheHandle::new(
+            self.inner.read().unwrap().cache.clone(),
+        ))
     }
 }
 
+/// Applies every statement of a migration to the cache. Statements that cannot be parsed are
+/// skipped, since they are reported as diagnostics for the migration file itself.
+fn replay_migration(cache: &mut SchemaCache, sql: &str) {
+    let Ok(parsed) = pgt_statement_splitter::split(sql) else {
+        return;
+    };
+
+    for range in parsed.ranges {
+        if let Ok(node) = pgt_query_ext::parse(&sql[range]) {
+            cache.mutate(&node);
+        }
+    }
+}
+
+/// Identifies a set of migrations, including when they were last modified, so that the cache is
+/// rebuilt whenever a migration is added or changed.
+fn migrations_to_source(migrations_dir: &Path, files: &[PathBuf]) -> String {
+    let last_modified = files
+        .iter()
+        .filter_map(|file| file.metadata().and_then(|m| m.modified()).ok())
+        .max()
+        .and_then(|modified| modified.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    format!(
+        "migrations:{}:{}:{}",
+        migrations_dir.display(),
+        files.len(),
+        last_modified
+    )
+}
+
+/// Identifies a snapshot file, including when it was last modified and its size, so that the
+/// cache is reloaded whenever the snapshot is dumped again.
+fn snapshot_to_source(path: &Path) -> std::io::Result<String> {
+    let metadata = path.metadata()?;
+    let last_modified = metadata
+        .modified()?
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    Ok(format!(
+        "snapshot:{}:{}:{}",
+        path.display(),
+        metadata.len(),
+        last_modified
+    ))
+}
+
 fn pool_to_conn_str(pool: &PgPool) -> String {
     let conn = pool.connect_options();
 
@@ -91,3 +343,62 @@ fn pool_to_conn_str(pool: &PgPool) -> String {
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use pgt_schema_cache::SchemaCache;
+    use tempfile::TempDir;
+
+    use super::SchemaCacheManager;
+
+    #[test]
+    fn builds_cache_from_migrations() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let migrations_dir = temp_dir.path();
+
+        fs::write(
+            migrations_dir.join("1_create_users.sql"),
+            "create table users (id serial primary key, name text);",
+        )
+        .unwrap();
+        fs::write(
+            migrations_dir.join("2_add_email.sql"),
+            "alter table users add column email varchar(255) not null;\nalter table users drop column name;",
+        )
+        .unwrap();
+
+        let manager = SchemaCacheManager::default();
+        let handle = manager.load_migrations(migrations_dir).unwrap();
+        let cache = handle.as_ref();
+
+        assert!(cache.find_table("users", Some("public")).is_some());
+        assert!(cache.find_col("name", "users", Some("public")).is_none());
+
+        let email = cache.find_col("email", "users", Some("public")).unwrap();
+        assert!(!email.is_nullable);
+        assert_eq!(email.type_id, 1043);
+    }
+
+    #[test]
+    fn reloads_changed_snapshots() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("schema.json");
+
+        let mut cache = SchemaCache::builtin();
+        cache.mutate(&pgt_query_ext::parse("create table users (id int)").unwrap());
+        fs::write(&path, cache.to_snapshot().unwrap()).unwrap();
+
+        let manager = SchemaCacheManager::default();
+        let handle = manager.load_snapshot(&path).unwrap();
+        assert!(handle.as_ref().find_table("users", None).is_some());
+        assert!(handle.as_ref().find_table("orders", None).is_none());
+
+        cache.mutate(&pgt_query_ext::parse("create table orders (id int)").unwrap());
+        fs::write(&path, cache.to_snapshot().unwrap()).unwrap();
+
+        let handle = manager.load_snapshot(&path).unwrap();
+        assert!(handle.as_ref().find_table("orders", None).is_some());
+    }
+}
@@ -33,6 +33,15 @@ pub fn generate_env_variables(docs_dir: &Path) -> Result<()> {
         env.pgt_config_path.description()
     )?;
 
+    for variable in env.connection_variables() {
+        writeln!(
+            content,
+            "### `{}`\n\n {}\n",
+            variable.name(),
+            variable.description()
+        )?;
+    }
+
     let data = fs::read_to_string(&file_path)?;
 
     let conent_str = String::from_utf8(content)?;
@@ -443,10 +443,17 @@ fn print_diagnostics(
 
     // split and parse each statement
     let stmts = pgt_statement_splitter::split(code).expect("unexpected parse error");
+    let mut statements = vec![];
     for stmt in stmts.ranges {
         match pgt_query_ext::parse(&code[stmt]) {
             Ok(ast) => {
-                for rule_diag in analyser.run(pgt_analyser::AnalyserContext { root: &ast }) {
+                for rule_diag in analyser.run(pgt_analyser::AnalyserContext {
+                    root: &ast,
+                    text: &code[stmt],
+                    schema_cache: None,
+                    version: None,
+                }) {
+                    let span = rule_diag.location().span.map(|span| span + stmt.start());
                     let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
 
                     let category = diag.category().expect("linter diagnostic has no code");
@@ -457,10 +464,13 @@ fn print_diagnostics(
                     let error = diag
                         .with_severity(severity)
                         .with_file_path(&file_path)
+                        .with_file_span(span.unwrap_or(stmt))
                         .with_file_source_code(code);
 
                     write_diagnostic(code, error)?;
                 }
+
+                statements.push((ast, stmt));
             }
             Err(e) => {
                 let error = SyntaxDiagnostic::from(e)
@@ -471,5 +481,32 @@ fn print_diagnostics(
         };
     }
 
+    // rules that look at the whole code block point at a statement of it
+    for rule_diag in analyser.run_file(pgt_analyser::FileAnalyserContext {
+        statements: statements
+            .iter()
+            .map(|(ast, range)| (ast, *range))
+            .collect(),
+        text: code,
+        schema_cache: None,
+        version: None,
+    }) {
+        let span = rule_diag.location().span.unwrap_or_default();
+        let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
+
+        let category = diag.category().expect("linter diagnostic has no code");
+        let severity = settings
+            .get_severity_from_rule_code(category)
+            .expect("If you see this error, it means you need to run cargo codegen-configuration");
+
+        let error = diag
+            .with_severity(severity)
+            .with_file_path(&file_path)
+            .with_file_span(span)
+            .with_file_source_code(code);
+
+        write_diagnostic(code, error)?;
+    }
+
     Ok(())
 }
@@ -58,14 +58,25 @@ export interface Advices {
 	advices: Advice[];
 }
 export type Category =
+	| "lint/safety/addingFieldWithDefault"
+	| "lint/safety/addingNotNullField"
 	| "lint/safety/addingRequiredField"
+	| "lint/safety/banConcurrentIndexCreationInTransaction"
 	| "lint/safety/banDropColumn"
 	| "lint/safety/banDropNotNull"
 	| "lint/safety/banDropTable"
+	| "lint/safety/banVacuumFullAndCluster"
+	| "lint/safety/changingColumnType"
+	| "lint/safety/constraintMissingNotValid"
+	| "lint/safety/requireConcurrentIndexCreation"
+	| "lint/safety/requireConcurrentIndexDeletion"
+	| "lint/safety/useIfExists"
 	| "stdin"
 	| "check"
 	| "configuration"
 	| "database/connection"
+	| "database/schemaSnapshot"
+	| "database/profile"
 	| "internalError/io"
 	| "internalError/runtime"
 	| "internalError/fs"
@@ -75,6 +86,9 @@ export type Category =
 	| "internalError/panic"
 	| "syntax"
 	| "dummy"
+	| "suppressions/parse"
+	| "suppressions/unknownRule"
+	| "suppressions/unused"
 	| "lint"
 	| "lint/performance"
 	| "lint/safety";
@@ -169,6 +183,30 @@ export interface BacktraceSymbol {
 	lineno?: number;
 	name?: string;
 }
+export interface FixFileParams {
+	fix_file_mode: FixFileMode;
+	only: RuleCode[];
+	path: PgTPath;
+	skip: RuleCode[];
+}
+/**
+ * Which fixes to apply
+ */
+export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export interface FixFileResult {
+	/**
+	 * The content of the file with the fixes applied
+	 */
+	code: string;
+	/**
+	 * The number of fixes that were applied
+	 */
+	fixes_applied: number;
+	/**
+	 * The number of fixes that were skipped because they are unsafe
+	 */
+	skipped_suggested_fixes: number;
+}
 export interface GetCompletionsParams {
 	/**
 	 * The File for which a completion is requested.
@@ -235,6 +273,10 @@ export interface PartialDatabaseConfiguration {
 	 * The connection timeout in seconds.
 	 */
 	connTimeoutSecs?: number;
+	/**
+	 * A connection string such as `postgres://user@localhost:5432/db`. The connection settings above take precedence over the parts of the connection string.
+	 */
+	connectionString?: string;
 	/**
 	 * The name of the database.
 	 */
@@ -243,6 +285,10 @@ export interface PartialDatabaseConfiguration {
 	 * The host of the database.
 	 */
 	host?: string;
+	/**
+	 * Load the statistics of each column from `pg_stats` into the schema cache, e.g. to take the number of distinct values into account. They are loaded the first time the lint rules run, since this can be slow on large databases.
+	 */
+	loadColumnStats?: boolean;
 	/**
 	 * The password to connect to the database.
 	 */
@@ -251,6 +297,42 @@ export interface PartialDatabaseConfiguration {
 	 * The port of the database.
 	 */
 	port?: number;
+	/**
+	 * The profile to connect with. If not set, the connection settings above are used.
+	 */
+	profile?: string;
+	/**
+	 * Named connection profiles, e.g. for a local, a staging and a production database. Settings a profile does not specify are taken from the connection settings above.
+	 */
+	profiles?: DatabaseProfiles;
+	/**
+	 * How often, in seconds, the language server checks the database for schema changes, e.g. after a migration was run, to update the diagnostics. Each check queries the catalog, so it is disabled by default (`0`).
+	 */
+	schemaRefreshIntervalSecs?: number;
+	/**
+	 * A schema snapshot created via `postgrestools dump-schema`. If set, the schema is loaded from the snapshot instead of the database. Features that need a connection, such as type checking, are disabled.
+	 */
+	schemaSnapshot?: string;
+	/**
+	 * The name of a service in the connection service file (`pg_service.conf`) to take the connection settings from.
+	 */
+	service?: string;
+	/**
+	 * The path to the client certificate.
+	 */
+	sslCert?: string;
+	/**
+	 * The path to the secret key of the client certificate.
+	 */
+	sslKey?: string;
+	/**
+	 * Whether and how the connection is encrypted with SSL.
+	 */
+	sslMode?: SslMode;
+	/**
+	 * The path to the certificate authorities the server certificate is verified with.
+	 */
+	sslRootCert?: string;
 	/**
 	 * The username to connect to the database.
 	 */
@@ -303,6 +385,10 @@ export interface PartialMigrationsConfiguration {
 	 * The directory where the migration files are stored
 	 */
 	migrationsDir?: string;
+	/**
+	 * Build the schema cache by replaying all migrations in order instead of loading it from the database. Useful if there is no database to connect to.
+	 */
+	schemaFromMigrations?: boolean;
 }
 /**
  * Set of properties to integrate with a VCS software.
@@ -332,6 +418,20 @@ If we can't find the configuration, it will attempt to use the current working d
 	useIgnoreFile?: boolean;
 }
 export type StringSet = string[];
+/**
+ * The connection profiles by name.
+ */
+export interface DatabaseProfiles {}
+/**
+ * Whether and how the connection is encrypted with SSL, as in `libpq`.
+ */
+export type SslMode =
+	| "disable"
+	| "allow"
+	| "prefer"
+	| "require"
+	| "verify-ca"
+	| "verify-full";
 export interface Rules {
 	/**
 	 * It enables ALL rules. The rules that belong to `nursery` won't be enabled.
@@ -348,6 +448,14 @@ export type VcsClientKind = "git";
  * A list of rules that belong to this group
  */
 export interface Safety {
+	/**
+	 * Adding a column with a volatile default value rewrites the whole table while blocking reads and writes.
+	 */
+	addingFieldWithDefault?: RuleConfiguration_for_Null;
+	/**
+	 * Setting a column to NOT NULL blocks reads and writes while the whole table is scanned.
+	 */
+	addingNotNullField?: RuleConfiguration_for_Null;
 	/**
 	 * Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required.
 	 */
@@ -356,6 +464,10 @@ export interface Safety {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * CREATE INDEX CONCURRENTLY fails inside a transaction block.
+	 */
+	banConcurrentIndexCreationInTransaction?: RuleConfiguration_for_Null;
 	/**
 	 * Dropping a column may break existing clients.
 	 */
@@ -368,10 +480,34 @@ export interface Safety {
 	 * Dropping a table may break existing clients.
 	 */
 	banDropTable?: RuleConfiguration_for_Null;
+	/**
+	 * VACUUM FULL and CLUSTER rewrite the whole table while blocking reads and writes.
+	 */
+	banVacuumFullAndCluster?: RuleConfiguration_for_Null;
+	/**
+	 * Changing the type of a column may rewrite the whole table while blocking reads and writes.
+	 */
+	changingColumnType?: RuleConfiguration_for_Null;
+	/**
+	 * Adding a foreign key or check constraint without NOT VALID blocks writes while all existing rows are checked.
+	 */
+	constraintMissingNotValid?: RuleConfiguration_for_Null;
 	/**
 	 * It enables the recommended rules for this group
 	 */
 	recommended?: boolean;
+	/**
+	 * Creating an index non-concurrently blocks writes to the table while the index is built.
+	 */
+	requireConcurrentIndexCreation?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping an index non-concurrently blocks reads and writes to the table.
+	 */
+	requireConcurrentIndexDeletion?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping a table, index, column or constraint without IF EXISTS fails if the migration is run again.
+	 */
+	useIfExists?: RuleConfiguration_for_Null;
 }
 export type RuleConfiguration_for_Null =
 	| RulePlainConfiguration
@@ -414,6 +550,7 @@ export interface Workspace {
 	pullDiagnostics(
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
+	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	getCompletions(params: GetCompletionsParams): Promise<CompletionResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
@@ -432,6 +569,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		pullDiagnostics(params) {
 			return transport.request("pgt/pull_diagnostics", params);
 		},
+		fixFile(params) {
+			return transport.request("pgt/fix_file", params);
+		},
 		getCompletions(params) {
 			return transport.request("pgt/get_completions", params);
 		},
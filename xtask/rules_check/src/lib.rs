@@ -129,11 +129,17 @@ fn assert_lint(
     // split and parse each statement
     match pgt_statement_splitter::split(code) {
         Ok(stmts) => {
+            let mut statements = vec![];
             for stmt in stmts.ranges {
                 match pgt_query_ext::parse(&code[stmt]) {
                     Ok(ast) => {
-                        for rule_diag in analyser.run(pgt_analyser::AnalyserContext { root: &ast })
-                        {
+                        for rule_diag in analyser.run(pgt_analyser::AnalyserContext {
+                            root: &ast,
+                            text: &code[stmt],
+                            schema_cache: None,
+                            version: None,
+                        }) {
+                            let span = rule_diag.location().span.map(|span| span + stmt.start());
                             let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
 
                             let category = diag.category().expect("linter diagnostic has no code");
@@ -144,10 +150,13 @@ fn assert_lint(
                             let error = diag
                                 .with_severity(severity)
                                 .with_file_path(&file_path)
+                                .with_file_span(span.unwrap_or(stmt))
                                 .with_file_source_code(code);
 
                             write_diagnostic(code, error)?;
                         }
+
+                        statements.push((ast, stmt));
                     }
                     Err(e) => {
                         let error = SyntaxDiagnostic::from(e)
@@ -157,6 +166,33 @@ fn assert_lint(
                     }
                 };
             }
+
+            // rules that look at the whole code block point at a statement of it
+            for rule_diag in analyser.run_file(pgt_analyser::FileAnalyserContext {
+                statements: statements
+                    .iter()
+                    .map(|(ast, range)| (ast, *range))
+                    .collect(),
+                text: code,
+                schema_cache: None,
+                version: None,
+            }) {
+                let span = rule_diag.location().span.unwrap_or_default();
+                let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
+
+                let category = diag.category().expect("linter diagnostic has no code");
+                let severity = settings.get_severity_from_rule_code(category).expect(
+                    "If you see this error, it means you need to run cargo codegen-configuration",
+                );
+
+                let error = diag
+                    .with_severity(severity)
+                    .with_file_path(&file_path)
+                    .with_file_span(span)
+                    .with_file_source_code(code);
+
+                write_diagnostic(code, error)?;
+            }
         }
         Err(errs) => {
             // Print all diagnostics to help the user